@@ -6,10 +6,18 @@ async fn main() {
         .join("examples")
         .join("cql");
     let opts = MigrateOpts {
+        allow_destructive: false,
+        allow_out_of_order: false,
         cassandra_opts: None,
+        checksum_algorithm: None,
         cql_dir,
         history_keyspace: None,
         history_table: None,
+        keyspace_map: None,
+        profile: None,
+        sources: Vec::new(),
+        vars: None,
+        version_scheme: None,
     };
     match migrate_cql(opts).await {
         Err(err) => {
@@ -0,0 +1,41 @@
+use cquill::*;
+
+#[tokio::main]
+async fn main() {
+    let migrations = embed_migrations!("examples/cql");
+    let opts = MigrateOpts {
+        allow_destructive: false,
+        allow_out_of_order: false,
+        cassandra_opts: None,
+        checksum_algorithm: None,
+        cql_dir: migrations.cql_dir(),
+        history_keyspace: None,
+        history_table: None,
+        keyspace_map: None,
+        profile: None,
+        sources: Vec::new(),
+        vars: None,
+        version_scheme: None,
+    };
+    match migrate_embedded(&migrations, opts).await {
+        Err(err) => {
+            println!("EXAMPLE ERRORED: {}", err);
+            std::process::exit(1);
+        }
+        Ok(migrated_cql_files) => {
+            if migrated_cql_files.is_empty() {
+                println!("✔ already up-to-date!");
+            } else {
+                println!(
+                    "✔ {} cql file(s) migrated: {}",
+                    migrated_cql_files.len(),
+                    migrated_cql_files
+                        .iter()
+                        .map(|f| f.filename.clone())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+    };
+}
@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use sha2::{Digest, Sha256};
+
+/// Algorithm of the content hash recorded with a migrated cql file in the history table, which is
+/// recorded with the hash so a cql file is validated against the algorithm it was migrated with.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ChecksumAlgorithm {
+    /// MD5 of a cql file's bytes and the algorithm of history rows recorded without an algorithm.
+    #[default]
+    Md5,
+    /// SHA-256 of a cql file's bytes.
+    Sha256,
+    /// SHA-256 of a cql file's tokens, which does not change with edits to comments, whitespace or
+    /// the casing of keywords.
+    Normalized,
+}
+
+impl ChecksumAlgorithm {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "md5",
+            ChecksumAlgorithm::Sha256 => "sha256",
+            ChecksumAlgorithm::Normalized => "normalized",
+        }
+    }
+
+    /// Hashes the unrendered content of a cql file as a lowercase hex string.
+    pub(crate) fn checksum(&self, cql: &[u8]) -> Result<String> {
+        let digest = match self {
+            ChecksumAlgorithm::Md5 => return Ok(format!("{:x}", md5::compute(cql))),
+            ChecksumAlgorithm::Sha256 => sha256(cql),
+            ChecksumAlgorithm::Normalized => {
+                let cql = std::str::from_utf8(cql)?;
+                match cquill_ast::normalize_cql(cql) {
+                    Err(err) => return Err(anyhow!("failed normalizing cql: {err}")),
+                    Ok(normalized) => sha256(normalized.as_bytes()),
+                }
+            }
+        };
+        Ok(digest.iter().map(|byte| format!("{byte:02x}")).collect())
+    }
+}
+
+impl Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "md5" => Ok(ChecksumAlgorithm::Md5),
+            "sha256" => Ok(ChecksumAlgorithm::Sha256),
+            "normalized" => Ok(ChecksumAlgorithm::Normalized),
+            _ => Err(anyhow!("{s} is not a checksum algorithm")),
+        }
+    }
+}
+
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_checksum() {
+        let sha256 = ChecksumAlgorithm::Sha256;
+        assert_eq!(
+            sha256.checksum(b"").unwrap(),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256.checksum(b"abc").unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            sha256
+                .checksum(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+                .unwrap(),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+    }
+
+    #[test]
+    fn test_md5_checksum() {
+        assert_eq!(
+            ChecksumAlgorithm::Md5.checksum(b"abc").unwrap(),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+    }
+
+    #[test]
+    fn test_normalized_checksum_ignores_comments_and_whitespace() {
+        let normalized = ChecksumAlgorithm::Normalized;
+        let checksum = normalized
+            .checksum(b"create table api.accounts (id uuid primary key);")
+            .unwrap();
+        assert_eq!(
+            normalized
+                .checksum(
+                    b"-- accounts\nCREATE TABLE api.accounts (\n    id uuid PRIMARY KEY\n);\n"
+                )
+                .unwrap(),
+            checksum
+        );
+        assert_ne!(
+            normalized
+                .checksum(b"create table api.accounts (id timeuuid primary key);")
+                .unwrap(),
+            checksum
+        );
+    }
+
+    #[test]
+    fn test_normalized_checksum_keeps_template_placeholders() {
+        let normalized = ChecksumAlgorithm::Normalized;
+        let checksum = |cql: &[u8]| normalized.checksum(cql).unwrap();
+        assert_ne!(
+            checksum(b"insert into api.settings (id, value) values (1, ${keyspace});"),
+            checksum(b"insert into api.settings (id, value) values (1, {keyspace});")
+        );
+        assert_ne!(
+            checksum(b"insert into api.settings (id, value) values (1, {{ keyspace }});"),
+            checksum(b"insert into api.settings (id, value) values (1, { { keyspace } });")
+        );
+        assert_eq!(
+            checksum(b"create table ${keyspace}.accounts (id uuid primary key);"),
+            checksum(b"CREATE TABLE ${keyspace}.accounts (\n    id uuid PRIMARY KEY\n);")
+        );
+    }
+
+    #[test]
+    fn test_parse_checksum_algorithm() {
+        for algorithm in [
+            ChecksumAlgorithm::Md5,
+            ChecksumAlgorithm::Sha256,
+            ChecksumAlgorithm::Normalized,
+        ] {
+            assert_eq!(
+                algorithm.as_str().parse::<ChecksumAlgorithm>().unwrap(),
+                algorithm
+            );
+        }
+        assert_eq!(
+            "crc32"
+                .parse::<ChecksumAlgorithm>()
+                .unwrap_err()
+                .to_string(),
+            "crc32 is not a checksum algorithm"
+        );
+    }
+}
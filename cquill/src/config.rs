@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Deserializer};
+
+pub const CONFIG_FILENAME: &str = "cquill.toml";
+
+/// ProjectConfig holds the settings of a project's `cquill.toml` file. Tables other than `[lint]`
+/// and `[vars]` are errors rather than ignored, so a misspelled table is not silently unused.
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProjectConfig {
+    /// Levels of lint rules by rule name, such as `drop-table = "off"`.
+    pub lint: BTreeMap<String, String>,
+    /// Defaults of template vars by name, which may be strings, numbers or booleans.
+    #[serde(deserialize_with = "scalar_values")]
+    pub vars: BTreeMap<String, String>,
+}
+
+impl ProjectConfig {
+    /// Reads a config file, defaulting to an empty config when the file does not exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        match fs::read_to_string(path) {
+            Err(err) => Err(anyhow!(
+                "failed reading file {}: {err}",
+                path.to_string_lossy()
+            )),
+            Ok(toml) => toml
+                .parse()
+                .map_err(|err| anyhow!("{err} in {}", path.to_string_lossy())),
+        }
+    }
+}
+
+impl std::str::FromStr for ProjectConfig {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).map_err(|err| anyhow!("invalid config: {}", err.message()))
+    }
+}
+
+/// Deserializes a table of strings, numbers and booleans as their string values.
+fn scalar_values<'de, D>(deserializer: D) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    BTreeMap::<String, toml::Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, value)| match value {
+            toml::Value::String(value) => Ok((key, value)),
+            toml::Value::Integer(value) => Ok((key, value.to_string())),
+            toml::Value::Float(value) => Ok((key, value.to_string())),
+            toml::Value::Boolean(value) => Ok((key, value.to_string())),
+            _ => Err(serde::de::Error::custom(format!(
+                "{key} is not a string, number or boolean"
+            ))),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config: ProjectConfig = "\
+# cquill settings
+[lint]
+drop-table = \"off\" # allowed during development
+\"truncate\" = 'warn'
+
+[vars]
+ttl_days = 30
+tracing = true
+"
+        .parse()
+        .unwrap();
+        assert_eq!(
+            config.lint,
+            BTreeMap::from([
+                ("drop-table".to_string(), "off".to_string()),
+                ("truncate".to_string(), "warn".to_string()),
+            ])
+        );
+        assert_eq!(
+            config.vars,
+            BTreeMap::from([
+                ("tracing".to_string(), "true".to_string()),
+                ("ttl_days".to_string(), "30".to_string()),
+            ])
+        );
+        assert_eq!(
+            "".parse::<ProjectConfig>().unwrap(),
+            ProjectConfig::default()
+        );
+    }
+
+    #[test]
+    fn test_parse_config_errors() {
+        for (toml, error) in [
+            ("drop-table = \"off\"", "unknown field `drop-table`"),
+            ("[lnit]\ndrop-table = \"off\"", "unknown field `lnit`"),
+            ("[lint\n", "unclosed table, expected `]`"),
+            ("[lint]\ndrop-table", "key with no value, expected `=`"),
+            (
+                "[lint]\ndrop-table = 1",
+                "invalid type: integer `1`, expected a string",
+            ),
+            (
+                "[vars]\nhosts = [\"a\"]",
+                "hosts is not a string, number or boolean",
+            ),
+        ] {
+            let err = toml.parse::<ProjectConfig>().unwrap_err().to_string();
+            assert!(err.starts_with("invalid config: "), "{err}");
+            assert!(err.contains(error), "{err}");
+        }
+    }
+}
@@ -1,25 +1,214 @@
 use std::fmt::{Debug, Display, Formatter};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Result, anyhow};
 use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::MigrateError;
+use crate::checksum::ChecksumAlgorithm;
+use crate::template::TemplateVars;
 
 lazy_static! {
-    static ref FILENAME_REGEX: Regex =
-        regex::Regex::new(r"^[Vv](?P<version>[\d]{3})(?:[-_\da-zA-Z]*)?.cql$")
-            .expect("cql filename regex");
+    static ref FILENAME_REGEX: Regex = regex::Regex::new(
+        r"^[Vv](?P<version>[\d]+)(?:[-_\da-zA-Z]*)?(?:\.(?P<profile>[a-zA-Z][_\da-zA-Z]*))?\.cql$"
+    )
+    .expect("cql filename regex");
+    static ref REPEATABLE_FILENAME_REGEX: Regex = regex::Regex::new(
+        r"^[Rr]__(?:[-_\da-zA-Z]+)(?:\.(?P<profile>[a-zA-Z][_\da-zA-Z]*))?\.cql$"
+    )
+    .expect("repeatable cql filename regex");
 }
 
+/// Suffix of a cql file reverting the versioned cql file of the same name, which is not migrated.
+pub(crate) const DOWN_FILE_SUFFIX: &str = ".down.cql";
+
 #[derive(Clone, Debug)]
 pub struct CqlFile {
+    /// Algorithm of the file's [CqlFile::hash].
+    pub checksum_algorithm: ChecksumAlgorithm,
+    /// Content of a file embedded in the executable, which is read instead of the file's path.
+    pub(crate) embedded_cql: Option<&'static str>,
     pub filename: String,
     pub hash: String,
+    pub kind: CqlFileKind,
+    /// Whether the file was migrated after a file of a higher version, which is only migrated with
+    /// out of order migrations allowed.
+    pub out_of_order: bool,
     pub path: PathBuf,
-    pub version: i16,
+    /// Migrate profile of a file named with a profile suffix such as `v004-seed-accounts.dev.cql`,
+    /// which is only migrated with that profile.
+    pub profile: Option<String>,
+    /// Version of a [CqlFileKind::Versioned] file, which is 0 for a [CqlFileKind::Repeatable] file.
+    pub version: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CqlFileKind {
+    /// A `v001-name.cql` file migrated once in version order.
+    #[default]
+    Versioned,
+    /// A `R__name.cql` file migrated after versioned files whenever its content hash changes.
+    Repeatable,
+}
+
+impl CqlFileKind {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            CqlFileKind::Versioned => "versioned",
+            CqlFileKind::Repeatable => "repeatable",
+        }
+    }
+}
+
+/// VersionScheme is the format of the version prefix of versioned cql file names.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum VersionScheme {
+    /// Versions of 3 digits such as `v001-init.cql`, numbering a project's cql files up to 999.
+    #[default]
+    Sequential,
+    /// Versions of any number of digits such as `v1-init.cql` or `v0001-init.cql`.
+    Integer,
+    /// Versions of the UTC time a file was created as `yyyyMMddHHmmss` such as
+    /// `V20261018153000__init.cql`, which do not collide between teams adding cql files.
+    Timestamp,
+}
+
+impl VersionScheme {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            VersionScheme::Sequential => "sequential",
+            VersionScheme::Integer => "integer",
+            VersionScheme::Timestamp => "timestamp",
+        }
+    }
+
+    /// Errors with the first versioned cql file named with a version of a different scheme.
+    pub(crate) fn validate(&self, cql_files: &[CqlFile]) -> Result<()> {
+        for cql_file in cql_files {
+            let Some(version) = FILENAME_REGEX
+                .captures(&cql_file.filename)
+                .and_then(|captures| captures.name("version"))
+            else {
+                continue;
+            };
+            let version = version.as_str();
+            let valid = match self {
+                VersionScheme::Sequential => version.len() == 3,
+                VersionScheme::Integer => true,
+                VersionScheme::Timestamp => is_timestamp(version),
+            };
+            if !valid {
+                return Err(anyhow!(
+                    "{} is not named with a {} version such as {}",
+                    cql_file.filename,
+                    self.as_str(),
+                    match self {
+                        VersionScheme::Sequential => "v001",
+                        VersionScheme::Integer => "v1",
+                        VersionScheme::Timestamp => "v20261018153000",
+                    }
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// The version of a cql file created at `now` after `cql_files`.
+    fn next_version(&self, cql_files: &[CqlFile], now: SystemTime) -> Result<i64> {
+        let latest_version = cql_files
+            .iter()
+            .rfind(|cql_file| cql_file.kind == CqlFileKind::Versioned && cql_file.profile.is_none())
+            .map(|cql_file| cql_file.version);
+        match self {
+            VersionScheme::Sequential => {
+                let version = latest_version.map_or(1, |version| version + 1);
+                if version > 999 {
+                    Err(anyhow!("v999 is the last cql file version"))
+                } else {
+                    Ok(version)
+                }
+            }
+            VersionScheme::Integer => Ok(latest_version.map_or(1, |version| version + 1)),
+            VersionScheme::Timestamp => {
+                let version = timestamp_version(now);
+                match latest_version {
+                    Some(latest_version) if latest_version >= version => Err(anyhow!(
+                        "v{version} is not later than the latest cql file version v{latest_version}"
+                    )),
+                    _ => Ok(version),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for VersionScheme {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sequential" => Ok(VersionScheme::Sequential),
+            "integer" => Ok(VersionScheme::Integer),
+            "timestamp" => Ok(VersionScheme::Timestamp),
+            _ => Err(anyhow!("{s} is not a version scheme")),
+        }
+    }
+}
+
+/// Whether `version` is a `yyyyMMddHHmmss` timestamp.
+fn is_timestamp(version: &str) -> bool {
+    if version.len() != 14 {
+        return false;
+    }
+    let field = |range: std::ops::Range<usize>| version[range].parse::<u32>().unwrap_or_default();
+    (1..=12).contains(&field(4..6))
+        && (1..=31).contains(&field(6..8))
+        && field(8..10) < 24
+        && field(10..12) < 60
+        && field(12..14) < 60
+}
+
+/// Formats the UTC time of `time` as a `yyyyMMddHHmmss` version.
+fn timestamp_version(time: SystemTime) -> i64 {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let (days, seconds) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+    // converts days since 1970-01-01 to a proleptic gregorian calendar date
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 {
+        month_from_march + 3
+    } else {
+        month_from_march - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    ((year * 100 + month) * 100 + day) * 1_000_000
+        + seconds / 3600 * 10_000
+        + seconds % 3600 / 60 * 100
+        + seconds % 60
+}
+
+impl FromStr for CqlFileKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "versioned" => Ok(CqlFileKind::Versioned),
+            "repeatable" => Ok(CqlFileKind::Repeatable),
+            _ => Err(anyhow!("{s} is not a cql file kind")),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -36,42 +225,135 @@ impl Display for CqlFile {
 
 impl CqlFile {
     pub fn from_path(path: PathBuf) -> Result<CqlFile> {
-        let filename = path.file_name().unwrap().to_string_lossy().to_string();
-        if !FILENAME_REGEX.is_match(filename.as_str()) {
-            // todo use MigrateError for main.rs to handle error with
-            //  info about _ for .cql files to be omitted from migrate
-            return Err(anyhow!("{filename} is not a valid cql file name"));
-        }
         let hash = match fs::read(&path) {
-            Err(err) => return Err(anyhow!("failed reading file {}: {err}", filename)),
+            Err(err) => {
+                return Err(anyhow!(
+                    "failed reading file {}: {err}",
+                    path.file_name().unwrap().to_string_lossy()
+                ));
+            }
             Ok(file_content) => format!("{:x}", md5::compute(file_content)),
         };
-        let version = FILENAME_REGEX
-            .captures(filename.as_str())
-            .unwrap()
-            .name("version")
-            .unwrap()
-            .as_str()
-            .parse::<i16>()
-            .unwrap();
+        CqlFile::from_filename(path, hash, None)
+    }
+
+    /// A file embedded in the executable at `path` with its md5 `hash` computed at compile time.
+    pub(crate) fn from_embedded(path: PathBuf, hash: &str, cql: &'static str) -> Result<CqlFile> {
+        CqlFile::from_filename(path, hash.to_string(), Some(cql))
+    }
+
+    fn from_filename(
+        path: PathBuf,
+        hash: String,
+        embedded_cql: Option<&'static str>,
+    ) -> Result<CqlFile> {
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        let (kind, captures) = match FILENAME_REGEX.captures(filename.as_str()) {
+            Some(captures) => (CqlFileKind::Versioned, captures),
+            None => match REPEATABLE_FILENAME_REGEX.captures(filename.as_str()) {
+                Some(captures) => (CqlFileKind::Repeatable, captures),
+                // todo use MigrateError for main.rs to handle error with
+                //  info about _ for .cql files to be omitted from migrate
+                None => return Err(anyhow!("{filename} is not a valid cql file name")),
+            },
+        };
+        let version = match captures.name("version") {
+            None => 0,
+            Some(version) => match version.as_str().parse::<i64>() {
+                Err(_) => return Err(anyhow!("{filename} has a version that is too large")),
+                Ok(version) => version,
+            },
+        };
+        let profile = captures
+            .name("profile")
+            .map(|profile| profile.as_str().to_lowercase());
         Ok(CqlFile {
+            checksum_algorithm: ChecksumAlgorithm::Md5,
+            embedded_cql,
             filename,
             hash,
+            kind,
+            out_of_order: false,
             path,
+            profile,
             version,
         })
     }
 
-    pub(crate) fn read_statements(&self) -> Result<Vec<CqlStatement>, MigrateError> {
-        let cql = match fs::read_to_string(&self.path) {
-            Err(err) => {
-                return Err(MigrateError::CqlFileReadError {
-                    filename: self.filename.clone(),
-                    error: err.to_string(),
-                });
-            }
-            Ok(cql) => cql,
-        };
+    /// Hashes the file's content with `checksum_algorithm`, which is the file's [CqlFile::hash]
+    /// when it was hashed with the same algorithm.
+    pub(crate) fn checksum(
+        &self,
+        checksum_algorithm: ChecksumAlgorithm,
+    ) -> Result<String, MigrateError> {
+        if checksum_algorithm == self.checksum_algorithm {
+            return Ok(self.hash.clone());
+        }
+        let cql = self.read_bytes()?;
+        checksum_algorithm
+            .checksum(&cql)
+            .map_err(|err| anyhow!("failed hashing file {}: {err}", self.filename).into())
+    }
+
+    /// Path of the file relative to `cql_dir` with `/` separators, such as `2026-q3/v041-index.cql`
+    /// for a file in a subdirectory, recorded in the history table to detect moved files.
+    pub(crate) fn relative_path(&self, cql_dir: &Path) -> String {
+        match self.path.strip_prefix(cql_dir) {
+            Err(_) => self.filename.clone(),
+            Ok(relative_path) => relative_path
+                .components()
+                .map(|component| component.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        }
+    }
+
+    /// Rehashes the file with `checksum_algorithm` for recording in the history table.
+    pub(crate) fn with_checksum_algorithm(
+        &self,
+        checksum_algorithm: ChecksumAlgorithm,
+    ) -> Result<CqlFile, MigrateError> {
+        Ok(CqlFile {
+            checksum_algorithm,
+            hash: self.checksum(checksum_algorithm)?,
+            ..self.clone()
+        })
+    }
+
+    /// Reads the file's cql with its template placeholders rendered. The file's hash is of the
+    /// unrendered cql so it does not change with the values of template vars.
+    pub(crate) fn read_cql(&self, vars: &TemplateVars) -> Result<String, MigrateError> {
+        let cql = self.read_unrendered_cql()?;
+        vars.render(&cql)
+            .map_err(|err| MigrateError::CqlFileRenderError {
+                filename: self.filename.clone(),
+                error: err.to_string(),
+            })
+    }
+
+    /// Reads the file's cql without rendering its template placeholders.
+    pub(crate) fn read_unrendered_cql(&self) -> Result<String, MigrateError> {
+        String::from_utf8(self.read_bytes()?).map_err(|err| MigrateError::CqlFileReadError {
+            filename: self.filename.clone(),
+            error: err.to_string(),
+        })
+    }
+
+    fn read_bytes(&self) -> Result<Vec<u8>, MigrateError> {
+        match self.embedded_cql {
+            Some(cql) => Ok(cql.as_bytes().to_vec()),
+            None => fs::read(&self.path).map_err(|err| MigrateError::CqlFileReadError {
+                filename: self.filename.clone(),
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    pub(crate) fn read_statements(
+        &self,
+        vars: &TemplateVars,
+    ) -> Result<Vec<CqlStatement>, MigrateError> {
+        let cql = self.read_cql(vars)?;
 
         // todo parse an ast bc this is no bueno
         let mut block_comment_begin: Option<usize> = None;
@@ -158,37 +440,79 @@ impl CqlFile {
     }
 }
 
+/// Reads the versioned cql files of `cql_dir` in version order followed by its repeatable cql files
+/// in filename order. Profile-specific cql files share the version of another versioned cql file
+/// and are ordered after it.
 pub(crate) fn files_from_dir(cql_dir: &PathBuf) -> Result<Vec<CqlFile>> {
-    let cql_file_paths = read_cql_file_paths(cql_dir)?;
-    let mut cql_files: Vec<CqlFile> = Vec::with_capacity(cql_file_paths.len());
-    let mut expected_version: i16 = 1;
-    for path in cql_file_paths {
-        let cql_file = CqlFile::from_path(path)?;
-        if cql_file.version != expected_version {
-            return if cql_file.version == expected_version - 1 {
-                let previous_index = usize::try_from(expected_version - 2)?;
-                let previous_filename = &cql_files.get(previous_index).unwrap().filename;
-                Err(anyhow!(
-                    "{} and {} repeat versions instead of incrementing to v{:0>3}",
-                    previous_filename,
-                    cql_file.filename,
-                    expected_version
-                ))
-            } else {
-                Err(anyhow!(
-                    "{} found without a preceding v{:0>3} version cql file",
-                    cql_file.filename,
-                    expected_version
-                ))
-            };
+    let cql_files = read_cql_file_paths(cql_dir)?
+        .into_iter()
+        .map(CqlFile::from_path)
+        .collect::<Result<Vec<CqlFile>>>()?;
+    order_cql_files(cql_files)
+}
+
+/// Orders cql files for migrating like [files_from_dir], erroring with cql files repeating versions.
+pub(crate) fn order_cql_files(all_cql_files: Vec<CqlFile>) -> Result<Vec<CqlFile>> {
+    let mut cql_files: Vec<CqlFile> = Vec::with_capacity(all_cql_files.len());
+    let mut profile_files: Vec<CqlFile> = Vec::new();
+    let mut repeatable_files: Vec<CqlFile> = Vec::new();
+    for cql_file in all_cql_files {
+        if cql_file.kind == CqlFileKind::Repeatable {
+            repeatable_files.push(cql_file);
+        } else if cql_file.profile.is_some() {
+            profile_files.push(cql_file);
+        } else {
+            cql_files.push(cql_file);
+        }
+    }
+    // versions may skip numbers for files merged from branches to migrate out of order
+    cql_files.sort_by_key(|cql_file| cql_file.version);
+    for pair in cql_files.windows(2) {
+        if pair[0].version == pair[1].version {
+            return Err(anyhow!(
+                "{} and {} repeat versions instead of incrementing to v{:0>3}",
+                pair[0].filename,
+                pair[1].filename,
+                pair[1].version + 1
+            ));
         }
-        cql_files.push(cql_file);
-        expected_version += 1;
     }
+    cql_files.append(&mut profile_files);
+    cql_files.sort_by_key(|cql_file| (cql_file.version, cql_file.profile.is_some()));
+    repeatable_files.sort_by(|a, b| a.filename.cmp(&b.filename));
+    cql_files.append(&mut repeatable_files);
     Ok(cql_files)
 }
 
-fn read_cql_file_paths(cql_dir: &PathBuf) -> Result<Vec<PathBuf>> {
+/// Names the cql file migrated after `cql_files` with a version of `version_scheme` and the
+/// lowercase words of `description` joined by dashes, such as `v004-add-accounts-table.cql`.
+pub(crate) fn next_filename(
+    cql_files: &[CqlFile],
+    description: &str,
+    version_scheme: VersionScheme,
+) -> Result<String> {
+    version_scheme.validate(cql_files)?;
+    let version = version_scheme.next_version(cql_files, SystemTime::now())?;
+    let slug = description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_ascii_lowercase())
+        .collect::<Vec<String>>()
+        .join("-");
+    if slug.is_empty() {
+        return Err(anyhow!(
+            "description '{description}' does not have any letters or numbers"
+        ));
+    }
+    Ok(format!("v{version:0>3}-{slug}.cql"))
+}
+
+/// Whether `cql_dir` is a directory without any cql files to migrate.
+pub(crate) fn is_dir_without_cql_files(cql_dir: &PathBuf) -> bool {
+    cql_dir.is_dir() && read_cql_file_paths(cql_dir).is_err()
+}
+
+pub(crate) fn read_cql_file_paths(cql_dir: &PathBuf) -> Result<Vec<PathBuf>> {
     let dir_read = match fs::read_dir(cql_dir) {
         Err(_) => {
             return Err(anyhow!(
@@ -199,12 +523,7 @@ fn read_cql_file_paths(cql_dir: &PathBuf) -> Result<Vec<PathBuf>> {
         Ok(dir_read) => dir_read,
     };
     let mut cql_file_paths = Vec::new();
-    for dir_entry in dir_read {
-        let path = dir_entry?.path();
-        if is_inclusive_cql_filename(&path) {
-            cql_file_paths.push(path);
-        }
-    }
+    read_cql_file_paths_recursively(dir_read, &mut cql_file_paths)?;
     cql_file_paths.sort();
     if cql_file_paths.is_empty() {
         return Err(anyhow!(
@@ -215,12 +534,33 @@ fn read_cql_file_paths(cql_dir: &PathBuf) -> Result<Vec<PathBuf>> {
     Ok(cql_file_paths)
 }
 
+/// Reads the cql files of subdirectories such as `cql/2026-q3`, except subdirectories prefixed
+/// with `_` or `.` like cql files that are not migrated.
+fn read_cql_file_paths_recursively(
+    dir_read: fs::ReadDir,
+    cql_file_paths: &mut Vec<PathBuf>,
+) -> Result<()> {
+    for dir_entry in dir_read {
+        let path = dir_entry?.path();
+        if path.is_dir() {
+            let dir_name = path.file_name().unwrap_or_default().to_string_lossy();
+            if !dir_name.starts_with('_') && !dir_name.starts_with('.') {
+                read_cql_file_paths_recursively(fs::read_dir(&path)?, cql_file_paths)?;
+            }
+        } else if is_inclusive_cql_filename(&path) {
+            cql_file_paths.push(path);
+        }
+    }
+    Ok(())
+}
+
 fn is_inclusive_cql_filename(path: &Path) -> bool {
     if path.is_file()
         && let Some(file_name) = path.file_name()
         && let Some(extension) = path.extension()
         && extension == "cql"
         && !file_name.to_string_lossy().starts_with('_')
+        && !file_name.to_string_lossy().ends_with(DOWN_FILE_SUFFIX)
     {
         return true;
     }
@@ -241,6 +581,15 @@ mod tests {
         assert!(FILENAME_REGEX.is_match("v001-init-schema.cql"));
         assert!(FILENAME_REGEX.is_match("V002_add_column_families.cql"));
         assert!(!FILENAME_REGEX.is_match("init-schema.cql"));
+        assert!(FILENAME_REGEX.is_match("V20261018153000__add_accounts.cql"));
+        assert!(FILENAME_REGEX.is_match("v8.cql"));
+        assert!(FILENAME_REGEX.is_match("v003-seed-accounts.dev.cql"));
+        assert!(!FILENAME_REGEX.is_match("v003-seed-accounts.dev.staging.cql"));
+        assert!(REPEATABLE_FILENAME_REGEX.is_match("R__views.cql"));
+        assert!(REPEATABLE_FILENAME_REGEX.is_match("R__test-data.dev.cql"));
+        assert!(REPEATABLE_FILENAME_REGEX.is_match("r__account-views.cql"));
+        assert!(!REPEATABLE_FILENAME_REGEX.is_match("R_views.cql"));
+        assert!(!REPEATABLE_FILENAME_REGEX.is_match("R__.cql"));
     }
 
     #[test]
@@ -258,7 +607,7 @@ mod tests {
                 assert_eq!(cql_file.filename, String::from("v073-more_tables.cql"));
                 assert_eq!(cql_file.version, 73);
                 assert_eq!(cql_file.hash, "e995c628cf1a06863dc86760020ecb43");
-                let statements_result = cql_file.read_statements();
+                let statements_result = cql_file.read_statements(&TemplateVars::default());
                 assert!(statements_result.is_ok());
                 let statements = statements_result.unwrap();
                 assert_eq!(statements.len(), 1);
@@ -270,12 +619,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cql_file_read_statements_renders_template_vars() {
+        let temp_dir = TempDir::new().unwrap();
+        let cql_file_path = temp_dir.path().join("v001.cql");
+        make_file(
+            cql_file_path.clone(),
+            "create table ${keyspace}.accounts (id uuid primary key);",
+        );
+        let cql_file = CqlFile::from_path(cql_file_path).unwrap();
+        let mut vars = TemplateVars::default();
+        vars.insert("keyspace", "api").unwrap();
+
+        let statements = cql_file.read_statements(&vars).unwrap();
+        assert_eq!(
+            statements[0].cql,
+            "create table api.accounts (id uuid primary key)"
+        );
+        assert_eq!(cql_file.hash, "d067550c6e6184799a9ace080b14693e");
+        match cql_file.read_statements(&TemplateVars::default()) {
+            Err(MigrateError::CqlFileRenderError { filename, error }) => {
+                assert_eq!(filename, "v001.cql");
+                assert_eq!(error, "variable keyspace on line 1 is not defined");
+            }
+            _ => panic!("error was not a MigrateError::CqlFileRenderError"),
+        }
+    }
+
     fn read_statements_test(cql: &'static str, expected: Vec<CqlStatement>) {
         let temp_dir = TempDir::new().unwrap();
         let cql_file_path = temp_dir.path().join("v001-no_more_tests.cql");
         make_file(cql_file_path.clone(), cql);
         let cql_file = CqlFile::from_path(cql_file_path).expect("cql file");
-        let statements_result = cql_file.read_statements();
+        let statements_result = cql_file.read_statements(&TemplateVars::default());
         assert!(statements_result.is_ok());
         let statements = statements_result.unwrap();
         assert_eq!(statements.len(), expected.len());
@@ -497,7 +873,7 @@ mod tests {
     #[test]
     fn test_files_from_dir_allows_non_migrating_cql() {
         let temp_dir = TempDir::new().unwrap();
-        ["v001-foo.cql", "_foo.cql"]
+        ["v001-foo.cql", "v001-foo.down.cql", "_foo.cql"]
             .iter()
             .for_each(|f| make_file(temp_dir.path().join(f), ""));
         let temp_dir_path = temp_dir.path().canonicalize().unwrap();
@@ -515,22 +891,89 @@ mod tests {
     }
 
     #[test]
-    fn test_files_from_dir_errors_with_out_of_order_versions() {
+    fn test_files_from_dir_orders_repeatable_after_versioned() {
+        let temp_dir = TempDir::new().unwrap();
+        [
+            "v001-foo.cql",
+            "R__views.cql",
+            "v002-foo.cql",
+            "R__functions.cql",
+        ]
+        .iter()
+        .for_each(|f| make_file(temp_dir.path().join(f), ""));
+        let temp_dir_path = temp_dir.path().canonicalize().unwrap();
+
+        let cql_files = files_from_dir(&temp_dir_path).unwrap();
+        assert_eq!(
+            cql_files
+                .iter()
+                .map(|cql_file| (cql_file.filename.as_str(), cql_file.kind, cql_file.version))
+                .collect::<Vec<_>>(),
+            vec![
+                ("v001-foo.cql", CqlFileKind::Versioned, 1),
+                ("v002-foo.cql", CqlFileKind::Versioned, 2),
+                ("R__functions.cql", CqlFileKind::Repeatable, 0),
+                ("R__views.cql", CqlFileKind::Repeatable, 0),
+            ]
+        );
+        assert_eq!(
+            next_filename(&cql_files, "bar", VersionScheme::Sequential).unwrap(),
+            "v003-bar.cql"
+        );
+    }
+
+    #[test]
+    fn test_files_from_dir_orders_profile_files_after_version() {
+        let temp_dir = TempDir::new().unwrap();
+        [
+            "v001-foo.cql",
+            "v001-accounts.dev.cql",
+            "v002-foo.cql",
+            "v002-accounts.Staging.cql",
+        ]
+        .iter()
+        .for_each(|f| make_file(temp_dir.path().join(f), ""));
+        let temp_dir_path = temp_dir.path().canonicalize().unwrap();
+
+        let cql_files = files_from_dir(&temp_dir_path).unwrap();
+        assert_eq!(
+            cql_files
+                .iter()
+                .map(|cql_file| (cql_file.filename.as_str(), cql_file.profile.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("v001-foo.cql", None),
+                ("v001-accounts.dev.cql", Some("dev")),
+                ("v002-foo.cql", None),
+                ("v002-accounts.Staging.cql", Some("staging")),
+            ]
+        );
+        assert_eq!(
+            next_filename(&cql_files, "bar", VersionScheme::Sequential).unwrap(),
+            "v003-bar.cql"
+        );
+    }
+
+    #[test]
+    fn test_files_from_dir_allows_skipped_versions() {
         let temp_dir = TempDir::new().unwrap();
         ["v001-foo.cql", "v002-foo.cql", "v004-foo.cql"]
             .iter()
             .for_each(|f| make_file(temp_dir.path().join(f), ""));
         let temp_dir_path = temp_dir.path().canonicalize().unwrap();
 
-        match files_from_dir(&temp_dir_path) {
-            Ok(_) => panic!("cql::files_from_dir should have errored"),
-            Err(err) => {
-                assert_eq!(
-                    err.to_string(),
-                    "v004-foo.cql found without a preceding v003 version cql file"
-                );
-            }
-        }
+        let cql_files = files_from_dir(&temp_dir_path).unwrap();
+        assert_eq!(
+            cql_files
+                .iter()
+                .map(|cql_file| cql_file.version)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 4]
+        );
+        assert_eq!(
+            next_filename(&cql_files, "bar", VersionScheme::Sequential).unwrap(),
+            "v005-bar.cql"
+        );
     }
 
     #[test]
@@ -551,4 +994,147 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_next_filename() {
+        let temp_dir = TempDir::new().unwrap();
+        make_file(temp_dir.path().join("v001-init.cql"), "");
+        let cql_files = files_from_dir(&temp_dir.path().to_path_buf()).unwrap();
+
+        assert_eq!(
+            next_filename(&cql_files, "Add accounts_table!", VersionScheme::Sequential).unwrap(),
+            "v002-add-accounts-table.cql"
+        );
+        assert_eq!(
+            next_filename(&[], "init schema", VersionScheme::Sequential).unwrap(),
+            "v001-init-schema.cql"
+        );
+        assert_eq!(
+            next_filename(&[], "  --  ", VersionScheme::Sequential)
+                .unwrap_err()
+                .to_string(),
+            "description '  --  ' does not have any letters or numbers"
+        );
+    }
+
+    fn versioned_files(filenames: &[&str]) -> Vec<CqlFile> {
+        let temp_dir = TempDir::new().unwrap();
+        filenames
+            .iter()
+            .map(|filename| {
+                make_file(temp_dir.path().join(filename), "");
+                CqlFile::from_path(temp_dir.path().join(filename)).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_version_scheme_validate() {
+        let sequential = versioned_files(&["v001-init.cql", "R__views.cql"]);
+        let integer = versioned_files(&["v8-init.cql"]);
+        let timestamp = versioned_files(&["V20261018153000__init.cql"]);
+        assert!(VersionScheme::Sequential.validate(&sequential).is_ok());
+        assert!(VersionScheme::Integer.validate(&sequential).is_ok());
+        assert!(VersionScheme::Integer.validate(&integer).is_ok());
+        assert!(VersionScheme::Integer.validate(&timestamp).is_ok());
+        assert!(VersionScheme::Timestamp.validate(&timestamp).is_ok());
+        assert_eq!(timestamp[0].version, 20261018153000);
+        assert_eq!(
+            VersionScheme::Sequential
+                .validate(&integer)
+                .unwrap_err()
+                .to_string(),
+            "v8-init.cql is not named with a sequential version such as v001"
+        );
+        assert_eq!(
+            VersionScheme::Timestamp
+                .validate(&versioned_files(&["v20261318153000.cql"]))
+                .unwrap_err()
+                .to_string(),
+            "v20261318153000.cql is not named with a timestamp version such as v20261018153000"
+        );
+    }
+
+    #[test]
+    fn test_cql_file_errors_with_too_large_version() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("v99999999999999999999.cql");
+        make_file(path.clone(), "");
+        assert_eq!(
+            CqlFile::from_path(path).unwrap_err().to_string(),
+            "v99999999999999999999.cql has a version that is too large"
+        );
+    }
+
+    #[test]
+    fn test_timestamp_version() {
+        let at = |seconds: u64| UNIX_EPOCH + std::time::Duration::from_secs(seconds);
+        assert_eq!(timestamp_version(at(0)), 19700101000000);
+        assert_eq!(timestamp_version(at(1709251199)), 20240229235959);
+        assert_eq!(timestamp_version(at(1792337400)), 20261018153000);
+    }
+
+    #[test]
+    fn test_next_version() {
+        let now = UNIX_EPOCH + std::time::Duration::from_secs(1792337400);
+        let cql_files = versioned_files(&["v20261018150000-init.cql"]);
+        assert_eq!(
+            VersionScheme::Timestamp
+                .next_version(&cql_files, now)
+                .unwrap(),
+            20261018153000
+        );
+        assert_eq!(
+            VersionScheme::Timestamp
+                .next_version(&versioned_files(&["v20261018153000-init.cql"]), now)
+                .unwrap_err()
+                .to_string(),
+            "v20261018153000 is not later than the latest cql file version v20261018153000"
+        );
+        assert_eq!(
+            VersionScheme::Integer
+                .next_version(&versioned_files(&["v999-init.cql"]), now)
+                .unwrap(),
+            1000
+        );
+        assert_eq!(
+            VersionScheme::Sequential
+                .next_version(&versioned_files(&["v999-init.cql"]), now)
+                .unwrap_err()
+                .to_string(),
+            "v999 is the last cql file version"
+        );
+    }
+
+    #[test]
+    fn test_files_from_dir_reads_subdirectories() {
+        let temp_dir = TempDir::new().unwrap();
+        for dir in ["2026-q2", "2026-q3", "_drafts"] {
+            fs::create_dir(temp_dir.path().join(dir)).unwrap();
+        }
+        [
+            "v001-init.cql",
+            "2026-q3/v003-accounts.cql",
+            "2026-q3/R__views.cql",
+            "2026-q2/v002-emails.cql",
+            "_drafts/v004-sessions.cql",
+        ]
+        .iter()
+        .for_each(|f| make_file(temp_dir.path().join(f), ""));
+        let temp_dir_path = temp_dir.path().canonicalize().unwrap();
+
+        let cql_files = files_from_dir(&temp_dir_path).unwrap();
+        assert_eq!(
+            cql_files
+                .iter()
+                .map(|cql_file| cql_file.relative_path(&temp_dir_path))
+                .collect::<Vec<_>>(),
+            vec![
+                "v001-init.cql",
+                "2026-q2/v002-emails.cql",
+                "2026-q3/v003-accounts.cql",
+                "2026-q3/R__views.cql",
+            ]
+        );
+    }
 }
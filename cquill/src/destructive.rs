@@ -0,0 +1,363 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+use anyhow::anyhow;
+use cquill_ast::ast::{
+    AlterStatement, AlterTableInstruction, AlterTableStatement, CqlStatement as AstStatement,
+    CreateStatement, KeyspaceReplication,
+};
+use scylla::client::session::Session;
+use scylla::cluster::metadata::Strategy;
+
+use crate::MigrateError;
+use crate::cql_file::CqlFile;
+use crate::keyspace::KeyspaceMap;
+use crate::template::TemplateVars;
+
+/// Comment annotating a cql file whose destructive statements are allowed to migrate.
+pub(crate) const ALLOW_DESTRUCTIVE_ANNOTATION: &str = "cquill: allow-destructive";
+
+/// A pending statement that deletes schema or data, which migrate refuses to execute unless
+/// destructive cql is allowed.
+#[derive(Debug, PartialEq)]
+pub struct DestructiveCql {
+    pub filename: String,
+    pub line: usize,
+    pub change: DestructiveChange,
+    pub cql: String,
+}
+
+impl Display for DestructiveCql {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {} of {} {}: {}",
+            self.line, self.filename, self.change, self.cql
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DestructiveChange {
+    DropsColumns,
+    DropsSchema,
+    ReducesReplication,
+    TruncatesTable,
+    /// A statement cquill cannot parse yet, which is not known to be safe to migrate.
+    Unparsed,
+}
+
+impl Display for DestructiveChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            DestructiveChange::DropsColumns => "drops columns",
+            DestructiveChange::DropsSchema => "drops schema",
+            DestructiveChange::ReducesReplication => "reduces replication",
+            DestructiveChange::TruncatesTable => "truncates a table",
+            DestructiveChange::Unparsed => "may be destructive and cannot be parsed",
+        })
+    }
+}
+
+/// Replicas of a keyspace's data for comparing replication before and after altering a keyspace.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Replication {
+    NetworkTopology(HashMap<String, usize>),
+    Simple(usize),
+}
+
+impl Replication {
+    fn from_ast(replication: &KeyspaceReplication) -> Self {
+        match replication {
+            KeyspaceReplication::NetworkTopology(datacenters) => Replication::NetworkTopology(
+                datacenters
+                    .iter()
+                    .map(|(datacenter, factor)| (datacenter.clone(), *factor as usize))
+                    .collect(),
+            ),
+            KeyspaceReplication::Simple(factor) => Replication::Simple(*factor as usize),
+        }
+    }
+
+    fn from_strategy(strategy: &Strategy) -> Option<Self> {
+        match strategy {
+            Strategy::NetworkTopologyStrategy {
+                datacenter_repfactors,
+            } => Some(Replication::NetworkTopology(datacenter_repfactors.clone())),
+            Strategy::SimpleStrategy { replication_factor } => {
+                Some(Replication::Simple(*replication_factor))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether any datacenter keeps fewer replicas with `to` than with this replication.
+    fn is_reduced_by(&self, to: &Replication) -> bool {
+        match (self, to) {
+            (Replication::Simple(from), Replication::Simple(to)) => to < from,
+            (Replication::Simple(from), Replication::NetworkTopology(to)) => {
+                to.is_empty() || to.values().any(|factor| factor < from)
+            }
+            (Replication::NetworkTopology(from), Replication::Simple(to)) => {
+                from.values().any(|factor| factor > to)
+            }
+            (Replication::NetworkTopology(from), Replication::NetworkTopology(to)) => {
+                from.iter().any(|(datacenter, factor)| {
+                    to.get(datacenter).copied().unwrap_or_default() < *factor
+                })
+            }
+        }
+    }
+}
+
+/// Finds the destructive statements of pending cql files that are not annotated with
+/// [ALLOW_DESTRUCTIVE_ANNOTATION], comparing altered keyspace replication with the cluster's.
+pub(crate) fn find_destructive_cql(
+    session: &Session,
+    pending: &[CqlFile],
+    keyspace_map: &KeyspaceMap,
+    vars: &TemplateVars,
+) -> Result<Vec<DestructiveCql>, MigrateError> {
+    let cluster_state = session.get_cluster_state();
+    classify_pending(pending, vars, |keyspace_name| {
+        let keyspace_name = keyspace_map
+            .get(keyspace_name)
+            .map(String::as_str)
+            .unwrap_or(keyspace_name);
+        cluster_state
+            .get_keyspace(keyspace_name)
+            .and_then(|keyspace| Replication::from_strategy(&keyspace.strategy))
+    })
+}
+
+fn classify_pending(
+    pending: &[CqlFile],
+    vars: &TemplateVars,
+    cluster_replication: impl Fn(&str) -> Option<Replication>,
+) -> Result<Vec<DestructiveCql>, MigrateError> {
+    let mut replications: HashMap<String, Replication> = HashMap::new();
+    let mut destructive = Vec::new();
+    for cql_file in pending {
+        let cql = cql_file.read_cql(vars)?;
+        let allowed = cql.lines().any(|line| {
+            let line = line.trim_start();
+            (line.starts_with("--") || line.starts_with("//"))
+                && line.contains(ALLOW_DESTRUCTIVE_ANNOTATION)
+        });
+        let statements = match cquill_ast::parse_cql_script(cql.as_str()) {
+            Err(err) => return Err(anyhow!("failed parsing file {cql_file}: {err}").into()),
+            Ok(statements) => statements,
+        };
+        for script_statement in statements {
+            let statement = match script_statement.statement {
+                Ok(statement) => statement,
+                Err(_) => {
+                    if !allowed {
+                        destructive.push(DestructiveCql {
+                            filename: cql_file.filename.clone(),
+                            line: script_statement.line,
+                            change: DestructiveChange::Unparsed,
+                            cql: cql
+                                .lines()
+                                .nth(script_statement.line - 1)
+                                .unwrap_or_default()
+                                .trim()
+                                .to_string(),
+                        });
+                    }
+                    continue;
+                }
+            };
+            let change = match &statement {
+                AstStatement::Alter(AlterStatement::Keyspace(alter_keyspace)) => {
+                    let Some(replication) = &alter_keyspace.replication else {
+                        continue;
+                    };
+                    let keyspace_name = alter_keyspace.keyspace_name.value().to_lowercase();
+                    let to = Replication::from_ast(replication);
+                    let from = replications
+                        .get(&keyspace_name)
+                        .cloned()
+                        .or_else(|| cluster_replication(&keyspace_name));
+                    replications.insert(keyspace_name, to.clone());
+                    match from {
+                        Some(from) if from.is_reduced_by(&to) => {
+                            DestructiveChange::ReducesReplication
+                        }
+                        _ => continue,
+                    }
+                }
+                AstStatement::Alter(AlterStatement::Table(AlterTableStatement {
+                    instruction: AlterTableInstruction::DropColumns { .. },
+                    ..
+                })) => DestructiveChange::DropsColumns,
+                AstStatement::Create(CreateStatement::Keyspace(create_keyspace)) => {
+                    replications.insert(
+                        create_keyspace.keyspace_name.value().to_lowercase(),
+                        Replication::from_ast(&create_keyspace.replication),
+                    );
+                    continue;
+                }
+                AstStatement::Drop(_) => DestructiveChange::DropsSchema,
+                AstStatement::Truncate(_) => DestructiveChange::TruncatesTable,
+                _ => continue,
+            };
+            if !allowed {
+                destructive.push(DestructiveCql {
+                    filename: cql_file.filename.clone(),
+                    line: script_statement.line,
+                    change,
+                    cql: statement.to_string(),
+                });
+            }
+        }
+    }
+    Ok(destructive)
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::test_utils::make_file;
+
+    use super::*;
+
+    fn classify(
+        cql_files: &[(&str, &str)],
+        cluster_replication: Option<Replication>,
+    ) -> Vec<String> {
+        let temp_dir = TempDir::new().unwrap();
+        let pending: Vec<CqlFile> = cql_files
+            .iter()
+            .map(|(filename, cql)| {
+                make_file(temp_dir.path().join(filename), cql);
+                CqlFile::from_path(temp_dir.path().join(filename)).unwrap()
+            })
+            .collect();
+        classify_pending(&pending, &TemplateVars::default(), |_| {
+            cluster_replication.clone()
+        })
+        .unwrap()
+        .iter()
+        .map(DestructiveCql::to_string)
+        .collect()
+    }
+
+    #[test]
+    fn test_classify_destructive_cql() {
+        let destructive = classify(
+            &[
+                (
+                    "v001.cql",
+                    "create table api.accounts (id uuid primary key, name text);
+alter table api.accounts add email text;
+alter table api.accounts drop name;",
+                ),
+                (
+                    "v002.cql",
+                    "truncate api.accounts;\n\ndrop table\n    api.accounts;\nselect * from api.accounts;",
+                ),
+            ],
+            None,
+        );
+        assert_eq!(
+            destructive,
+            vec![
+                "line 3 of v001.cql drops columns: ALTER TABLE api.accounts DROP name",
+                "line 1 of v002.cql truncates a table: TRUNCATE api.accounts",
+                "line 3 of v002.cql drops schema: DROP TABLE api.accounts",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_replication_reduction() {
+        let cluster_replication = Some(Replication::NetworkTopology(HashMap::from([
+            ("dc1".to_string(), 3),
+            ("dc2".to_string(), 3),
+        ])));
+        let destructive = classify(
+            &[(
+                "v001.cql",
+                "alter keyspace api with replication = {'class': 'NetworkTopologyStrategy', 'dc1': 3, 'dc2': 3, 'dc3': 3};
+alter keyspace api with replication = {'class': 'NetworkTopologyStrategy', 'dc1': 3, 'dc2': 3};
+alter keyspace api with durable_writes = false;
+alter keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 3};
+alter keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};
+create keyspace logs with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};
+alter keyspace logs with replication = {'class': 'SimpleStrategy', 'replication_factor': 2};",
+            )],
+            cluster_replication,
+        );
+        assert_eq!(
+            destructive,
+            vec![
+                "line 2 of v001.cql reduces replication: ALTER KEYSPACE api WITH replication = {'class': 'NetworkTopologyStrategy', 'dc1': 3, 'dc2': 3}",
+                "line 5 of v001.cql reduces replication: ALTER KEYSPACE api WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1}",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_allowed_by_annotation() {
+        let destructive = classify(
+            &[
+                (
+                    "v001.cql",
+                    "-- cquill: allow-destructive\ndrop table api.sessions;",
+                ),
+                ("v002.cql", "drop table api.accounts;"),
+            ],
+            None,
+        );
+        assert_eq!(
+            destructive,
+            vec!["line 1 of v002.cql drops schema: DROP TABLE api.accounts"]
+        );
+    }
+
+    #[test]
+    fn test_classify_unparsed_cql() {
+        let cql = "alter table api.accounts drop name using timestamp 10;
+alter table api.accounts drop (name, email);
+drop table api.\"Accounts\";";
+        assert_eq!(
+            classify(&[("v001.cql", cql)], None),
+            vec![
+                "line 1 of v001.cql may be destructive and cannot be parsed: alter table api.accounts drop name using timestamp 10;",
+                "line 2 of v001.cql drops columns: ALTER TABLE api.accounts DROP (name, email)",
+                "line 3 of v001.cql drops schema: DROP TABLE api.\"Accounts\"",
+            ]
+        );
+        let allowed = format!("-- {ALLOW_DESTRUCTIVE_ANNOTATION}\n{cql}");
+        assert!(classify(&[("v001.cql", allowed.as_str())], None).is_empty());
+    }
+
+    #[test]
+    fn test_replication_reduction() {
+        let simple = Replication::Simple;
+        let network_topology = |datacenters: &[(&str, usize)]| {
+            Replication::NetworkTopology(
+                datacenters
+                    .iter()
+                    .map(|(datacenter, factor)| (datacenter.to_string(), *factor))
+                    .collect(),
+            )
+        };
+        assert!(simple(3).is_reduced_by(&simple(2)));
+        assert!(!simple(2).is_reduced_by(&simple(3)));
+        assert!(!simple(3).is_reduced_by(&network_topology(&[("dc1", 3)])));
+        assert!(simple(3).is_reduced_by(&network_topology(&[("dc1", 3), ("dc2", 1)])));
+        assert!(network_topology(&[("dc1", 3)]).is_reduced_by(&simple(1)));
+        assert!(!network_topology(&[("dc1", 3)]).is_reduced_by(&simple(3)));
+        assert!(
+            network_topology(&[("dc1", 3), ("dc2", 3)])
+                .is_reduced_by(&network_topology(&[("dc1", 3)]))
+        );
+        assert!(
+            !network_topology(&[("dc1", 3)])
+                .is_reduced_by(&network_topology(&[("dc1", 3), ("dc2", 3)]))
+        );
+    }
+}
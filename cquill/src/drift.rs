@@ -0,0 +1,352 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use cquill_ast::ast::ClusteringOrder;
+use cquill_ast::schema::{
+    ClusteringColumnSchema, ColumnSchema, FieldSchema, IndexSchema, KeyspaceSchema, SchemaCatalog,
+    SchemaDiff, SchemaObjectType, TableSchema, TypeSchema, ViewSchema,
+};
+use scylla::client::session::Session;
+use scylla::cluster::metadata::{CollectionType, ColumnKind, ColumnType, NativeType, Table};
+
+use crate::queries::system_schema;
+use crate::verify::{VerifyOpts, verify_cql};
+use crate::{CassandraOpts, cql_session};
+
+pub struct DriftOpts {
+    pub cassandra_opts: Option<CassandraOpts>,
+    pub cql_dir: PathBuf,
+}
+
+/// `drift_cql` compares the schema created by the cql scripts in [DriftOpts::cql_dir] with the
+/// schema of the cluster's keyspaces of the same names. Diffs are from the cql scripts' schema
+/// to the cluster's, so [SchemaDiff::Added] is an object that only exists on the cluster.
+///
+/// Tables, columns, types, indexes and materialized views are compared. Keyspace replication and
+/// table options are not.
+pub async fn drift_cql(opts: DriftOpts) -> Result<Vec<SchemaDiff>> {
+    let node_address = opts.cassandra_opts.unwrap_or_default().node_address();
+    let session = cql_session(node_address).await?;
+    drift(&session, opts.cql_dir).await
+}
+
+pub(crate) async fn drift(session: &Session, cql_dir: PathBuf) -> Result<Vec<SchemaDiff>> {
+    let migrated = verify_cql(VerifyOpts { cql_dir })?.schema;
+    let cluster = cluster_schema(session, &migrated).await?;
+    Ok(migrated
+        .diff(&cluster)
+        .into_iter()
+        .filter(|diff| match diff.object_type() {
+            SchemaObjectType::Column
+            | SchemaObjectType::Field
+            | SchemaObjectType::Index
+            | SchemaObjectType::Table
+            | SchemaObjectType::Type => true,
+            SchemaObjectType::Keyspace | SchemaObjectType::MaterializedView => {
+                !matches!(diff, SchemaDiff::Changed { .. })
+            }
+            SchemaObjectType::Aggregate
+            | SchemaObjectType::Function
+            | SchemaObjectType::Role
+            | SchemaObjectType::TableOption => false,
+        })
+        .collect())
+}
+
+/// Builds a catalog of the cluster's schema for the keyspaces of `migrated`. Objects that are not
+/// compared for drift are copied from `migrated`.
+pub(crate) async fn cluster_schema(
+    session: &Session,
+    migrated: &SchemaCatalog,
+) -> Result<SchemaCatalog> {
+    let cluster_state = session.get_cluster_state();
+    let mut cluster = SchemaCatalog::new();
+    for (keyspace_name, migrated_keyspace) in &migrated.keyspaces {
+        let keyspace = match cluster_state.get_keyspace(keyspace_name) {
+            None => continue,
+            Some(keyspace) => keyspace,
+        };
+        let clustering_orders = system_schema::select_clustering_orders(session, keyspace_name)
+            .await?
+            .into_iter()
+            .map(|(table_name, column_name, order)| ((table_name, column_name), order))
+            .collect::<BTreeMap<(String, String), String>>();
+        let clustering_key = |table_name: &String, table: &Table| {
+            table
+                .clustering_key
+                .iter()
+                .map(|column_name| ClusteringColumnSchema {
+                    name: column_name.clone(),
+                    order: match clustering_orders
+                        .get(&(table_name.clone(), column_name.clone()))
+                        .map(String::as_str)
+                    {
+                        Some("desc") => ClusteringOrder::Desc,
+                        _ => ClusteringOrder::Asc,
+                    },
+                })
+                .collect::<Vec<ClusteringColumnSchema>>()
+        };
+        let mut tables = BTreeMap::new();
+        for (table_name, table) in &keyspace.tables {
+            let migrated_table = migrated_keyspace.tables.get(table_name);
+            tables.insert(
+                table_name.clone(),
+                TableSchema {
+                    columns: table_columns(table),
+                    partition_key: table.partition_key.clone(),
+                    clustering_key: clustering_key(table_name, table),
+                    compact_storage: migrated_table.is_some_and(|table| table.compact_storage),
+                    options: migrated_table
+                        .map(|table| table.options.clone())
+                        .unwrap_or_default(),
+                },
+            );
+        }
+        let mut types = BTreeMap::new();
+        for (type_name, user_defined_type) in &keyspace.user_defined_types {
+            types.insert(
+                type_name.clone(),
+                TypeSchema {
+                    fields: user_defined_type
+                        .field_types
+                        .iter()
+                        .map(|(field_name, field_type)| FieldSchema {
+                            name: field_name.to_string(),
+                            data_type: column_type_cql(field_type),
+                        })
+                        .collect(),
+                },
+            );
+        }
+        let mut indexes = BTreeMap::new();
+        for (table_name, index_name, target) in
+            system_schema::select_indexes(session, keyspace_name).await?
+        {
+            indexes.insert(index_name, IndexSchema { table_name, target });
+        }
+        let mut where_clauses: BTreeMap<String, String> =
+            system_schema::select_view_where_clauses(session, keyspace_name)
+                .await?
+                .into_iter()
+                .collect();
+        let mut views = BTreeMap::new();
+        for (view_name, view) in &keyspace.views {
+            let view_schema = match migrated_keyspace.views.get(view_name) {
+                Some(migrated_view) => migrated_view.clone(),
+                None => ViewSchema {
+                    base_table_name: view.base_table_name.clone(),
+                    columns: table_columns(&view.view_metadata),
+                    where_clause: where_clauses.remove(view_name).unwrap_or_default(),
+                    partition_key: view.view_metadata.partition_key.clone(),
+                    clustering_key: clustering_key(view_name, &view.view_metadata),
+                    options: BTreeMap::new(),
+                },
+            };
+            views.insert(view_name.clone(), view_schema);
+        }
+        cluster.keyspaces.insert(
+            keyspace_name.clone(),
+            KeyspaceSchema {
+                tables,
+                types,
+                indexes,
+                views,
+                ..migrated_keyspace.clone()
+            },
+        );
+    }
+    Ok(cluster)
+}
+
+/// Columns of a table's metadata in primary key order followed by the remaining columns sorted by
+/// name, because the cluster does not retain the order columns were declared in.
+fn table_columns(table: &Table) -> Vec<ColumnSchema> {
+    let mut column_names: Vec<&String> = table
+        .partition_key
+        .iter()
+        .chain(table.clustering_key.iter())
+        .collect();
+    let mut other_column_names: Vec<&String> = table
+        .columns
+        .keys()
+        .filter(|column_name| !column_names.contains(column_name))
+        .collect();
+    other_column_names.sort();
+    column_names.append(&mut other_column_names);
+    column_names
+        .into_iter()
+        .map(|column_name| {
+            let column = &table.columns[column_name];
+            ColumnSchema {
+                name: column_name.clone(),
+                data_type: column_type_cql(&column.typ),
+                is_static: column.kind == ColumnKind::Static,
+            }
+        })
+        .collect()
+}
+
+/// Renders a column type from the cluster's metadata as it would be declared in CQL.
+fn column_type_cql(column_type: &ColumnType) -> String {
+    let frozen = |frozen: bool, cql: String| {
+        if frozen {
+            format!("frozen<{cql}>")
+        } else {
+            cql
+        }
+    };
+    match column_type {
+        ColumnType::Native(native_type) => native_type_cql(native_type).to_string(),
+        ColumnType::Collection { frozen: f, typ } => frozen(
+            *f,
+            match typ {
+                CollectionType::List(element_type) => {
+                    format!("list<{}>", column_type_cql(element_type))
+                }
+                CollectionType::Map(key_type, value_type) => format!(
+                    "map<{}, {}>",
+                    column_type_cql(key_type),
+                    column_type_cql(value_type)
+                ),
+                CollectionType::Set(element_type) => {
+                    format!("set<{}>", column_type_cql(element_type))
+                }
+                _ => format!("{typ:?}"),
+            },
+        ),
+        ColumnType::Vector { typ, dimensions } => {
+            format!("vector<{}, {dimensions}>", column_type_cql(typ))
+        }
+        ColumnType::UserDefinedType {
+            frozen: f,
+            definition,
+        } => frozen(*f, definition.name.to_string()),
+        ColumnType::Tuple(element_types) => format!(
+            "tuple<{}>",
+            element_types
+                .iter()
+                .map(column_type_cql)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        _ => format!("{column_type:?}"),
+    }
+}
+
+fn native_type_cql(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Ascii => "ascii",
+        NativeType::BigInt => "bigint",
+        NativeType::Blob => "blob",
+        NativeType::Boolean => "boolean",
+        NativeType::Counter => "counter",
+        NativeType::Date => "date",
+        NativeType::Decimal => "decimal",
+        NativeType::Double => "double",
+        NativeType::Duration => "duration",
+        NativeType::Float => "float",
+        NativeType::Inet => "inet",
+        NativeType::Int => "int",
+        NativeType::SmallInt => "smallint",
+        NativeType::Text => "text",
+        NativeType::Time => "time",
+        NativeType::Timestamp => "timestamp",
+        NativeType::Timeuuid => "timeuuid",
+        NativeType::TinyInt => "tinyint",
+        NativeType::Uuid => "uuid",
+        NativeType::Varint => "varint",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils;
+
+    use super::*;
+
+    #[test]
+    fn test_column_type_cql() {
+        assert_eq!(
+            column_type_cql(&ColumnType::Native(NativeType::Text)),
+            "text"
+        );
+        assert_eq!(
+            column_type_cql(&ColumnType::Collection {
+                frozen: true,
+                typ: CollectionType::Map(
+                    Box::new(ColumnType::Native(NativeType::Text)),
+                    Box::new(ColumnType::Collection {
+                        frozen: false,
+                        typ: CollectionType::List(Box::new(ColumnType::Native(NativeType::Int))),
+                    }),
+                ),
+            }),
+            "frozen<map<text, list<int>>>"
+        );
+        assert_eq!(
+            column_type_cql(&ColumnType::Tuple(vec![
+                ColumnType::Native(NativeType::Uuid),
+                ColumnType::Native(NativeType::Timestamp),
+            ])),
+            "tuple<uuid, timestamp>"
+        );
+        assert_eq!(
+            column_type_cql(&ColumnType::Vector {
+                typ: Box::new(ColumnType::Native(NativeType::Float)),
+                dimensions: 3,
+            }),
+            "vector<float, 3>"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drift_reports_cluster_only_and_cql_only_schema() {
+        let keyspace_name = test_utils::keyspace_name();
+        let harness = test_utils::TestHarness::builder()
+            .cql_file(
+                "v001.cql",
+                format!(
+                    "create keyspace {keyspace_name} with replication = {{'class': 'SimpleStrategy', 'replication_factor': 1}};\ncreate table {keyspace_name}.accounts (id uuid primary key, name text, email text);"
+                )
+                .as_str(),
+            )
+            .initialize()
+            .await;
+        for cql in [
+            format!(
+                "create keyspace {keyspace_name} with replication = {{'class': 'SimpleStrategy', 'replication_factor': 1}}"
+            ),
+            format!(
+                "create table {keyspace_name}.accounts (id uuid primary key, name int, phone text)"
+            ),
+            format!("create table {keyspace_name}.invoices (id uuid primary key)"),
+        ] {
+            harness.session.query_unpaged(cql, ()).await.unwrap();
+        }
+        harness.session.refresh_metadata().await.unwrap();
+
+        let diffs = drift(&harness.session, harness.cql_dir.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            diffs
+                .iter()
+                .map(|diff| diff.to_string())
+                .collect::<Vec<String>>(),
+            vec![
+                format!("- column {keyspace_name}.accounts.email"),
+                format!("~ column {keyspace_name}.accounts.name: text -> int"),
+                format!("+ column {keyspace_name}.accounts.phone"),
+                format!("+ table {keyspace_name}.invoices"),
+            ]
+        );
+
+        crate::queries::keyspace::drop(&harness.session, &keyspace_name)
+            .await
+            .unwrap();
+        harness.drop_keyspace().await;
+    }
+}
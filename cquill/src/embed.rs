@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+
+use crate::cql_file::{self, CqlFile};
+
+/// A cql file embedded in the executable by [embed_migrations](crate::embed_migrations).
+#[doc(hidden)]
+pub struct EmbeddedCql {
+    /// Path relative to the embedded cql dir with `/` separators.
+    pub path: &'static str,
+    pub cql: &'static str,
+    /// MD5 of the cql computed at compile time.
+    pub hash: &'static str,
+}
+
+/// The cql files of a cql dir embedded in the executable by
+/// [embed_migrations](crate::embed_migrations) for [migrate_embedded](crate::migrate_embedded),
+/// for services deployed without their cql dir.
+#[derive(Clone, Copy)]
+pub struct EmbeddedMigrations {
+    cql_dir: &'static str,
+    files: &'static [EmbeddedCql],
+}
+
+impl EmbeddedMigrations {
+    #[doc(hidden)]
+    pub const fn new(cql_dir: &'static str, files: &'static [EmbeddedCql]) -> Self {
+        EmbeddedMigrations { cql_dir, files }
+    }
+
+    /// Path of the embedded cql dir relative to the crate's `Cargo.toml`, which is the cql dir
+    /// that history table paths of embedded cql files are relative to.
+    pub fn cql_dir(&self) -> PathBuf {
+        PathBuf::from(self.cql_dir)
+    }
+
+    /// The embedded cql files in the order they are migrated.
+    pub fn cql_files(&self) -> Result<Vec<CqlFile>> {
+        if self.files.is_empty() {
+            return Err(anyhow!(
+                "no cql files embedded from directory '{}'",
+                self.cql_dir
+            ));
+        }
+        let cql_dir = self.cql_dir();
+        let cql_files = self
+            .files
+            .iter()
+            .map(|embedded| {
+                let path = embedded
+                    .path
+                    .split('/')
+                    .fold(cql_dir.clone(), |path, component| path.join(component));
+                CqlFile::from_embedded(path, embedded.hash, embedded.cql)
+            })
+            .collect::<Result<Vec<CqlFile>>>()?;
+        cql_file::order_cql_files(cql_files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::template::TemplateVars;
+    use crate::{ChecksumAlgorithm, embed_migrations};
+
+    use super::*;
+
+    #[test]
+    fn test_embedded_cql_files_match_cql_dir() {
+        let embedded = embed_migrations!("examples/cql");
+        let cql_files = cql_file::files_from_dir(
+            &PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("examples/cql"),
+        )
+        .unwrap();
+        let embedded_cql_files = embedded.cql_files().unwrap();
+        assert_eq!(embedded.cql_dir(), PathBuf::from("examples/cql"));
+        assert_eq!(embedded_cql_files.len(), cql_files.len());
+        for (embedded_cql_file, cql_file) in embedded_cql_files.iter().zip(&cql_files) {
+            assert_eq!(embedded_cql_file.filename, cql_file.filename);
+            assert_eq!(embedded_cql_file.version, cql_file.version);
+            assert_eq!(embedded_cql_file.hash, cql_file.hash);
+            assert_eq!(
+                embedded_cql_file.relative_path(&embedded.cql_dir()),
+                cql_file.filename
+            );
+            assert_eq!(
+                embedded_cql_file
+                    .read_cql(&TemplateVars::default())
+                    .unwrap(),
+                cql_file.read_cql(&TemplateVars::default()).unwrap()
+            );
+            assert_eq!(
+                embedded_cql_file
+                    .checksum(ChecksumAlgorithm::Sha256)
+                    .unwrap(),
+                cql_file.checksum(ChecksumAlgorithm::Sha256).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_embedded_cql_files_error_without_files() {
+        assert_eq!(
+            EmbeddedMigrations::new("cql", &[])
+                .cql_files()
+                .unwrap_err()
+                .to_string(),
+            "no cql files embedded from directory 'cql'"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Result, anyhow};
+
+use crate::cql_file;
+use crate::{MigrateOpts, Migrator};
+
+pub struct FmtOpts {
+    pub cql_dir: PathBuf,
+    pub check: bool,
+    /// Migration whose history tables record cql files that are not rewritten or checked, since
+    /// rewriting a migrated cql file changes its checksum and errors the next migration. Without,
+    /// every cql file is formatted. The history tables are read without creating or upgrading them.
+    pub skip_migrated: Option<MigrateOpts>,
+}
+
+/// `fmt_cql` rewrites the cql scripts in [FmtOpts::cql_dir] with each statement in its canonical
+/// form, except cql scripts migrated according to [FmtOpts::skip_migrated]. With [FmtOpts::check]
+/// files are left as is. A successful method result contains a vec of the cql script paths that
+/// were not already formatted.
+pub async fn fmt_cql(opts: FmtOpts) -> Result<Vec<PathBuf>> {
+    let migrated = match opts.skip_migrated {
+        None => Vec::new(),
+        Some(migrate_opts) => migrated_paths(migrate_opts, &opts.cql_dir).await?,
+    };
+    let mut unformatted = Vec::new();
+    for path in cql_file::read_cql_file_paths(&opts.cql_dir)? {
+        if migrated.contains(&path) {
+            continue;
+        }
+        let filename = path.file_name().unwrap().to_string_lossy().to_string();
+        let cql = match fs::read_to_string(&path) {
+            Err(err) => return Err(anyhow!("failed reading file {filename}: {err}")),
+            Ok(cql) => cql,
+        };
+        let formatted = match cquill_ast::format_cql(cql.as_str()) {
+            Err(err) => return Err(anyhow!("failed formatting file {filename}: {err}")),
+            Ok(formatted) => formatted,
+        };
+        if formatted != cql {
+            if !opts.check
+                && let Err(err) = fs::write(&path, formatted)
+            {
+                return Err(anyhow!("failed writing file {filename}: {err}"));
+            }
+            unformatted.push(path);
+        }
+    }
+    Ok(unformatted)
+}
+
+/// Paths of the cql files recorded in the history tables of a migration of `cql_dir`.
+async fn migrated_paths(migrate_opts: MigrateOpts, cql_dir: &Path) -> Result<Vec<PathBuf>> {
+    let migrator = Migrator::builder()
+        .opts(migrate_opts)
+        .cql_dir(cql_dir.to_path_buf())
+        .build()
+        .await?;
+    let statuses = match migrator.status().await {
+        Err(err) => return Err(anyhow!("failed reading migrated cql files: {err}")),
+        Ok(statuses) => statuses,
+    };
+    Ok(statuses
+        .into_iter()
+        .flat_map(|status| status.migrated)
+        .map(|cql_file| cql_file.path)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::keyspace::KeyspaceOpts;
+    use crate::test_utils::{self, TestHarness, make_file};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fmt_cql_rewrites_unformatted_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let formatted_path = temp_dir.path().join("v001.cql");
+        let unformatted_path = temp_dir.path().join("v002.cql");
+        make_file(formatted_path.clone(), "USE big_data_keyspace;\n");
+        make_file(unformatted_path.clone(), "use  big_data_keyspace\n");
+
+        let result = fmt_cql(FmtOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            check: false,
+            skip_migrated: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, vec!(unformatted_path.clone()));
+        assert_eq!(
+            fs::read_to_string(&unformatted_path).unwrap(),
+            "USE big_data_keyspace;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fmt_cql_check_does_not_rewrite_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let unformatted_path = temp_dir.path().join("v001.cql");
+        make_file(unformatted_path.clone(), "use big_data_keyspace;\n");
+
+        let result = fmt_cql(FmtOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            check: true,
+            skip_migrated: None,
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, vec!(unformatted_path.clone()));
+        assert_eq!(
+            fs::read_to_string(&unformatted_path).unwrap(),
+            "use big_data_keyspace;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fmt_cql_skips_migrated_files() {
+        let harness = TestHarness::builder()
+            .cql_file("v001.cql", "create table accounts (id uuid primary key);\n")
+            .cql_file("v002.cql", "alter table accounts add name text;\n")
+            .initialize()
+            .await;
+        let migrate_opts = || MigrateOpts {
+            cql_dir: harness.cql_dir.clone(),
+            history_keyspace: Some(KeyspaceOpts::simple(harness.cquill_keyspace.clone(), 1)),
+            history_table: Some(harness.cquill_table.clone()),
+            ..Default::default()
+        };
+        let migrator = Migrator::builder()
+            .session(test_utils::cql_session().await)
+            .opts(migrate_opts())
+            .build()
+            .await
+            .expect("build migrator");
+        migrator.migrate().await.expect("migrate");
+        let pending_path = harness.cql_file_path("v003.cql");
+        make_file(
+            pending_path.clone(),
+            "alter table accounts add email text;\n",
+        );
+
+        let result = fmt_cql(FmtOpts {
+            cql_dir: harness.cql_dir.clone(),
+            check: false,
+            skip_migrated: Some(migrate_opts()),
+        })
+        .await
+        .unwrap();
+        assert_eq!(result, vec!(pending_path));
+        assert_eq!(
+            fs::read_to_string(harness.cql_file_path("v001.cql")).unwrap(),
+            "create table accounts (id uuid primary key);\n"
+        );
+        assert!(migrator.plan().await.is_ok());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_fmt_cql_check_skips_migrated_files() {
+        let harness = TestHarness::builder()
+            .cql_file("v001.cql", "create  table accounts (id uuid primary key)\n")
+            .initialize()
+            .await;
+        let migrate_opts = || MigrateOpts {
+            cql_dir: harness.cql_dir.clone(),
+            history_keyspace: Some(KeyspaceOpts::simple(harness.cquill_keyspace.clone(), 1)),
+            history_table: Some(harness.cquill_table.clone()),
+            ..Default::default()
+        };
+        Migrator::builder()
+            .session(test_utils::cql_session().await)
+            .opts(migrate_opts())
+            .build()
+            .await
+            .expect("build migrator")
+            .migrate()
+            .await
+            .expect("migrate");
+
+        let result = fmt_cql(FmtOpts {
+            cql_dir: harness.cql_dir.clone(),
+            check: true,
+            skip_migrated: Some(migrate_opts()),
+        })
+        .await
+        .unwrap();
+        assert!(result.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+}
@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+use cquill_ast::schema::SchemaCatalog;
+
+use crate::cql_file::{self, CqlFile, VersionScheme};
+use crate::drift::cluster_schema;
+use crate::verify::{VerifyOpts, verify_cql};
+use crate::{CassandraOpts, cql_session};
+
+pub struct GenerateOpts {
+    pub cql_dir: PathBuf,
+    /// Describes the generated cql file's changes for its filename.
+    pub description: String,
+    pub desired: DesiredSchema,
+    /// Names the generated cql file with the next version of this scheme.
+    pub version_scheme: VersionScheme,
+}
+
+/// The schema a generated cql file migrates to.
+pub enum DesiredSchema {
+    /// A cql file declaring the complete schema of its keyspaces.
+    CqlFile(PathBuf),
+    /// The cluster's schema of the keyspaces created by cql files.
+    Cluster(Option<CassandraOpts>),
+}
+
+/// `generate_cql` writes the statements that migrate the schema created by the cql scripts in
+/// [GenerateOpts::cql_dir] to the [DesiredSchema] as the next versioned cql file. A successful
+/// method result contains the written cql file or none if the schemas already match.
+pub async fn generate_cql(opts: GenerateOpts) -> Result<Option<CqlFile>> {
+    // a cql dir without cql files is migrated from an empty schema
+    let (cql_files, migrated) = if cql_file::is_dir_without_cql_files(&opts.cql_dir) {
+        (Vec::new(), SchemaCatalog::new())
+    } else {
+        let verified = verify_cql(VerifyOpts {
+            cql_dir: opts.cql_dir.clone(),
+        })?;
+        (verified.cql_files, verified.schema)
+    };
+    let filename = cql_file::next_filename(&cql_files, &opts.description, opts.version_scheme)?;
+    let desired = match opts.desired {
+        DesiredSchema::CqlFile(path) => schema_from_file(&path)?,
+        DesiredSchema::Cluster(cassandra_opts) => {
+            let node_address = cassandra_opts.unwrap_or_default().node_address();
+            let session = cql_session(node_address).await?;
+            cluster_schema(&session, &migrated).await?
+        }
+    };
+    let statements = migrated.migration_to(&desired)?;
+    if statements.is_empty() {
+        return Ok(None);
+    }
+    let cql = statements
+        .iter()
+        .map(|statement| format!("{statement};\n"))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let path = opts.cql_dir.join(&filename);
+    if let Err(err) = fs::write(&path, cql) {
+        return Err(anyhow!("failed writing file {filename}: {err}"));
+    }
+    CqlFile::from_path(path).map(Some)
+}
+
+fn schema_from_file(path: &PathBuf) -> Result<SchemaCatalog> {
+    let filename = path.to_string_lossy();
+    let cql = match fs::read_to_string(path) {
+        Err(err) => return Err(anyhow!("failed reading file {filename}: {err}")),
+        Ok(cql) => cql,
+    };
+    let statements = match cquill_ast::parse_cql_script(cql.as_str()) {
+        Err(err) => return Err(anyhow!("failed parsing file {filename}: {err}")),
+        Ok(statements) => statements,
+    };
+    let mut schema = SchemaCatalog::new();
+    for script_statement in statements {
+        let line = script_statement.line;
+        match script_statement.statement {
+            Err(err) => return Err(anyhow!("{err} on line {line} of {filename}")),
+            Ok(statement) => {
+                if let Err(err) = schema.apply(&statement) {
+                    return Err(anyhow!("{err} on line {line} of {filename}"));
+                }
+            }
+        }
+    }
+    Ok(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::test_utils::make_file;
+
+    use super::*;
+
+    const CREATE_KEYSPACE: &str = "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};";
+
+    #[tokio::test]
+    async fn test_generate_cql_writes_next_cql_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let cql_dir = temp_dir.path().join("cql");
+        fs::create_dir(&cql_dir).unwrap();
+        make_file(
+            cql_dir.join("v001-init.cql"),
+            format!("{CREATE_KEYSPACE}\ncreate table api.accounts (id uuid primary key);").as_str(),
+        );
+        let schema_path = temp_dir.path().join("schema.cql");
+        make_file(
+            schema_path.clone(),
+            format!(
+                "{CREATE_KEYSPACE}\ncreate table api.accounts (id uuid primary key, name text);"
+            )
+            .as_str(),
+        );
+
+        let cql_file = generate_cql(GenerateOpts {
+            cql_dir: cql_dir.clone(),
+            description: "Add account names".to_string(),
+            desired: DesiredSchema::CqlFile(schema_path),
+            version_scheme: VersionScheme::Sequential,
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(cql_file.filename, "v002-add-account-names.cql");
+        assert_eq!(cql_file.version, 2);
+        assert_eq!(
+            fs::read_to_string(cql_file.path).unwrap(),
+            "ALTER TABLE api.accounts ADD name text;\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_generate_cql_from_empty_cql_dir() {
+        let temp_dir = TempDir::new().unwrap();
+        let cql_dir = temp_dir.path().join("cql");
+        fs::create_dir(&cql_dir).unwrap();
+        let schema_path = temp_dir.path().join("schema.cql");
+        make_file(schema_path.clone(), CREATE_KEYSPACE);
+
+        let cql_file = generate_cql(GenerateOpts {
+            cql_dir,
+            description: "init".to_string(),
+            desired: DesiredSchema::CqlFile(schema_path),
+            version_scheme: VersionScheme::Sequential,
+        })
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(cql_file.filename, "v001-init.cql");
+    }
+
+    #[tokio::test]
+    async fn test_generate_cql_when_schema_matches() {
+        let temp_dir = TempDir::new().unwrap();
+        let cql_dir = temp_dir.path().join("cql");
+        fs::create_dir(&cql_dir).unwrap();
+        make_file(cql_dir.join("v001.cql"), CREATE_KEYSPACE);
+        let schema_path = temp_dir.path().join("schema.cql");
+        make_file(schema_path.clone(), CREATE_KEYSPACE);
+
+        let generated = generate_cql(GenerateOpts {
+            cql_dir: cql_dir.clone(),
+            description: "nothing".to_string(),
+            desired: DesiredSchema::CqlFile(schema_path),
+            version_scheme: VersionScheme::Sequential,
+        })
+        .await
+        .unwrap();
+        assert!(generated.is_none());
+        assert_eq!(fs::read_dir(cql_dir).unwrap().count(), 1);
+    }
+}
@@ -132,6 +132,72 @@ impl FromStr for ReplicationFactor {
     }
 }
 
+/// KeyspaceMap renames keyspaces referenced by migrated CQL, which allows deploying a system's
+/// keyspaces side-by-side such as for an isolated test environment.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyspaceMap(HashMap<String, String>);
+
+impl KeyspaceMap {
+    /// Maps `keyspace_name` to `mapped_name`. Keyspace names are case-insensitive.
+    pub fn insert(&mut self, keyspace_name: &str, mapped_name: &str) -> Result<()> {
+        lazy_static! {
+            static ref KEYSPACE_NAME_REGEX: Regex =
+                regex::Regex::new(r"^[a-zA-Z][a-zA-Z\d_]{0,47}$").unwrap();
+        }
+        for name in [keyspace_name, mapped_name] {
+            if !KEYSPACE_NAME_REGEX.is_match(name) {
+                return Err(anyhow!("{name} is not a valid keyspace name"));
+            }
+        }
+        let keyspace_name = keyspace_name.to_lowercase();
+        if self.0.contains_key(&keyspace_name) {
+            return Err(anyhow!("keyspace map duplicates keyspace {keyspace_name}"));
+        }
+        self.0.insert(keyspace_name, mapped_name.to_lowercase());
+        Ok(())
+    }
+
+    pub fn get(&self, keyspace_name: &str) -> Option<&String> {
+        self.0.get(&keyspace_name.to_lowercase())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn as_map(&self) -> &HashMap<String, String> {
+        &self.0
+    }
+}
+
+impl From<HashMap<String, String>> for KeyspaceMap {
+    fn from(map: HashMap<String, String>) -> Self {
+        KeyspaceMap(map)
+    }
+}
+
+impl FromStr for KeyspaceMap {
+    type Err = anyhow::Error;
+
+    /// from_str parses comma separated `keyspace=mapped_keyspace` pairs such as `api=api_pr123`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut keyspace_map = KeyspaceMap::default();
+        for pair in s.split(',') {
+            match pair.split_once('=') {
+                Some((keyspace_name, mapped_name)) => {
+                    keyspace_map.insert(keyspace_name.trim(), mapped_name.trim())?
+                }
+                None => {
+                    return Err(anyhow!(
+                        "keyspace map {pair} is not a keyspace=mapped_keyspace pair"
+                    ));
+                }
+            }
+        }
+        Ok(keyspace_map)
+    }
+}
+
 pub(crate) fn get_keyspace_table_names(
     session: &Session,
     keyspace_name: &String,
@@ -143,6 +209,21 @@ pub(crate) fn get_keyspace_table_names(
     }
 }
 
+pub(crate) fn get_table_column_names(
+    session: &Session,
+    keyspace_name: &String,
+    table_name: &String,
+) -> Result<Vec<String>> {
+    let cluster_state = session.get_cluster_state();
+    match cluster_state
+        .get_keyspace(keyspace_name.to_lowercase().as_str())
+        .and_then(|keyspace| keyspace.tables.get(table_name.to_lowercase().as_str()))
+    {
+        None => Err(anyhow!("table {keyspace_name}.{table_name} does not exist")),
+        Some(table) => Ok(table.columns.keys().cloned().collect()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{queries, test_utils};
@@ -271,6 +352,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_keyspace_map_from_str() {
+        let keyspace_map = "api=api_pr123, Auth=auth_pr123"
+            .parse::<KeyspaceMap>()
+            .unwrap();
+        assert_eq!(keyspace_map.get("api"), Some(&"api_pr123".to_string()));
+        assert_eq!(keyspace_map.get("API"), Some(&"api_pr123".to_string()));
+        assert_eq!(keyspace_map.get("auth"), Some(&"auth_pr123".to_string()));
+        assert_eq!(keyspace_map.get("billing"), None);
+    }
+
+    #[test]
+    fn test_keyspace_map_from_str_error() {
+        for (input, err_msg) in [
+            (
+                "api",
+                "keyspace map api is not a keyspace=mapped_keyspace pair",
+            ),
+            ("api=", " is not a valid keyspace name"),
+            ("api=api-pr123", "api-pr123 is not a valid keyspace name"),
+            ("api=a,api=b", "keyspace map duplicates keyspace api"),
+        ] {
+            match input.parse::<KeyspaceMap>() {
+                Ok(_) => panic!("{input} should not parse"),
+                Err(err) => assert_eq!(err.to_string(), err_msg),
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_table_names() {
         let harness = test_utils::TestHarness::builder().initialize().await;
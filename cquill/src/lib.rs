@@ -1,20 +1,55 @@
+// expands embed_migrations! paths within the crate's own tests
+extern crate self as cquill;
+
 use std::{path::PathBuf, str};
 
 use anyhow::{Result, anyhow};
+pub use cquill_ast::schema::{SchemaDiff, SchemaObjectName};
 use scylla::client::session::Session;
 use scylla::client::session_builder::SessionBuilder;
 
-pub use crate::cql_file::CqlFile;
-pub use crate::migrate::{MigrateError, MigrateErrorState};
+pub use cquill_macros::embed_migrations;
+
+pub use crate::checksum::ChecksumAlgorithm;
+pub use crate::config::{CONFIG_FILENAME, ProjectConfig};
+pub use crate::cql_file::{CqlFile, CqlFileKind, CqlStatement, VersionScheme};
+pub use crate::destructive::{DestructiveChange, DestructiveCql};
+pub use crate::drift::{DriftOpts, drift_cql};
+pub use crate::embed::{EmbeddedCql, EmbeddedMigrations};
+pub use crate::fmt::{FmtOpts, fmt_cql};
+pub use crate::generate::{DesiredSchema, GenerateOpts, generate_cql};
+pub use crate::lint::{LintFinding, LintLevel, LintOpts, lint_cql, lint_rule_levels};
+pub use crate::listener::MigrationListener;
+pub use crate::migrate::{MigrateError, MigrateErrorState, PlannedCql};
+pub use crate::migrator::{Migrator, MigratorBuilder};
 use crate::queries::*;
+pub use crate::scaffold::{NewOpts, new_cql_file};
+pub use crate::source::{MigratedSource, MigrationSource, SourcePlan, SourceStatus};
+pub use crate::template::{ENV_VAR_PREFIX, TemplateVars};
+pub use crate::verify::{UnverifiedCql, VerifiedCql, VerifyError, VerifyOpts, verify_cql};
 use crate::{keyspace::*, queries::keyspace::CreateKeyspaceError};
 
+mod checksum;
+mod config;
 mod cql_file;
+mod destructive;
+mod drift;
+mod embed;
+mod fmt;
+mod generate;
 pub mod keyspace;
+mod lint;
+mod listener;
 mod migrate;
+mod migrator;
 mod queries;
+mod rewrite;
+mod scaffold;
+mod source;
+mod template;
 #[cfg(test)]
 pub(crate) mod test_utils;
+mod verify;
 
 const NODE_ADDRESS: &str = "127.0.0.1:9042";
 
@@ -23,10 +58,52 @@ pub const KEYSPACE: &str = "cquill";
 pub const TABLE: &str = "migrated_cql";
 
 pub struct MigrateOpts {
+    /// Migrates statements that drop schema, truncate tables or reduce keyspace replication, which
+    /// are otherwise only migrated from cql files annotated with a `-- cquill: allow-destructive`
+    /// comment.
+    pub allow_destructive: bool,
+    /// Migrates versioned cql files that were never migrated but are of a lower version than a
+    /// migrated cql file, such as files merged from a feature branch, which otherwise errors.
+    pub allow_out_of_order: bool,
     pub cassandra_opts: Option<CassandraOpts>,
+    /// Hashes migrated cql files for the history table with this algorithm, defaulting to md5.
+    /// Files migrated with a different algorithm are validated with the algorithm recorded in the
+    /// history table.
+    pub checksum_algorithm: Option<ChecksumAlgorithm>,
     pub cql_dir: PathBuf,
     pub history_keyspace: Option<KeyspaceOpts>,
     pub history_table: Option<String>,
+    /// Renames keyspaces referenced by migrated CQL, recorded with each file in the history table.
+    pub keyspace_map: Option<KeyspaceMap>,
+    /// Migrates the cql files named with this profile's suffix, such as `v004-seed.dev.cql` for
+    /// the `dev` profile, which are otherwise not migrated.
+    pub profile: Option<String>,
+    /// Named cql dirs migrated in order, each with its own version sequence and history table,
+    /// instead of [MigrateOpts::cql_dir] and [MigrateOpts::history_table].
+    pub sources: Vec<MigrationSource>,
+    /// Values of `${name}` and `{{ name }}` placeholders rendered into cql files before migrating.
+    pub vars: Option<TemplateVars>,
+    /// Scheme of the versions cql files are named with, defaulting to 3 digit versions.
+    pub version_scheme: Option<VersionScheme>,
+}
+
+impl Default for MigrateOpts {
+    fn default() -> Self {
+        MigrateOpts {
+            allow_destructive: false,
+            allow_out_of_order: false,
+            cassandra_opts: None,
+            checksum_algorithm: None,
+            cql_dir: PathBuf::from("./cql"),
+            history_keyspace: None,
+            history_table: None,
+            keyspace_map: None,
+            profile: None,
+            sources: Vec::new(),
+            vars: None,
+            version_scheme: None,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -51,28 +128,44 @@ impl CassandraOpts {
 /// `migrate_cql` performs a migration of all newly added cql scripts in [MigrateOpts::cql_dir]
 /// since its last invocation. Migrated scripts are tracked in a cquill keyspace and history table
 /// specified with [MigrateOpts::history_keyspace] and [MigrateOpts::history_table]. A successful
-/// method result contains a vec of the cql script paths executed during this invocation.
+/// method result contains a vec of the cql script paths executed during this invocation. See
+/// [Migrator] for migrating with an existing session.
 pub async fn migrate_cql(opts: MigrateOpts) -> Result<Vec<CqlFile>, MigrateError> {
-    let cql_files = cql_file::files_from_dir(&opts.cql_dir)?;
-    let node_address = opts.cassandra_opts.unwrap_or_default().node_address();
-    let session = cql_session(node_address).await?;
-
-    let cquill_keyspace = opts
-        .history_keyspace
-        .unwrap_or_else(|| KeyspaceOpts::simple(String::from(KEYSPACE), 1));
-    let history_table = opts.history_table.unwrap_or_else(|| String::from(TABLE));
-    prepare_cquill_keyspace(&session, &cquill_keyspace, &history_table).await?;
-
-    migrate::perform(
-        &session,
-        &cql_files,
-        migrate::MigrateArgs {
-            cql_dir: opts.cql_dir,
-            history_keyspace: cquill_keyspace.name,
-            history_table,
-        },
-    )
-    .await
+    Ok(migrate_sources(opts)
+        .await?
+        .into_iter()
+        .flat_map(|source| source.migrated)
+        .collect())
+}
+
+/// `migrate_embedded` performs a migration of the cql scripts embedded in the executable with
+/// [embed_migrations] like [migrate_cql] does of [MigrateOpts::cql_dir], which along with
+/// [MigrateOpts::sources] is not read. Embedded scripts are recorded in the history table with
+/// paths relative to the embedded cql dir, so a service may migrate with either function.
+pub async fn migrate_embedded(
+    migrations: &EmbeddedMigrations,
+    opts: MigrateOpts,
+) -> Result<Vec<CqlFile>, MigrateError> {
+    let migrator = Migrator::builder()
+        .opts(opts)
+        .embedded(*migrations)
+        .build()
+        .await?;
+    Ok(migrator
+        .migrate()
+        .await?
+        .into_iter()
+        .flat_map(|source| source.migrated)
+        .collect())
+}
+
+/// `migrate_sources` performs a migration of each of [MigrateOpts::sources] in order, or of
+/// [MigrateOpts::cql_dir] as a source named after [MigrateOpts::history_table] without sources.
+/// A successful method result contains the cql scripts executed from each source. An error of a
+/// source is returned as a [MigrateError::SourceMigration] with the sources migrated before it.
+pub async fn migrate_sources(opts: MigrateOpts) -> Result<Vec<MigratedSource>, MigrateError> {
+    let migrator = Migrator::builder().opts(opts).build().await?;
+    migrator.migrate().await
 }
 
 // todo drop and recreate dev mode
@@ -90,10 +183,33 @@ async fn prepare_cquill_keyspace(
     };
     if create_table {
         migrated::table::create(session, &keyspace.name, table_name).await?;
+    } else {
+        for added_column in missing_history_columns(session, &keyspace.name, table_name)? {
+            migrated::table::add_column(session, &keyspace.name, table_name, added_column).await?;
+        }
     }
     Ok(())
 }
 
+/// Columns added to history tables since the first version of cquill that a history table
+/// created by an earlier version is missing.
+fn missing_history_columns(
+    session: &Session,
+    keyspace_name: &String,
+    table_name: &String,
+) -> Result<Vec<(&'static str, &'static str)>> {
+    let column_names = get_table_column_names(session, keyspace_name, table_name)?;
+    Ok(migrated::table::ADDED_COLUMNS
+        .iter()
+        .filter(|added_column| {
+            !column_names
+                .iter()
+                .any(|column_name| column_name == added_column.0)
+        })
+        .copied()
+        .collect())
+}
+
 async fn cql_session(node_address: String) -> Result<Session> {
     let connecting = SessionBuilder::new()
         .known_node(&node_address)
@@ -185,4 +301,78 @@ mod tests {
 
         harness.drop_keyspace().await;
     }
+
+    #[tokio::test]
+    async fn test_prepare_cquill_keyspace_upgrades_v1_history_table() {
+        let session = test_utils::cql_session().await;
+        let keyspace_opts = test_utils::create_keyspace(&session).await;
+        let table_name = String::from("migrated_cql");
+        queries::exec(
+            &session,
+            format!(
+                "create table {}.{table_name} (id timeuuid primary key, ver smallint, name varchar, hash varchar)",
+                keyspace_opts.name
+            ),
+        )
+        .await
+        .expect("create v1 history table");
+
+        prepare_cquill_keyspace(&session, &keyspace_opts, &table_name)
+            .await
+            .expect("prepare keyspace");
+        session.refresh_metadata().await.expect("refresh metadata");
+        let column_names =
+            get_table_column_names(&session, &keyspace_opts.name, &table_name).unwrap();
+        for (column_name, _) in migrated::table::ADDED_COLUMNS {
+            assert!(column_names.contains(&column_name.to_string()));
+        }
+
+        queries::keyspace::drop(&session, &keyspace_opts.name)
+            .await
+            .expect("drop keyspace");
+    }
+
+    #[tokio::test]
+    async fn test_migrate_sources_in_order() {
+        let temp_dir = temp_dir::TempDir::new().unwrap();
+        let history_keyspace = test_utils::keyspace_name();
+        let mut sources = Vec::new();
+        for name in ["accounts", "billing"] {
+            let cql_dir = temp_dir.path().join(name);
+            std::fs::create_dir(&cql_dir).unwrap();
+            test_utils::make_file(cql_dir.join("v001.cql"), "");
+            test_utils::make_file(cql_dir.join("v002.cql"), "");
+            sources.push(MigrationSource::new(name, cql_dir).unwrap());
+        }
+        let opts = || MigrateOpts {
+            allow_destructive: false,
+            allow_out_of_order: false,
+            cassandra_opts: None,
+            checksum_algorithm: None,
+            cql_dir: temp_dir.path().to_path_buf(),
+            history_keyspace: Some(KeyspaceOpts::simple(history_keyspace.clone(), 1)),
+            history_table: None,
+            keyspace_map: None,
+            profile: None,
+            sources: sources.clone(),
+            vars: None,
+            version_scheme: None,
+        };
+
+        let migrated = migrate_sources(opts()).await.expect("migrate sources");
+        assert_eq!(
+            migrated
+                .iter()
+                .map(|source| (source.name.as_str(), source.migrated.len()))
+                .collect::<Vec<_>>(),
+            vec![("accounts", 2), ("billing", 2)]
+        );
+        let migrated = migrate_sources(opts()).await.expect("migrate sources");
+        assert!(migrated.iter().all(|source| source.migrated.is_empty()));
+
+        let session = test_utils::cql_session().await;
+        queries::keyspace::drop(&session, &history_keyspace)
+            .await
+            .expect("drop keyspace");
+    }
 }
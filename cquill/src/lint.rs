@@ -0,0 +1,596 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use cquill_ast::ast::*;
+use cquill_ast::schema::SchemaCatalog;
+
+use crate::config::ProjectConfig;
+use crate::cql_file;
+
+/// Comment directive suppressing rules for the statement it precedes or ends the first line of,
+/// such as `-- cquill-lint: allow drop-table, truncate`.
+const SUPPRESS_DIRECTIVE: &str = "cquill-lint: allow";
+
+pub struct LintOpts {
+    pub cql_dir: PathBuf,
+    /// Lints for a development environment, where SimpleStrategy replication is allowed.
+    pub dev: bool,
+    /// Overrides the default [LintLevel] of rules by rule name.
+    pub rule_levels: BTreeMap<String, LintLevel>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LintLevel {
+    Off,
+    Warn,
+    Error,
+}
+
+impl FromStr for LintLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(LintLevel::Off),
+            "warn" => Ok(LintLevel::Warn),
+            "error" => Ok(LintLevel::Error),
+            _ => Err(anyhow!("lint level {s} is not one of off, warn or error")),
+        }
+    }
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            LintLevel::Off => "off",
+            LintLevel::Warn => "warning",
+            LintLevel::Error => "error",
+        })
+    }
+}
+
+/// A statement of a cql file found by a lint rule.
+#[derive(Debug, PartialEq)]
+pub struct LintFinding {
+    pub filename: String,
+    pub line: usize,
+    pub rule: &'static str,
+    pub level: LintLevel,
+    pub message: String,
+}
+
+impl Display for LintFinding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{} {} {}: {}",
+            self.filename, self.line, self.level, self.rule, self.message
+        )
+    }
+}
+
+struct LintContext<'a> {
+    dev: bool,
+    /// The schema created by the statements preceding the linted statement.
+    schema: &'a SchemaCatalog,
+}
+
+struct LintRule {
+    name: &'static str,
+    level: LintLevel,
+    check: fn(&CqlStatement, &LintContext) -> Option<String>,
+}
+
+const RULES: [LintRule; 10] = [
+    LintRule {
+        name: "allow-filtering",
+        level: LintLevel::Warn,
+        check: check_allow_filtering,
+    },
+    LintRule {
+        name: "compact-storage",
+        level: LintLevel::Warn,
+        check: check_compact_storage,
+    },
+    LintRule {
+        name: "drop-column",
+        level: LintLevel::Warn,
+        check: check_drop_column,
+    },
+    LintRule {
+        name: "drop-keyspace",
+        level: LintLevel::Error,
+        check: check_drop_keyspace,
+    },
+    LintRule {
+        name: "drop-table",
+        level: LintLevel::Warn,
+        check: check_drop_table,
+    },
+    LintRule {
+        name: "if-not-exists",
+        level: LintLevel::Off,
+        check: check_if_not_exists,
+    },
+    LintRule {
+        name: "plaintext-password",
+        level: LintLevel::Error,
+        check: check_plaintext_password,
+    },
+    LintRule {
+        name: "secondary-index",
+        level: LintLevel::Warn,
+        check: check_secondary_index,
+    },
+    LintRule {
+        name: "simple-strategy",
+        level: LintLevel::Warn,
+        check: check_simple_strategy,
+    },
+    LintRule {
+        name: "truncate",
+        level: LintLevel::Warn,
+        check: check_truncate,
+    },
+];
+
+/// Reads the `[lint]` table of a project config as the level of each named rule.
+pub fn lint_rule_levels(config: &ProjectConfig) -> Result<BTreeMap<String, LintLevel>> {
+    let mut rule_levels = BTreeMap::new();
+    for (rule_name, level) in &config.lint {
+        if !RULES.iter().any(|rule| rule.name == rule_name) {
+            return Err(anyhow!("lint rule {rule_name} does not exist"));
+        }
+        rule_levels.insert(rule_name.clone(), level.parse()?);
+    }
+    Ok(rule_levels)
+}
+
+/// `lint_cql` checks the statements of the cql scripts in [LintOpts::cql_dir] for risky CQL such
+/// as dropping tables or committing plaintext passwords. Rules are checked at their default level
+/// unless overridden by [LintOpts::rule_levels] and a rule is not checked for a statement
+/// suppressing it with a `-- cquill-lint: allow rule-name` comment. A successful method result
+/// contains the findings of rules not turned off.
+pub fn lint_cql(opts: LintOpts) -> Result<Vec<LintFinding>> {
+    let mut findings = Vec::new();
+    let mut schema = SchemaCatalog::new();
+    for cql_file in cql_file::files_from_dir(&opts.cql_dir)? {
+        let cql = match fs::read_to_string(&cql_file.path) {
+            Err(err) => return Err(anyhow!("failed reading file {cql_file}: {err}")),
+            Ok(cql) => cql,
+        };
+        let statements = match cquill_ast::parse_cql_script(cql.as_str()) {
+            Err(err) => return Err(anyhow!("failed parsing file {cql_file}: {err}")),
+            Ok(statements) => statements,
+        };
+        let lines: Vec<&str> = cql.lines().collect();
+        for script_statement in statements {
+            let Ok(statement) = script_statement.statement else {
+                continue;
+            };
+            let suppressed = suppressed_rules(&lines, script_statement.line);
+            let context = LintContext {
+                dev: opts.dev,
+                schema: &schema,
+            };
+            for rule in &RULES {
+                let level = opts
+                    .rule_levels
+                    .get(rule.name)
+                    .copied()
+                    .unwrap_or(rule.level);
+                if level == LintLevel::Off || suppressed.contains(&rule.name) {
+                    continue;
+                }
+                if let Some(message) = (rule.check)(&statement, &context) {
+                    findings.push(LintFinding {
+                        filename: cql_file.filename.clone(),
+                        line: script_statement.line,
+                        rule: rule.name,
+                        level,
+                        message,
+                    });
+                }
+            }
+            // invalid statements are reported by verify
+            let _ = schema.apply(&statement);
+        }
+    }
+    Ok(findings)
+}
+
+/// Rule names of suppressing comments on the lines directly before a statement's 1-based line or at
+/// the end of its first line.
+fn suppressed_rules<'a>(lines: &[&'a str], line: usize) -> Vec<&'a str> {
+    let mut comments = Vec::new();
+    if let Some(first_line) = lines.get(line - 1) {
+        comments.push(*first_line);
+    }
+    for preceding_line in lines[..line - 1].iter().rev() {
+        let trimmed = preceding_line.trim_start();
+        if !trimmed.starts_with("--") && !trimmed.starts_with("//") {
+            break;
+        }
+        comments.push(trimmed);
+    }
+    comments
+        .into_iter()
+        .filter_map(|comment| comment.split_once(SUPPRESS_DIRECTIVE))
+        .flat_map(|(_, rule_names)| {
+            rule_names
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|rule_name| !rule_name.is_empty())
+        })
+        .collect()
+}
+
+fn qualified(keyspace_name: &Option<TokenView>, name: &TokenView) -> String {
+    match keyspace_name {
+        None => name.value(),
+        Some(keyspace_name) => format!("{keyspace_name}.{name}"),
+    }
+}
+
+fn check_allow_filtering(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Select(select) if select.allow_filtering => Some(format!(
+            "ALLOW FILTERING may scan every partition of {}",
+            qualified(&select.keyspace_name, &select.table_name)
+        )),
+        _ => None,
+    }
+}
+
+fn check_compact_storage(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Create(CreateStatement::Table(create_table))
+            if create_table
+                .attributes
+                .iter()
+                .flatten()
+                .any(|attribute| *attribute == TableDefinitionAttribute::CompactStorage) =>
+        {
+            Some(format!(
+                "COMPACT STORAGE of table {} is not supported since Cassandra 4",
+                qualified(&create_table.keyspace_name, &create_table.table_name)
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn check_drop_column(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Alter(AlterStatement::Table(AlterTableStatement {
+            keyspace_name,
+            table_name,
+            instruction: AlterTableInstruction::DropColumns { column_names, .. },
+            ..
+        })) => Some(format!(
+            "dropping {} from table {} deletes the column data",
+            column_names
+                .iter()
+                .map(TokenView::value)
+                .collect::<Vec<String>>()
+                .join(", "),
+            qualified(keyspace_name, table_name)
+        )),
+        _ => None,
+    }
+}
+
+fn check_drop_keyspace(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Drop(DropStatement::Keyspace(drop_keyspace)) => Some(format!(
+            "dropping keyspace {} deletes all of its tables and data",
+            drop_keyspace.keyspace_name
+        )),
+        _ => None,
+    }
+}
+
+fn check_drop_table(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Drop(DropStatement::Table(drop_table)) => Some(format!(
+            "dropping table {} deletes its data",
+            qualified(&drop_table.keyspace_name, &drop_table.table_name)
+        )),
+        _ => None,
+    }
+}
+
+fn check_if_not_exists(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    let (object_type, name) = match statement {
+        CqlStatement::Create(create) => match create {
+            CreateStatement::Aggregate(statement)
+                if statement.if_exists_behavior == CreateIfExistsBehavior::Error =>
+            {
+                (
+                    "aggregate",
+                    qualified(&statement.keyspace_name, &statement.function_name),
+                )
+            }
+            CreateStatement::Function(statement)
+                if statement.if_exists_behavior == CreateIfExistsBehavior::Error =>
+            {
+                (
+                    "function",
+                    qualified(&statement.keyspace_name, &statement.function_name),
+                )
+            }
+            CreateStatement::Index(statement) if !statement.if_not_exists => (
+                "index",
+                match &statement.index_name {
+                    None => format!("on {}", statement.table_name),
+                    Some(index_name) => qualified(&statement.keyspace_name, index_name),
+                },
+            ),
+            CreateStatement::Keyspace(statement) if !statement.if_not_exists => {
+                ("keyspace", statement.keyspace_name.value())
+            }
+            CreateStatement::MaterializedView(statement) if !statement.if_not_exists => (
+                "materialized view",
+                qualified(&statement.keyspace_name, &statement.view_name),
+            ),
+            CreateStatement::Role(statement) if !statement.if_not_exists => {
+                ("role", statement.role_name.value())
+            }
+            CreateStatement::Table(statement) if !statement.if_not_exists => (
+                "table",
+                qualified(&statement.keyspace_name, &statement.table_name),
+            ),
+            CreateStatement::Trigger(statement) if !statement.if_not_exists => {
+                ("trigger", statement.trigger_name.value())
+            }
+            CreateStatement::Type(statement) if !statement.if_not_exists => (
+                "type",
+                qualified(&statement.keyspace_name, &statement.type_name),
+            ),
+            CreateStatement::User(statement) if !statement.if_not_exists => {
+                ("user", statement.user_name.value())
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(format!(
+        "creating {object_type} {name} without IF NOT EXISTS errors if it exists"
+    ))
+}
+
+fn check_plaintext_password(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    let is_plaintext =
+        |password: &Option<AuthPassword>| matches!(password, Some(AuthPassword::PlainText(_)));
+    let has_plaintext = |attributes: &[RoleConfigAttribute]| {
+        attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                RoleConfigAttribute::Password(AuthPassword::PlainText(_))
+            )
+        })
+    };
+    let (object_type, name) = match statement {
+        CqlStatement::Alter(AlterStatement::Role(statement))
+            if has_plaintext(&statement.attributes) =>
+        {
+            ("role", &statement.role_name)
+        }
+        CqlStatement::Alter(AlterStatement::User(statement))
+            if is_plaintext(&statement.password) =>
+        {
+            ("user", &statement.user_name)
+        }
+        CqlStatement::Create(CreateStatement::Role(statement))
+            if has_plaintext(statement.attributes.as_deref().unwrap_or_default()) =>
+        {
+            ("role", &statement.role_name)
+        }
+        CqlStatement::Create(CreateStatement::User(statement))
+            if is_plaintext(&statement.password) =>
+        {
+            ("user", &statement.user_name)
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "plaintext password of {object_type} {name} is stored in cql files"
+    ))
+}
+
+/// Data types with so many distinct values that a secondary index queries nearly every node.
+const HIGH_CARDINALITY_DATA_TYPES: [&str; 10] = [
+    "bigint",
+    "blob",
+    "decimal",
+    "double",
+    "float",
+    "inet",
+    "timestamp",
+    "timeuuid",
+    "uuid",
+    "varint",
+];
+
+fn check_secondary_index(statement: &CqlStatement, context: &LintContext) -> Option<String> {
+    let CqlStatement::Create(CreateStatement::Index(create_index)) = statement else {
+        return None;
+    };
+    let CreateIndexColumn::Column(column_name) = &create_index.on_column else {
+        return None;
+    };
+    let keyspace_name = match &create_index.keyspace_name {
+        Some(keyspace_name) => keyspace_name.value(),
+        None => context.schema.current_keyspace()?.clone(),
+    };
+    let column = context
+        .schema
+        .table(&keyspace_name, &create_index.table_name.value())?
+        .column(&column_name.value().to_lowercase())?;
+    if !HIGH_CARDINALITY_DATA_TYPES.contains(&column.data_type.as_str()) {
+        return None;
+    }
+    Some(format!(
+        "secondary index on {} column {column_name} of table {keyspace_name}.{} queries every node",
+        column.data_type, create_index.table_name
+    ))
+}
+
+fn check_simple_strategy(statement: &CqlStatement, context: &LintContext) -> Option<String> {
+    if context.dev {
+        return None;
+    }
+    let (keyspace_name, replication) = match statement {
+        CqlStatement::Alter(AlterStatement::Keyspace(statement)) => {
+            (&statement.keyspace_name, statement.replication.as_ref()?)
+        }
+        CqlStatement::Create(CreateStatement::Keyspace(statement)) => {
+            (&statement.keyspace_name, &statement.replication)
+        }
+        _ => return None,
+    };
+    match replication {
+        KeyspaceReplication::Simple(_) => Some(format!(
+            "keyspace {keyspace_name} uses SimpleStrategy replication, which is not aware of datacenters"
+        )),
+        KeyspaceReplication::NetworkTopology(_) => None,
+    }
+}
+
+fn check_truncate(statement: &CqlStatement, _: &LintContext) -> Option<String> {
+    match statement {
+        CqlStatement::Truncate(truncate) => Some(format!(
+            "truncating table {} deletes its data",
+            qualified(&truncate.keyspace_name, &truncate.table_name)
+        )),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::test_utils::make_file;
+
+    use super::*;
+
+    fn lint(cql: &str, dev: bool, rule_levels: &[(&str, LintLevel)]) -> Vec<String> {
+        let temp_dir = TempDir::new().unwrap();
+        make_file(temp_dir.path().join("v001.cql"), cql);
+        lint_cql(LintOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            dev,
+            rule_levels: rule_levels
+                .iter()
+                .map(|(rule_name, level)| (rule_name.to_string(), *level))
+                .collect(),
+        })
+        .unwrap()
+        .iter()
+        .map(LintFinding::to_string)
+        .collect()
+    }
+
+    const CREATE_KEYSPACE: &str = "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};";
+
+    #[test]
+    fn test_lint_rules() {
+        let findings = lint(
+            &format!(
+                "{CREATE_KEYSPACE}
+create table api.accounts (id uuid primary key, name text, created timestamp) with compact storage;
+create index on api.accounts (created);
+create index on api.accounts (name);
+select * from api.accounts where name = 'x' allow filtering;
+alter table api.accounts drop name;
+truncate api.accounts;
+create role api_role with password = 'hunter2' and login = true;
+drop table api.accounts;
+drop keyspace api;"
+            ),
+            false,
+            &[],
+        );
+        assert_eq!(
+            findings,
+            vec![
+                "v001.cql:1 warning simple-strategy: keyspace api uses SimpleStrategy replication, which is not aware of datacenters",
+                "v001.cql:2 warning compact-storage: COMPACT STORAGE of table api.accounts is not supported since Cassandra 4",
+                "v001.cql:3 warning secondary-index: secondary index on timestamp column created of table api.accounts queries every node",
+                "v001.cql:5 warning allow-filtering: ALLOW FILTERING may scan every partition of api.accounts",
+                "v001.cql:6 warning drop-column: dropping name from table api.accounts deletes the column data",
+                "v001.cql:7 warning truncate: truncating table api.accounts deletes its data",
+                "v001.cql:8 error plaintext-password: plaintext password of role api_role is stored in cql files",
+                "v001.cql:9 warning drop-table: dropping table api.accounts deletes its data",
+                "v001.cql:10 error drop-keyspace: dropping keyspace api deletes all of its tables and data",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_rule_levels() {
+        let findings = lint(
+            &format!("{CREATE_KEYSPACE}\ncreate table api.accounts (id uuid primary key);"),
+            false,
+            &[
+                ("if-not-exists", LintLevel::Error),
+                ("simple-strategy", LintLevel::Off),
+            ],
+        );
+        assert_eq!(
+            findings,
+            vec![
+                "v001.cql:1 error if-not-exists: creating keyspace api without IF NOT EXISTS errors if it exists",
+                "v001.cql:2 error if-not-exists: creating table api.accounts without IF NOT EXISTS errors if it exists",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_simple_strategy_allowed_for_dev() {
+        assert!(lint(CREATE_KEYSPACE, true, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_lint_suppressed_with_comments() {
+        let findings = lint(
+            &format!(
+                "{CREATE_KEYSPACE} -- cquill-lint: allow simple-strategy
+create table api.accounts (id uuid primary key);
+-- cleaning up a table that was never released
+-- cquill-lint: allow drop-table, truncate
+truncate api.accounts;
+drop table api.accounts;"
+            ),
+            false,
+            &[],
+        );
+        assert_eq!(
+            findings,
+            vec!["v001.cql:6 warning drop-table: dropping table api.accounts deletes its data"]
+        );
+    }
+
+    #[test]
+    fn test_lint_rule_levels_from_config() {
+        let config: ProjectConfig = "[lint]\ndrop-table = \"error\"".parse().unwrap();
+        assert_eq!(
+            lint_rule_levels(&config).unwrap(),
+            BTreeMap::from([("drop-table".to_string(), LintLevel::Error)])
+        );
+        let config: ProjectConfig = "[lint]\ndrop-tables = \"error\"".parse().unwrap();
+        assert_eq!(
+            lint_rule_levels(&config).unwrap_err().to_string(),
+            "lint rule drop-tables does not exist"
+        );
+        let config: ProjectConfig = "[lint]\ndrop-table = \"loud\"".parse().unwrap();
+        assert_eq!(
+            lint_rule_levels(&config).unwrap_err().to_string(),
+            "lint level loud is not one of off, warn or error"
+        );
+    }
+}
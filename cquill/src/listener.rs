@@ -0,0 +1,44 @@
+use std::time::Duration;
+
+use crate::cql_file::{CqlFile, CqlStatement};
+use crate::migrate::MigrateError;
+use crate::source::MigratedSource;
+
+/// Callbacks of a migration's progress, such as for reporting progress while migrating. Callbacks
+/// are called in order from the task migrating and should return quickly. Every callback defaults
+/// to doing nothing, and `()` is a listener without any callbacks.
+pub trait MigrationListener: Send + Sync {
+    /// A source started migrating, named after its history table without named sources.
+    fn source_started(&self, _name: &str) {}
+
+    /// A source finished migrating its pending cql files.
+    fn source_finished(&self, _source: &MigratedSource) {}
+
+    /// A cql file started executing.
+    fn file_started(&self, _cql_file: &CqlFile) {}
+
+    /// A cql file finished executing and was recorded in the history table.
+    fn file_finished(&self, _cql_file: &CqlFile, _duration: Duration) {}
+
+    /// A statement started executing, with the lines of the cql file it spans in
+    /// [CqlStatement::lines].
+    fn statement_started(&self, _cql_file: &CqlFile, _statement: &CqlStatement) {}
+
+    /// A statement finished executing.
+    fn statement_finished(
+        &self,
+        _cql_file: &CqlFile,
+        _statement: &CqlStatement,
+        _duration: Duration,
+    ) {
+    }
+
+    /// A cql file's execution was recorded in the history table, including an execution that
+    /// failed before the migration errors.
+    fn history_inserted(&self, _cql_file: &CqlFile) {}
+
+    /// The migration errored with the error it returns.
+    fn errored(&self, _error: &MigrateError) {}
+}
+
+impl MigrationListener for () {}
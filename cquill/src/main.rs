@@ -1,12 +1,17 @@
 use std::ops::Deref;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::{Parser, Subcommand};
 
-use cquill::MigrateError::HistoryUpdateFailed;
+use cquill::MigrateError::{DestructiveCql, HistoryUpdateFailed, SourceMigration};
 use cquill::{
-    CassandraOpts, CqlFile, MigrateError, MigrateError::PartialMigration, MigrateErrorState,
-    MigrateOpts, keyspace::*, migrate_cql,
+    CONFIG_FILENAME, CassandraOpts, ChecksumAlgorithm, CqlFile, CqlStatement, DesiredSchema,
+    DriftOpts, FmtOpts, GenerateOpts, LintLevel, LintOpts, MigrateError,
+    MigrateError::PartialMigration, MigrateErrorState, MigrateOpts, MigratedSource,
+    MigrationListener, MigrationSource, Migrator, NewOpts, ProjectConfig, SchemaDiff, TemplateVars,
+    VerifyOpts, VersionScheme, drift_cql, fmt_cql, generate_cql, keyspace::*, lint_cql,
+    lint_rule_levels, new_cql_file, verify_cql,
 };
 
 #[derive(Parser)]
@@ -18,11 +23,99 @@ struct CquillCli {
 
 #[derive(Subcommand)]
 enum CquillCommand {
+    Drift(DriftCliArgs),
+    Fmt(FmtCliArgs),
+    Generate(GenerateCliArgs),
+    Lint(LintCliArgs),
     Migrate(MigrateCliArgs),
+    New(NewCliArgs),
+    Verify(VerifyCliArgs),
+}
+
+#[derive(Parser, Debug)]
+struct DriftCliArgs {
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
+}
+
+#[derive(Parser, Debug)]
+struct FmtCliArgs {
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
+    /// Exit with an error if cql files are not formatted instead of rewriting them
+    #[clap(long)]
+    check: bool,
+    /// Also format cql files already migrated without reading the history table, which changes
+    /// their md5 and sha256 checksums when rewritten
+    #[clap(long)]
+    include_migrated: bool,
+    #[clap(long, value_name = "HISTORY_KEYSPACE", default_value = cquill::KEYSPACE)]
+    history_keyspace: String,
+    #[clap(long, value_name = "HISTORY_TABLE", default_value = cquill::TABLE)]
+    history_table: String,
+}
+
+#[derive(Parser, Debug)]
+struct GenerateCliArgs {
+    /// Describes the changes for the generated cql file's name
+    description: String,
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
+    /// Generate cql to migrate to the schema declared by a cql file
+    #[clap(long, value_name = "SCHEMA_CQL", required_unless_present = "cluster")]
+    schema: Option<PathBuf>,
+    /// Generate cql to migrate to the schema of the cluster
+    #[clap(long, conflicts_with = "schema")]
+    cluster: bool,
+    /// Version the generated cql file with the next sequential, integer or timestamp version
+    #[clap(long, value_name = "VERSION_SCHEME", default_value = "sequential")]
+    version_scheme: String,
+}
+
+#[derive(Parser, Debug)]
+struct LintCliArgs {
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
+    /// Project config with a [lint] table of rule levels
+    #[clap(long, value_name = "CONFIG", default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Lint for a development environment, allowing SimpleStrategy replication
+    #[clap(long)]
+    dev: bool,
+}
+
+#[derive(Parser, Debug)]
+struct NewCliArgs {
+    /// Describes the changes for the new cql file's name and header comment
+    description: String,
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
+    /// Also create a .down.cql file for reverting the new cql file's changes
+    #[clap(long)]
+    down: bool,
+    /// Version the new cql file with the next sequential, integer or timestamp version
+    #[clap(long, value_name = "VERSION_SCHEME", default_value = "sequential")]
+    version_scheme: String,
+}
+
+#[derive(Parser, Debug)]
+struct VerifyCliArgs {
+    #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
+    cql_dir: PathBuf,
 }
 
 #[derive(Parser, Debug)]
 struct MigrateCliArgs {
+    /// Migrate statements that drop schema, truncate tables or reduce keyspace replication
+    #[clap(long)]
+    allow_destructive: bool,
+    /// Migrate never migrated cql files of a lower version than a migrated cql file
+    #[clap(long)]
+    allow_out_of_order: bool,
+    /// Hash migrated cql files with md5, sha256 or normalized, a sha256 of cql tokens that ignores
+    /// comments and whitespace
+    #[clap(long, value_name = "ALGORITHM", default_value = "md5")]
+    checksum: String,
     #[clap(short = 'd', long, value_name = "CQL_DIR", default_value = "./cql")]
     cql_dir: PathBuf,
     #[clap(long, value_name = "HISTORY_KEYSPACE", default_value = cquill::KEYSPACE)]
@@ -31,6 +124,25 @@ struct MigrateCliArgs {
     history_replication: String,
     #[clap(long, value_name = "HISTORY_TABLE", default_value = cquill::TABLE)]
     history_table: String,
+    /// Rename a keyspace referenced by CQL files, such as `api=api_pr123` for an isolated deploy
+    #[clap(long, value_name = "KEYSPACE=MAPPED_KEYSPACE")]
+    keyspace_map: Vec<String>,
+    /// Migrate a named cql dir recorded in its own history table, defaulting to
+    /// migrated_cql_NAME, instead of --cql-dir, repeated for sources migrated in order
+    #[clap(long, value_name = "NAME=CQL_DIR[,HISTORY_TABLE]")]
+    source: Vec<String>,
+    /// Also migrate cql files named with the profile's suffix, such as `v004-seed.dev.cql` for dev
+    #[clap(long, value_name = "PROFILE")]
+    profile: Option<String>,
+    /// Project config with a [vars] table of template var defaults
+    #[clap(long, value_name = "CONFIG", default_value = CONFIG_FILENAME)]
+    config: PathBuf,
+    /// Render `${NAME}` and `{{ NAME }}` placeholders of CQL files with a value
+    #[clap(long, value_name = "NAME=VALUE")]
+    var: Vec<String>,
+    /// Validate cql files are named with sequential, integer or timestamp versions
+    #[clap(long, value_name = "VERSION_SCHEME", default_value = "sequential")]
+    version_scheme: String,
 }
 
 impl MigrateCliArgs {
@@ -39,55 +151,393 @@ impl MigrateCliArgs {
             Ok(replication_factor) => replication_factor,
             Err(err) => error_exit(MigrateError::from(err)),
         };
+        let keyspace_map = if self.keyspace_map.is_empty() {
+            None
+        } else {
+            match self.keyspace_map.join(",").parse::<KeyspaceMap>() {
+                Ok(keyspace_map) => Some(keyspace_map),
+                Err(err) => error_exit(MigrateError::from(err)),
+            }
+        };
+        let checksum_algorithm = match self.checksum.parse::<ChecksumAlgorithm>() {
+            Ok(checksum_algorithm) => checksum_algorithm,
+            Err(err) => error_exit(MigrateError::from(err)),
+        };
+        let version_scheme = match self.version_scheme.parse::<VersionScheme>() {
+            Ok(version_scheme) => version_scheme,
+            Err(err) => error_exit(MigrateError::from(err)),
+        };
+        let sources = match self
+            .source
+            .iter()
+            .map(|source| source.parse::<MigrationSource>())
+            .collect::<anyhow::Result<Vec<MigrationSource>>>()
+        {
+            Ok(sources) => sources,
+            Err(err) => error_exit(MigrateError::from(err)),
+        };
+        let vars = match self.template_vars() {
+            Ok(vars) => vars,
+            Err(err) => error_exit(MigrateError::from(err)),
+        };
         MigrateOpts {
+            allow_destructive: self.allow_destructive,
+            allow_out_of_order: self.allow_out_of_order,
             cassandra_opts: Some(CassandraOpts::default()),
+            checksum_algorithm: Some(checksum_algorithm),
             cql_dir: self.cql_dir.clone(),
             history_keyspace: Some(KeyspaceOpts {
                 name: self.history_keyspace.clone(),
                 replication: Some(replication_factor),
             }),
             history_table: Some(self.history_table.clone()),
+            keyspace_map,
+            profile: self.profile.clone(),
+            sources,
+            vars: Some(vars),
+            version_scheme: Some(version_scheme),
         }
     }
+
+    fn template_vars(&self) -> anyhow::Result<TemplateVars> {
+        let mut vars = TemplateVars::default();
+        for (name, value) in ProjectConfig::load(&self.config)?.vars {
+            vars.insert_default(&name, &value)?;
+        }
+        for var in &self.var {
+            match var.split_once('=') {
+                Some((name, value)) => vars.insert(name.trim(), value)?,
+                None => return Err(anyhow::anyhow!("var {var} is not a NAME=VALUE pair")),
+            }
+        }
+        Ok(vars)
+    }
 }
 
 #[tokio::main]
 async fn main() {
     let cquill_cli = CquillCli::parse();
     match cquill_cli.command {
+        CquillCommand::Drift(args) => drift(args).await,
+        CquillCommand::Fmt(args) => fmt(args).await,
+        CquillCommand::Generate(args) => generate(args).await,
+        CquillCommand::Lint(args) => lint(args),
         CquillCommand::Migrate(args) => migrate(args).await,
+        CquillCommand::New(args) => new(args),
+        CquillCommand::Verify(args) => verify(args),
+    };
+}
+
+async fn drift(args: DriftCliArgs) {
+    let diffs = match drift_cql(DriftOpts {
+        cassandra_opts: Some(CassandraOpts::default()),
+        cql_dir: args.cql_dir,
+    })
+    .await
+    {
+        Ok(diffs) => diffs,
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
+    };
+    if diffs.is_empty() {
+        println!("✔ cluster schema matches cql files");
+        return;
+    }
+    println!(
+        "{} cluster schema has drifted from cql files:",
+        error_prefix()
+    );
+    diffs.iter().for_each(|diff| match diff {
+        SchemaDiff::Added { object_type, name } => {
+            println!("  {object_type} {name} exists on the cluster but not in cql files")
+        }
+        SchemaDiff::Removed { object_type, name } => {
+            println!("  {object_type} {name} exists in cql files but not on the cluster")
+        }
+        SchemaDiff::Changed {
+            object_type,
+            name,
+            from,
+            to,
+        } => println!("  {object_type} {name} is {from} in cql files but {to} on the cluster"),
+    });
+    std::process::exit(1);
+}
+
+async fn fmt(args: FmtCliArgs) {
+    let check = args.check;
+    let skip_migrated = if args.include_migrated {
+        None
+    } else {
+        Some(MigrateOpts {
+            cassandra_opts: Some(CassandraOpts::default()),
+            cql_dir: args.cql_dir.clone(),
+            history_keyspace: Some(KeyspaceOpts {
+                name: args.history_keyspace,
+                replication: None,
+            }),
+            history_table: Some(args.history_table),
+            ..Default::default()
+        })
+    };
+    let unformatted = match fmt_cql(FmtOpts {
+        cql_dir: args.cql_dir,
+        check,
+        skip_migrated,
+    })
+    .await
+    {
+        Ok(unformatted) => unformatted,
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
+    };
+    if unformatted.is_empty() {
+        println!("✔ cql files are formatted");
+    } else if check {
+        println!("{} cql files are not formatted:", error_prefix());
+        unformatted.iter().for_each(|p| {
+            println!("  {}", p.to_string_lossy());
+        });
+        std::process::exit(1);
+    } else if unformatted.len() == 1 {
+        println!(
+            "✔ 1 cql file formatted: {}",
+            unformatted[0].to_string_lossy()
+        );
+    } else {
+        println!("✔ {} cql files formatted:", unformatted.len());
+        unformatted.iter().for_each(|p| {
+            println!("  {}", p.to_string_lossy());
+        });
+    }
+}
+
+async fn generate(args: GenerateCliArgs) {
+    let desired = match args.schema {
+        None => DesiredSchema::Cluster(Some(CassandraOpts::default())),
+        Some(path) => DesiredSchema::CqlFile(path),
+    };
+    let generated = match args.version_scheme.parse::<VersionScheme>() {
+        Err(err) => Err(err),
+        Ok(version_scheme) => {
+            generate_cql(GenerateOpts {
+                cql_dir: args.cql_dir,
+                description: args.description,
+                desired,
+                version_scheme,
+            })
+            .await
+        }
+    };
+    match generated {
+        Ok(None) => println!("✔ cql files already match the desired schema"),
+        Ok(Some(cql_file)) => println!("✔ generated {}", cql_file.path.to_string_lossy()),
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
+    }
+}
+
+fn lint(args: LintCliArgs) {
+    let findings = match ProjectConfig::load(&args.config)
+        .and_then(|config| lint_rule_levels(&config))
+        .and_then(|rule_levels| {
+            lint_cql(LintOpts {
+                cql_dir: args.cql_dir,
+                dev: args.dev,
+                rule_levels,
+            })
+        }) {
+        Ok(findings) => findings,
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
     };
+    if findings.is_empty() {
+        println!("✔ cql files passed lint rules");
+        return;
+    }
+    findings.iter().for_each(|finding| println!("{finding}"));
+    let errors = findings
+        .iter()
+        .filter(|finding| finding.level == LintLevel::Error)
+        .count();
+    println!(
+        "{} errors and {} warnings found by lint rules",
+        errors,
+        findings.len() - errors
+    );
+    if errors > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn new(args: NewCliArgs) {
+    let created = args
+        .version_scheme
+        .parse::<VersionScheme>()
+        .and_then(|version_scheme| {
+            new_cql_file(NewOpts {
+                cql_dir: args.cql_dir,
+                description: args.description,
+                down: args.down,
+                version_scheme,
+            })
+        });
+    match created {
+        Ok(created) => created.iter().for_each(|path| {
+            println!("✔ created {}", path.to_string_lossy());
+        }),
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
+    }
+}
+
+fn verify(args: VerifyCliArgs) {
+    let verified = match verify_cql(VerifyOpts {
+        cql_dir: args.cql_dir,
+    }) {
+        Ok(verified) => verified,
+        Err(err) => {
+            println!("{} {err}", error_prefix());
+            std::process::exit(1);
+        }
+    };
+    if verified.cql_files.len() == 1 {
+        println!("✔ 1 cql file verified");
+    } else {
+        println!("✔ {} cql files verified", verified.cql_files.len());
+    }
+    if !verified.unverified.is_empty() {
+        println!("Statements cquill cannot parse yet were not verified:");
+        verified.unverified.iter().for_each(|unverified| {
+            println!("  line {} of {}", unverified.line, unverified.filename);
+        });
+    }
 }
 
 async fn migrate(args: MigrateCliArgs) {
     let opts = args.to_opts();
     let version = env!("CARGO_PKG_VERSION");
-    let cql_dir = opts.cql_dir.to_string_lossy();
-    println!("CQuill {version}\nMigrating CQL files from {cql_dir}");
-    match migrate_cql(opts).await {
-        Ok(migrated_cql) => print_migrated_cql(&migrated_cql),
-        Err(err) => match err {
-            HistoryUpdateFailed {
-                error_state,
-                cquill_keyspace,
-                cquill_table,
-            } => history_update_failed_exit(error_state.deref(), cquill_keyspace, cquill_table),
-            PartialMigration { error_state } => partial_migrate_error_exit(error_state.deref()),
-            _ => error_exit(err),
-        },
-    }
-}
-
-fn print_migrated_cql(migrated_cql: &[CqlFile]) {
-    if migrated_cql.is_empty() {
-        println!("✔ already up to date");
-    } else if migrated_cql.len() == 1 {
-        println!("✔ 1 cql file migrated: {}", migrated_cql[0].filename);
+    println!("CQuill {version}");
+    if opts.sources.is_empty() {
+        println!(
+            "Migrating CQL files from {}",
+            opts.cql_dir.to_string_lossy()
+        );
+    }
+    for source in &opts.sources {
+        println!(
+            "Migrating CQL files of {} from {}",
+            source.name,
+            source.cql_dir.to_string_lossy()
+        );
+    }
+    if !args.keyspace_map.is_empty() {
+        println!("Renaming keyspaces {}", args.keyspace_map.join(", "));
+    }
+    let migrator = Migrator::builder()
+        .listener(MigrateProgress {
+            named_sources: !opts.sources.is_empty(),
+        })
+        .opts(opts)
+        .build()
+        .await;
+    let migrate_result = match migrator {
+        Ok(migrator) => migrator.migrate().await,
+        Err(err) => Err(err),
+    };
+    if let Err(err) = migrate_result {
+        migrate_error_exit(err);
+    }
+}
+
+/// Prints the progress of `cquill migrate` as cql files are migrated.
+struct MigrateProgress {
+    named_sources: bool,
+}
+
+impl MigrationListener for MigrateProgress {
+    fn source_started(&self, name: &str) {
+        if self.named_sources {
+            println!("{name}:");
+        }
+    }
+
+    fn source_finished(&self, source: &MigratedSource) {
+        match source.migrated.len() {
+            0 => println!("✔ already up to date"),
+            1 => println!("✔ 1 cql file migrated"),
+            migrated => println!("✔ {migrated} cql files migrated"),
+        }
+    }
+
+    fn file_started(&self, cql_file: &CqlFile) {
+        println!("  migrating {}", migrated_filename(cql_file));
+    }
+
+    fn file_finished(&self, cql_file: &CqlFile, duration: Duration) {
+        println!(
+            "  ✔ {} migrated in {}ms",
+            cql_file.filename,
+            duration.as_millis()
+        );
+    }
+
+    fn statement_finished(
+        &self,
+        _cql_file: &CqlFile,
+        statement: &CqlStatement,
+        duration: Duration,
+    ) {
+        println!(
+            "    {} executed in {}ms",
+            line_range(statement.lines),
+            duration.as_millis()
+        );
+    }
+}
+
+fn migrate_error_exit(err: MigrateError) {
+    match err {
+        HistoryUpdateFailed {
+            error_state,
+            cquill_keyspace,
+            cquill_table,
+        } => history_update_failed_exit(error_state.deref(), cquill_keyspace, cquill_table),
+        PartialMigration { error_state } => partial_migrate_error_exit(error_state.deref()),
+        DestructiveCql(destructive) => destructive_cql_exit(&destructive),
+        SourceMigration { name, error, .. } => {
+            println!("Migrating source {name} failed");
+            migrate_error_exit(*error)
+        }
+        _ => error_exit(err),
+    }
+}
+
+fn migrated_filename(cql_file: &CqlFile) -> String {
+    if cql_file.out_of_order {
+        format!("{} (out of order)", cql_file.filename)
     } else {
-        println!("✔ {} cql files migrated:", migrated_cql.len());
-        migrated_cql.iter().for_each(|p| {
-            println!("  {}", p.filename);
-        });
+        cql_file.filename.clone()
+    }
+}
+
+fn line_range(lines: (usize, usize)) -> String {
+    if lines.0 == lines.1 {
+        format!("line {}", lines.0)
+    } else if lines.1 - lines.0 == 1 {
+        format!("lines {} and {}", lines.0, lines.1)
+    } else {
+        format!("lines {} to {}", lines.0, lines.1)
     }
 }
 
@@ -104,9 +554,6 @@ fn history_update_failed_exit(
     cquill_keyspace: String,
     cquill_table: String,
 ) {
-    if !error_state.migrated.is_empty() {
-        print_migrated_cql(&error_state.migrated);
-    }
     println!(
         "\nUpdating CQuill's migration history table failed after executing the CQL from {}.",
         error_state.failed_file
@@ -120,23 +567,31 @@ fn history_update_failed_exit(
     println!("===============");
 }
 
+fn destructive_cql_exit(destructive: &[cquill::DestructiveCql]) -> ! {
+    println!(
+        "{} migrate stopped before executing destructive cql:",
+        error_prefix()
+    );
+    destructive.iter().for_each(|destructive| {
+        println!(
+            "  line {} of {} {}: {}",
+            destructive.line, destructive.filename, destructive.change, destructive.cql
+        );
+    });
+    println!(
+        "Pass --allow-destructive or add a `-- cquill: allow-destructive` comment to the cql file to migrate these statements."
+    );
+    std::process::exit(1);
+}
+
 fn partial_migrate_error_exit(error_state: &MigrateErrorState) {
-    if !error_state.migrated.is_empty() {
-        print_migrated_cql(&error_state.migrated);
-    }
     match &error_state.failed_cql {
         None => println!("Migrate failed during {}", error_state.failed_file),
         Some(failed_cql) => {
             println!(
                 "\nMigrate failed during {} ({}) on the CQL statement:\n    {}",
                 error_state.failed_file,
-                if failed_cql.lines.0 == failed_cql.lines.1 {
-                    format!("line {}", failed_cql.lines.0)
-                } else if failed_cql.lines.1 - failed_cql.lines.0 == 1 {
-                    format!("lines {} and {}", failed_cql.lines.0, failed_cql.lines.1)
-                } else {
-                    format!("lines {} to {}", failed_cql.lines.0, failed_cql.lines.1)
-                },
+                line_range(failed_cql.lines),
                 failed_cql.cql
             );
         }
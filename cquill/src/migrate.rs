@@ -1,18 +1,35 @@
-use std::collections::VecDeque;
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::Result;
 use scylla::client::session::Session;
 
-use crate::cql_file::{CqlFile, CqlStatement};
+use crate::checksum::ChecksumAlgorithm;
+use crate::cql_file::{CqlFile, CqlFileKind, CqlStatement};
+use crate::destructive::{self, DestructiveCql};
+use crate::keyspace::KeyspaceMap;
+use crate::listener::MigrationListener;
 use crate::queries;
 use crate::queries::QueryError;
 use crate::queries::keyspace::CreateKeyspaceError;
+use crate::queries::migrated::files::{Execution, ExecutionStatus};
+use crate::rewrite;
+use crate::source::MigratedSource;
+use crate::template::TemplateVars;
 
 #[derive(thiserror::Error, Debug)]
 pub enum MigrateError {
     #[error("error reading {filename} from disk: {error}")]
     CqlFileReadError { filename: String, error: String },
+    #[error(
+        "destructive cql must be allowed to migrate: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<String>>().join(", ")
+    )]
+    DestructiveCql(Vec<DestructiveCql>),
+    #[error("error rendering template vars of {filename}: {error}")]
+    CqlFileRenderError { filename: String, error: String },
     #[error("cql query error: {source}")]
     CqlQueryError {
         #[from]
@@ -32,10 +49,49 @@ pub enum MigrateError {
         cquill_table: String,
         error_state: Box<MigrateErrorState>,
     },
+    #[error(
+        "embedded migrations are migrated without named sources, which must not also be configured"
+    )]
+    EmbeddedWithSources,
+    #[error(
+        "keyspace map cannot rewrite keyspace names of unsupported cql statement on line {line} of {filename}"
+    )]
+    KeyspaceMapUnsupportedCql { filename: String, line: usize },
+    #[error(
+        "previously migrated file '{filename}' has been moved from {migrated_path} to {path} (its path in the cql dir is recorded in {cquill_keyspace}.{cquill_table})"
+    )]
+    MovedCql {
+        filename: String,
+        migrated_path: String,
+        path: String,
+        cquill_keyspace: String,
+        cquill_table: String,
+    },
+    #[error(
+        "{filename} must be allowed to migrate out of order after {migrated_filename} of a higher version was migrated"
+    )]
+    OutOfOrderCql {
+        filename: String,
+        migrated_filename: String,
+    },
+    #[error(
+        "history table {cquill_keyspace}.{cquill_table} was created by an earlier version of cquill and must be upgraded by running cquill migrate"
+    )]
+    OutdatedHistoryTable {
+        cquill_keyspace: String,
+        cquill_table: String,
+    },
     #[error("errored during migrate of '{0}': {1}", error_state.failed_file.filename, error_state.error)]
     PartialMigration { error_state: Box<MigrateErrorState> },
     #[error(transparent)]
     PrepareKeyspaceError(#[from] CreateKeyspaceError),
+    #[error("errored migrating source {name}: {error}")]
+    SourceMigration {
+        name: String,
+        /// Sources migrated before the source that errored.
+        migrated: Vec<MigratedSource>,
+        error: Box<MigrateError>,
+    },
     #[error("{source}")]
     Other {
         #[from]
@@ -52,9 +108,24 @@ pub struct MigrateErrorState {
 }
 
 pub(crate) struct MigrateArgs {
+    pub allow_destructive: bool,
+    pub allow_out_of_order: bool,
+    pub checksum_algorithm: ChecksumAlgorithm,
     pub cql_dir: PathBuf,
     pub history_keyspace: String,
     pub history_table: String,
+    pub keyspace_map: KeyspaceMap,
+    pub listener: Arc<dyn MigrationListener>,
+    pub profile: Option<String>,
+    pub vars: TemplateVars,
+}
+
+/// A cql file pending migration with the statements that will be executed, rendered with template
+/// vars and rewritten with the keyspace map.
+#[derive(Debug)]
+pub struct PlannedCql {
+    pub cql_file: CqlFile,
+    pub statements: Vec<CqlStatement>,
 }
 
 pub(crate) async fn perform(
@@ -62,19 +133,123 @@ pub(crate) async fn perform(
     cql_files: &[CqlFile],
     args: MigrateArgs,
 ) -> Result<Vec<CqlFile>, MigrateError> {
-    let mut previously_migrated = VecDeque::from(
-        queries::migrated::files::select_all(
-            session,
-            &args.history_keyspace,
-            &args.history_table,
-            &args.cql_dir,
-        )
-        .await?,
-    );
-    let mut not_migrated: Vec<(CqlFile, Vec<CqlStatement>)> = Vec::new();
+    let history = select_history(session, &args).await?;
+    let planned = plan(session, cql_files, history, &args)?;
+    execute(session, planned, args).await
+}
+
+/// Selects the migrated cql files recorded in the history table.
+pub(crate) async fn select_history(
+    session: &Session,
+    args: &MigrateArgs,
+) -> Result<Vec<CqlFile>, MigrateError> {
+    Ok(queries::migrated::files::select_all(
+        session,
+        &args.history_keyspace,
+        &args.history_table,
+        &args.cql_dir,
+        &args.keyspace_map,
+    )
+    .await?)
+}
+
+/// The cql files of `cql_files` not yet migrated according to `history`, without validating
+/// migrated cql files against their history.
+pub(crate) fn pending(
+    cql_files: &[CqlFile],
+    history: &[CqlFile],
+    args: &MigrateArgs,
+) -> Vec<CqlFile> {
+    cql_files
+        .iter()
+        .filter(|cql_file| cql_file.profile.is_none() || cql_file.profile == args.profile)
+        .filter(|cql_file| {
+            !history.iter().any(
+                |migrated_cql_file| match (cql_file.kind, &cql_file.profile) {
+                    (CqlFileKind::Repeatable, _) => {
+                        migrated_cql_file.kind == CqlFileKind::Repeatable
+                            && migrated_cql_file.filename == cql_file.filename
+                            && cql_file
+                                .checksum(migrated_cql_file.checksum_algorithm)
+                                .is_ok_and(|hash| hash == migrated_cql_file.hash)
+                    }
+                    (CqlFileKind::Versioned, None) => {
+                        migrated_cql_file.kind == CqlFileKind::Versioned
+                            && migrated_cql_file.profile.is_none()
+                            && migrated_cql_file.version == cql_file.version
+                    }
+                    (CqlFileKind::Versioned, Some(_)) => {
+                        migrated_cql_file.filename == cql_file.filename
+                    }
+                },
+            )
+        })
+        .cloned()
+        .collect()
+}
+
+/// Validates `cql_files` against `history` and plans the cql files to migrate, erroring as
+/// migrating would before executing any cql.
+pub(crate) fn plan(
+    session: &Session,
+    cql_files: &[CqlFile],
+    history: Vec<CqlFile>,
+    args: &MigrateArgs,
+) -> Result<Vec<PlannedCql>, MigrateError> {
+    let mut previously_migrated: HashMap<i64, CqlFile> = HashMap::new();
+    let mut previously_profiled: Vec<CqlFile> = Vec::new();
+    let mut previously_repeated: Vec<CqlFile> = Vec::new();
+    for migrated_cql_file in history {
+        match (migrated_cql_file.kind, &migrated_cql_file.profile) {
+            (CqlFileKind::Repeatable, _) => previously_repeated.push(migrated_cql_file),
+            (CqlFileKind::Versioned, None) => {
+                previously_migrated.insert(migrated_cql_file.version, migrated_cql_file);
+            }
+            (CqlFileKind::Versioned, Some(_)) => previously_profiled.push(migrated_cql_file),
+        }
+    }
+    let latest_migrated = previously_migrated
+        .values()
+        .max_by_key(|migrated_cql_file| migrated_cql_file.version);
+    let mut not_migrated: Vec<PlannedCql> = Vec::new();
     for cql_file in cql_files {
-        if let Some(migrated_cql_file) = previously_migrated.pop_front() {
-            if cql_file.hash == migrated_cql_file.hash {
+        let mut out_of_order = false;
+        if cql_file.profile.is_some() && cql_file.profile != args.profile {
+            continue;
+        }
+        if cql_file.kind == CqlFileKind::Repeatable {
+            // repeatable cql files are migrated again whenever their content changes
+            if let Some(repeated) = previously_repeated
+                .iter()
+                .find(|repeated| repeated.filename == cql_file.filename)
+            {
+                check_not_moved(cql_file, repeated, args)?;
+                if cql_file.checksum(repeated.checksum_algorithm)? == repeated.hash {
+                    continue;
+                }
+            }
+        } else if cql_file.profile.is_some() {
+            // profile cql files share versions and are matched to history by filename
+            if let Some(migrated_cql_file) = previously_profiled
+                .iter()
+                .find(|profiled| profiled.filename == cql_file.filename)
+            {
+                check_not_moved(cql_file, migrated_cql_file, args)?;
+                if cql_file.checksum(migrated_cql_file.checksum_algorithm)?
+                    == migrated_cql_file.hash
+                {
+                    continue;
+                } else {
+                    return Err(MigrateError::HashConflict {
+                        filename: cql_file.filename.clone(),
+                        cquill_keyspace: args.history_keyspace.clone(),
+                        cquill_table: args.history_table.clone(),
+                    });
+                }
+            }
+        } else if let Some(migrated_cql_file) = previously_migrated.get(&cql_file.version) {
+            check_not_moved(cql_file, migrated_cql_file, args)?;
+            if cql_file.checksum(migrated_cql_file.checksum_algorithm)? == migrated_cql_file.hash {
                 continue;
             } else {
                 return Err(MigrateError::HashConflict {
@@ -83,37 +258,122 @@ pub(crate) async fn perform(
                     cquill_table: args.history_table.clone(),
                 });
             }
+        } else if let Some(latest_migrated) = latest_migrated
+            && latest_migrated.version > cql_file.version
+        {
+            // a file merged from a branch after a file of a higher version was migrated
+            if !args.allow_out_of_order {
+                return Err(MigrateError::OutOfOrderCql {
+                    filename: cql_file.filename.clone(),
+                    migrated_filename: latest_migrated.filename.clone(),
+                });
+            }
+            out_of_order = true;
+        }
+        let mut cql = cql_file.read_statements(&args.vars)?;
+        if !args.keyspace_map.is_empty() {
+            for cql_statement in cql.iter_mut() {
+                match rewrite::rewrite_keyspace_names(&cql_statement.cql, &args.keyspace_map) {
+                    Some(rewritten) => cql_statement.cql = rewritten,
+                    None => {
+                        return Err(MigrateError::KeyspaceMapUnsupportedCql {
+                            filename: cql_file.filename.clone(),
+                            line: cql_statement.lines.0,
+                        });
+                    }
+                }
+            }
+        }
+        let cql_file = CqlFile {
+            out_of_order,
+            ..cql_file.with_checksum_algorithm(args.checksum_algorithm)?
+        };
+        not_migrated.push(PlannedCql {
+            cql_file,
+            statements: cql,
+        });
+    }
+    if !args.allow_destructive {
+        let pending: Vec<CqlFile> = not_migrated
+            .iter()
+            .map(|planned| planned.cql_file.clone())
+            .collect();
+        let destructive =
+            destructive::find_destructive_cql(session, &pending, &args.keyspace_map, &args.vars)?;
+        if !destructive.is_empty() {
+            return Err(MigrateError::DestructiveCql(destructive));
         }
-        let cql = cql_file.read_statements()?;
-        not_migrated.push((cql_file.clone(), cql));
     }
+    Ok(not_migrated)
+}
+
+/// Executes the statements of `planned` cql files, recording each file in the history table.
+pub(crate) async fn execute(
+    session: &Session,
+    planned: Vec<PlannedCql>,
+    args: MigrateArgs,
+) -> Result<Vec<CqlFile>, MigrateError> {
     let mut migrated: Vec<CqlFile> = Vec::new();
-    for cql in not_migrated {
-        for cql_statement in cql.1 {
+    for planned_cql in planned {
+        let cql_file = planned_cql.cql_file;
+        args.listener.file_started(&cql_file);
+        let started = Instant::now();
+        let mut execution = Execution::default();
+        for cql_statement in planned_cql.statements {
+            args.listener.statement_started(&cql_file, &cql_statement);
+            let statement_started = Instant::now();
             if let Err(err) = queries::exec(session, cql_statement.cql.clone()).await {
+                execution.duration = started.elapsed();
+                execution.status = ExecutionStatus::Failure;
+                // the failure is recorded for auditing and the migration error is reported
+                // whether or not recording it succeeds
+                if queries::migrated::files::insert(
+                    session,
+                    &args.history_keyspace,
+                    &args.history_table,
+                    &args.cql_dir,
+                    &cql_file,
+                    &args.keyspace_map,
+                    &execution,
+                )
+                .await
+                .is_ok()
+                {
+                    args.listener.history_inserted(&cql_file);
+                }
                 return Err(MigrateError::PartialMigration {
                     error_state: Box::from(MigrateErrorState {
                         error: err.to_string(),
                         failed_cql: Some(cql_statement),
-                        failed_file: cql.0,
+                        failed_file: cql_file,
                         migrated,
                     }),
                 });
             }
+            args.listener.statement_finished(
+                &cql_file,
+                &cql_statement,
+                statement_started.elapsed(),
+            );
+            execution.statement_count += 1;
         }
-        migrated.push(cql.0.clone());
+        execution.duration = started.elapsed();
+        migrated.push(cql_file.clone());
         if let Err(err) = queries::migrated::files::insert(
             session,
             &args.history_keyspace,
             &args.history_table,
-            &cql.0,
+            &args.cql_dir,
+            &cql_file,
+            &args.keyspace_map,
+            &execution,
         )
         .await
         {
             return Err(MigrateError::HistoryUpdateFailed {
                 error_state: Box::from(MigrateErrorState {
                     error: err.to_string(),
-                    failed_file: cql.0,
+                    failed_file: cql_file,
                     failed_cql: None,
                     migrated,
                 }),
@@ -121,10 +381,36 @@ pub(crate) async fn perform(
                 cquill_table: args.history_table,
             });
         };
+        args.listener.history_inserted(&cql_file);
+        args.listener.file_finished(&cql_file, execution.duration);
     }
     Ok(migrated)
 }
 
+/// Errors when a previously migrated file is not in the subdirectory of the cql dir recorded with
+/// it, such as after renaming a subdirectory, instead of matching it to history by version or
+/// filename.
+fn check_not_moved(
+    cql_file: &CqlFile,
+    migrated_cql_file: &CqlFile,
+    args: &MigrateArgs,
+) -> Result<(), MigrateError> {
+    let path = cql_file.relative_path(&args.cql_dir);
+    let migrated_path = migrated_cql_file.relative_path(&args.cql_dir);
+    let subdirectory = |path: &str| path.rsplit_once('/').map(|(dir, _)| dir.to_string());
+    if subdirectory(&path) == subdirectory(&migrated_path) {
+        Ok(())
+    } else {
+        Err(MigrateError::MovedCql {
+            filename: cql_file.filename.clone(),
+            migrated_path,
+            path,
+            cquill_keyspace: args.history_keyspace.clone(),
+            cquill_table: args.history_table.clone(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test_utils;
@@ -164,7 +450,10 @@ mod tests {
             &harness.session,
             &harness.cquill_keyspace,
             &harness.cquill_table.clone(),
+            &harness.cql_dir,
             &CqlFile::from_path(harness.cql_file_path("v001.cql")).unwrap(),
+            &KeyspaceMap::default(),
+            &Execution::default(),
         )
         .await
         .expect("save migrated file");
@@ -196,12 +485,20 @@ mod tests {
             &harness.session,
             &harness.cquill_keyspace,
             &harness.cquill_table.clone(),
+            &harness.cql_dir,
             &CqlFile {
+                checksum_algorithm: ChecksumAlgorithm::Md5,
+                embedded_cql: None,
                 version: 1,
                 hash: "abc".to_string(),
+                kind: CqlFileKind::Versioned,
                 path: harness.cql_file_path("v001.cql"),
+                profile: None,
                 filename: "v001.cql".to_string(),
+                out_of_order: false,
             },
+            &KeyspaceMap::default(),
+            &Execution::default(),
         )
         .await
         .expect("save migrated file");
@@ -260,4 +557,289 @@ mod tests {
 
         harness.drop_keyspace().await;
     }
+
+    #[tokio::test]
+    async fn test_migrate_with_keyspace_map() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "CREATE TABLE api.asdf (id UUID PRIMARY KEY);")
+            .initialize()
+            .await;
+        let migrate_args = || MigrateArgs {
+            keyspace_map: format!("api={}", harness.cquill_keyspace)
+                .parse::<KeyspaceMap>()
+                .unwrap(),
+            ..harness.migrate_args()
+        };
+
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate with keyspace map");
+        assert_eq!(migrated_files.len(), 1);
+        let table_names =
+            crate::keyspace::get_keyspace_table_names(&harness.session, &harness.cquill_keyspace)
+                .unwrap();
+        assert!(table_names.contains(&"asdf".to_string()));
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate with keyspace map");
+        assert!(migrated_files.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_errors_when_keyspace_map_cannot_rewrite_cql() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "\nGRANT SELECT ON KEYSPACE api TO api_role;")
+            .initialize()
+            .await;
+        let migrate_args = MigrateArgs {
+            keyspace_map: "api=api_pr123".parse::<KeyspaceMap>().unwrap(),
+            ..harness.migrate_args()
+        };
+
+        match perform(&harness.session, &harness.cql_files, migrate_args).await {
+            Err(MigrateError::KeyspaceMapUnsupportedCql { filename, line }) => {
+                assert_eq!(filename, "v001.cql");
+                assert_eq!(line, 2);
+            }
+            _ => panic!("error was not a MigrateError::KeyspaceMapUnsupportedCql"),
+        }
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_refuses_destructive_cql() {
+        let keyspace = test_utils::keyspace_name();
+        let harness = test_utils::TestHarness::builder()
+            .cql_file(
+                "v001.cql",
+                format!("CREATE TABLE {keyspace}.asdf (id UUID PRIMARY KEY);").as_str(),
+            )
+            .cql_file(
+                "v002.cql",
+                format!("\nDROP TABLE {keyspace}.asdf;").as_str(),
+            )
+            .cquill_history(keyspace.as_str(), "cquill")
+            .initialize()
+            .await;
+
+        match perform(&harness.session, &harness.cql_files, harness.migrate_args()).await {
+            Err(MigrateError::DestructiveCql(destructive)) => {
+                assert_eq!(destructive.len(), 1);
+                assert_eq!(destructive[0].filename, "v002.cql");
+                assert_eq!(destructive[0].line, 2);
+            }
+            _ => panic!("error was not a MigrateError::DestructiveCql"),
+        }
+        let migrate_args = MigrateArgs {
+            allow_destructive: true,
+            ..harness.migrate_args()
+        };
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args)
+            .await
+            .expect("migrate allowing destructive cql");
+        assert_eq!(migrated_files.len(), 2);
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_repeatable_cql_when_changed() {
+        let keyspace = test_utils::keyspace_name();
+        let harness = test_utils::TestHarness::builder()
+            .cql_file(
+                "v001.cql",
+                format!("CREATE TABLE {keyspace}.asdf (id UUID PRIMARY KEY);").as_str(),
+            )
+            .cql_file(
+                "R__views.cql",
+                format!("CREATE MATERIALIZED VIEW IF NOT EXISTS {keyspace}.asdf_view AS SELECT id FROM {keyspace}.asdf WHERE id IS NOT NULL PRIMARY KEY (id);").as_str(),
+            )
+            .cquill_history(keyspace.as_str(), "cquill")
+            .initialize()
+            .await;
+
+        let migrated_files = perform(&harness.session, &harness.cql_files, harness.migrate_args())
+            .await
+            .expect("migrate");
+        let migrated_file_names: Vec<&str> =
+            migrated_files.iter().map(|f| f.filename.as_str()).collect();
+        assert_eq!(migrated_file_names, vec!["v001.cql", "R__views.cql"]);
+        let migrated_files = perform(&harness.session, &harness.cql_files, harness.migrate_args())
+            .await
+            .expect("migrate");
+        assert!(migrated_files.is_empty());
+
+        test_utils::make_file(
+            harness.cql_file_path("R__views.cql"),
+            format!("-- views\nCREATE MATERIALIZED VIEW IF NOT EXISTS {keyspace}.asdf_view AS SELECT id FROM {keyspace}.asdf WHERE id IS NOT NULL PRIMARY KEY (id);").as_str(),
+        );
+        let cql_files = crate::cql_file::files_from_dir(&harness.cql_dir).unwrap();
+        let migrated_files = perform(&harness.session, &cql_files, harness.migrate_args())
+            .await
+            .expect("migrate");
+        let migrated_file_names: Vec<&str> =
+            migrated_files.iter().map(|f| f.filename.as_str()).collect();
+        assert_eq!(migrated_file_names, vec!["R__views.cql"]);
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_profile_cql_with_profile() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "")
+            .cql_file("v001-seed.dev.cql", "")
+            .cql_file("v002.cql", "")
+            .initialize()
+            .await;
+
+        let migrated_files = perform(&harness.session, &harness.cql_files, harness.migrate_args())
+            .await
+            .expect("migrate");
+        let migrated_file_names: Vec<&str> =
+            migrated_files.iter().map(|f| f.filename.as_str()).collect();
+        assert_eq!(migrated_file_names, vec!["v001.cql", "v002.cql"]);
+        let migrate_args = || MigrateArgs {
+            profile: Some("dev".to_string()),
+            ..harness.migrate_args()
+        };
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate with dev profile");
+        let migrated_file_names: Vec<&str> =
+            migrated_files.iter().map(|f| f.filename.as_str()).collect();
+        assert_eq!(migrated_file_names, vec!["v001-seed.dev.cql"]);
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate with dev profile");
+        assert!(migrated_files.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_validates_history_with_recorded_checksum_algorithm() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "")
+            .cql_file("v002.cql", "")
+            .initialize()
+            .await;
+        queries::migrated::files::insert(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table.clone(),
+            &harness.cql_dir,
+            &CqlFile::from_path(harness.cql_file_path("v001.cql")).unwrap(),
+            &KeyspaceMap::default(),
+            &Execution::default(),
+        )
+        .await
+        .expect("save migrated file");
+
+        let migrate_args = || MigrateArgs {
+            checksum_algorithm: ChecksumAlgorithm::Sha256,
+            ..harness.migrate_args()
+        };
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate");
+        assert_eq!(migrated_files.len(), 1);
+        assert_eq!(migrated_files[0].filename, "v002.cql");
+        assert_eq!(
+            migrated_files[0].hash,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate");
+        assert!(migrated_files.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_out_of_order_cql_when_allowed() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "")
+            .cql_file("v002-from-branch.cql", "")
+            .cql_file("v003.cql", "")
+            .initialize()
+            .await;
+        for filename in ["v001.cql", "v003.cql"] {
+            queries::migrated::files::insert(
+                &harness.session,
+                &harness.cquill_keyspace,
+                &harness.cquill_table.clone(),
+                &harness.cql_dir,
+                &CqlFile::from_path(harness.cql_file_path(filename)).unwrap(),
+                &KeyspaceMap::default(),
+                &Execution::default(),
+            )
+            .await
+            .expect("save migrated file");
+        }
+
+        match perform(&harness.session, &harness.cql_files, harness.migrate_args()).await {
+            Ok(_) => panic!(),
+            Err(err) => assert_eq!(
+                err.to_string(),
+                "v002-from-branch.cql must be allowed to migrate out of order after v003.cql of a higher version was migrated"
+            ),
+        }
+        let migrate_args = || MigrateArgs {
+            allow_out_of_order: true,
+            ..harness.migrate_args()
+        };
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate out of order");
+        assert_eq!(migrated_files.len(), 1);
+        assert_eq!(migrated_files[0].filename, "v002-from-branch.cql");
+        assert!(migrated_files[0].out_of_order);
+        let migrated_files = perform(&harness.session, &harness.cql_files, migrate_args())
+            .await
+            .expect("migrate");
+        assert!(migrated_files.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrate_errors_when_migrated_cql_moved_to_another_subdirectory() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("2026-q3/v001.cql", "")
+            .cql_file("2026-q3/v002.cql", "")
+            .initialize()
+            .await;
+        queries::migrated::files::insert(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            &harness.cql_dir,
+            &CqlFile {
+                path: harness.cql_dir.join("2026-q2").join("v001.cql"),
+                ..harness.cql_files[0].clone()
+            },
+            &KeyspaceMap::default(),
+            &Execution::default(),
+        )
+        .await
+        .expect("save migrated file");
+
+        match perform(&harness.session, &harness.cql_files, harness.migrate_args()).await {
+            Ok(_) => panic!(),
+            Err(err) => assert_eq!(
+                err.to_string(),
+                format!(
+                    "previously migrated file 'v001.cql' has been moved from 2026-q2/v001.cql to 2026-q3/v001.cql (its path in the cql dir is recorded in {}.{})",
+                    harness.cquill_keyspace, harness.cquill_table
+                )
+            ),
+        }
+
+        harness.drop_keyspace().await;
+    }
 }
@@ -0,0 +1,585 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use scylla::client::session::Session;
+
+use crate::checksum::ChecksumAlgorithm;
+use crate::cql_file::{self, CqlFile, VersionScheme};
+use crate::embed::EmbeddedMigrations;
+use crate::keyspace::{KeyspaceMap, KeyspaceOpts, get_keyspace_table_names};
+use crate::listener::MigrationListener;
+use crate::migrate::{self, MigrateArgs, MigrateError};
+use crate::source::{self, MigratedSource, MigrationSource, SourcePlan, SourceStatus};
+use crate::template::TemplateVars;
+use crate::verify::{self, VerifiedCql, VerifyError};
+use crate::{CassandraOpts, KEYSPACE, MigrateOpts, TABLE};
+
+/// Builds a [Migrator] from [MigrateOpts], connecting to [MigrateOpts::cassandra_opts] unless
+/// given a session.
+#[derive(Default)]
+pub struct MigratorBuilder {
+    embedded: Option<EmbeddedMigrations>,
+    listener: Option<Arc<dyn MigrationListener>>,
+    opts: MigrateOpts,
+    session: Option<Arc<Session>>,
+}
+
+impl MigratorBuilder {
+    /// Migrates with an existing session, such as one configured with auth, TLS and load
+    /// balancing, instead of connecting with [MigrateOpts::cassandra_opts].
+    pub fn session(mut self, session: impl Into<Arc<Session>>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+
+    /// Replaces the options set on the builder.
+    pub fn opts(mut self, opts: MigrateOpts) -> Self {
+        self.opts = opts;
+        self
+    }
+
+    /// Migrates cql files embedded with [embed_migrations](crate::embed_migrations) instead of
+    /// reading [MigrateOpts::cql_dir]. Building errors when [MigrateOpts::sources] are also
+    /// configured.
+    pub fn embedded(mut self, migrations: EmbeddedMigrations) -> Self {
+        self.embedded = Some(migrations);
+        self
+    }
+
+    /// Reports the progress of migrations to `listener`.
+    pub fn listener(mut self, listener: impl MigrationListener + 'static) -> Self {
+        self.listener = Some(Arc::new(listener));
+        self
+    }
+
+    pub fn allow_destructive(mut self, allow_destructive: bool) -> Self {
+        self.opts.allow_destructive = allow_destructive;
+        self
+    }
+
+    pub fn allow_out_of_order(mut self, allow_out_of_order: bool) -> Self {
+        self.opts.allow_out_of_order = allow_out_of_order;
+        self
+    }
+
+    pub fn cassandra_opts(mut self, cassandra_opts: CassandraOpts) -> Self {
+        self.opts.cassandra_opts = Some(cassandra_opts);
+        self
+    }
+
+    pub fn checksum_algorithm(mut self, checksum_algorithm: ChecksumAlgorithm) -> Self {
+        self.opts.checksum_algorithm = Some(checksum_algorithm);
+        self
+    }
+
+    pub fn cql_dir(mut self, cql_dir: PathBuf) -> Self {
+        self.opts.cql_dir = cql_dir;
+        self
+    }
+
+    pub fn history_keyspace(mut self, history_keyspace: KeyspaceOpts) -> Self {
+        self.opts.history_keyspace = Some(history_keyspace);
+        self
+    }
+
+    pub fn history_table(mut self, history_table: String) -> Self {
+        self.opts.history_table = Some(history_table);
+        self
+    }
+
+    pub fn keyspace_map(mut self, keyspace_map: KeyspaceMap) -> Self {
+        self.opts.keyspace_map = Some(keyspace_map);
+        self
+    }
+
+    pub fn profile(mut self, profile: String) -> Self {
+        self.opts.profile = Some(profile);
+        self
+    }
+
+    /// Adds a named source migrated after the sources added before it.
+    pub fn source(mut self, source: MigrationSource) -> Self {
+        self.opts.sources.push(source);
+        self
+    }
+
+    pub fn vars(mut self, vars: TemplateVars) -> Self {
+        self.opts.vars = Some(vars);
+        self
+    }
+
+    pub fn version_scheme(mut self, version_scheme: VersionScheme) -> Self {
+        self.opts.version_scheme = Some(version_scheme);
+        self
+    }
+
+    pub async fn build(self) -> Result<Migrator, MigrateError> {
+        let opts = self.opts;
+        if self.embedded.is_some() && !opts.sources.is_empty() {
+            return Err(MigrateError::EmbeddedWithSources);
+        }
+        let named_sources = !opts.sources.is_empty();
+        let sources = if named_sources {
+            source::validate_sources(&opts.sources)?;
+            opts.sources
+        } else {
+            let history_table = opts.history_table.unwrap_or_else(|| String::from(TABLE));
+            vec![MigrationSource {
+                name: history_table.clone(),
+                cql_dir: match &self.embedded {
+                    None => opts.cql_dir,
+                    Some(migrations) => migrations.cql_dir(),
+                },
+                history_table,
+            }]
+        };
+        let session = match self.session {
+            Some(session) => session,
+            None => {
+                let node_address = opts.cassandra_opts.unwrap_or_default().node_address();
+                // boxed to keep the futures of callers within the compiler's layout query depth
+                Arc::new(Box::pin(crate::cql_session(node_address)).await?)
+            }
+        };
+        Ok(Migrator {
+            allow_destructive: opts.allow_destructive,
+            allow_out_of_order: opts.allow_out_of_order,
+            checksum_algorithm: opts.checksum_algorithm.unwrap_or_default(),
+            embedded: self.embedded,
+            history_keyspace: opts
+                .history_keyspace
+                .unwrap_or_else(|| KeyspaceOpts::simple(String::from(KEYSPACE), 1)),
+            keyspace_map: opts.keyspace_map.unwrap_or_default(),
+            listener: self.listener.unwrap_or_else(|| Arc::new(())),
+            named_sources,
+            profile: opts.profile.map(|profile| profile.to_lowercase()),
+            session,
+            sources,
+            vars: opts.vars.unwrap_or_default(),
+            version_scheme: opts.version_scheme.unwrap_or_default(),
+        })
+    }
+}
+
+/// `Migrator` migrates the cql files of its sources with a session that is reused across
+/// operations. Cql files are read for every operation, and each operation errors with a
+/// [MigrateError::SourceMigration] for the source that errored when migrating named sources.
+pub struct Migrator {
+    allow_destructive: bool,
+    allow_out_of_order: bool,
+    checksum_algorithm: ChecksumAlgorithm,
+    embedded: Option<EmbeddedMigrations>,
+    history_keyspace: KeyspaceOpts,
+    keyspace_map: KeyspaceMap,
+    listener: Arc<dyn MigrationListener>,
+    named_sources: bool,
+    profile: Option<String>,
+    session: Arc<Session>,
+    sources: Vec<MigrationSource>,
+    vars: TemplateVars,
+    version_scheme: VersionScheme,
+}
+
+impl Migrator {
+    pub fn builder() -> MigratorBuilder {
+        MigratorBuilder::default()
+    }
+
+    pub fn session(&self) -> &Arc<Session> {
+        &self.session
+    }
+
+    /// `status` reports the migrated and pending cql files of each source without validating
+    /// migrated cql files against their history. History tables are read without being created
+    /// or upgraded, erroring with [MigrateError::OutdatedHistoryTable] until migrated.
+    pub async fn status(&self) -> Result<Vec<SourceStatus>, MigrateError> {
+        let mut statuses = Vec::with_capacity(self.sources.len());
+        for (source, cql_files) in self.read_sources()? {
+            let args = self.migrate_args(source);
+            let history = self
+                .read_history(&args)
+                .await
+                .map_err(|err| self.source_error(source, Vec::new(), err))?;
+            statuses.push(SourceStatus {
+                name: source.name.clone(),
+                pending: migrate::pending(&cql_files, &history, &args),
+                migrated: history,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// `plan` validates the cql files of each source against their history and plans the cql
+    /// files [Migrator::migrate] would execute, erroring as migrating would without executing cql
+    /// or altering history tables like [Migrator::status].
+    pub async fn plan(&self) -> Result<Vec<SourcePlan>, MigrateError> {
+        let mut plans = Vec::with_capacity(self.sources.len());
+        for (source, cql_files) in self.read_sources()? {
+            let args = self.migrate_args(source);
+            let planned = match self.read_history(&args).await {
+                Ok(history) => migrate::plan(&self.session, &cql_files, history, &args),
+                Err(err) => Err(err),
+            }
+            .map_err(|err| self.source_error(source, Vec::new(), err))?;
+            plans.push(SourcePlan {
+                name: source.name.clone(),
+                planned,
+            });
+        }
+        Ok(plans)
+    }
+
+    /// `migrate` migrates each source in order, creating the history keyspace and tables that do
+    /// not exist. The cql files of every source are read before migrating any source.
+    pub async fn migrate(&self) -> Result<Vec<MigratedSource>, MigrateError> {
+        let migrate_result = self.migrate_sources().await;
+        if let Err(err) = &migrate_result {
+            self.listener.errored(err);
+        }
+        migrate_result
+    }
+
+    async fn migrate_sources(&self) -> Result<Vec<MigratedSource>, MigrateError> {
+        let sources = self.read_sources()?;
+        let mut migrated: Vec<MigratedSource> = Vec::with_capacity(sources.len());
+        for (source, cql_files) in sources {
+            self.listener.source_started(&source.name);
+            if let Err(err) = crate::prepare_cquill_keyspace(
+                &self.session,
+                &self.history_keyspace,
+                &source.history_table,
+            )
+            .await
+            {
+                return Err(self.source_error(source, migrated, err.into()));
+            }
+            match migrate::perform(&self.session, &cql_files, self.migrate_args(source)).await {
+                Ok(migrated_cql) => {
+                    let migrated_source = MigratedSource {
+                        name: source.name.clone(),
+                        migrated: migrated_cql,
+                    };
+                    self.listener.source_finished(&migrated_source);
+                    migrated.push(migrated_source);
+                }
+                Err(err) => return Err(self.source_error(source, migrated, err)),
+            }
+        }
+        Ok(migrated)
+    }
+
+    /// `verify` replays the cql files of every source in order like [verify_cql](crate::verify_cql)
+    /// without executing cql.
+    pub fn verify(&self) -> Result<VerifiedCql, VerifyError> {
+        let mut cql_files = Vec::new();
+        for source in &self.sources {
+            cql_files.append(&mut self.read_source(source)?);
+        }
+        verify::verify_cql_files(cql_files)
+    }
+
+    fn read_source(&self, source: &MigrationSource) -> anyhow::Result<Vec<CqlFile>> {
+        let cql_files = match &self.embedded {
+            None => cql_file::files_from_dir(&source.cql_dir)?,
+            Some(migrations) => migrations.cql_files()?,
+        };
+        self.version_scheme.validate(&cql_files)?;
+        Ok(cql_files)
+    }
+
+    fn read_sources(&self) -> Result<Vec<(&MigrationSource, Vec<CqlFile>)>, MigrateError> {
+        self.sources
+            .iter()
+            .map(|source| match self.read_source(source) {
+                Ok(cql_files) => Ok((source, cql_files)),
+                Err(err) => Err(self.source_error(source, Vec::new(), err.into())),
+            })
+            .collect()
+    }
+
+    /// Reads the history of a source without altering the history keyspace, which is empty when
+    /// its history table does not exist and errors when created by an earlier version that only
+    /// [Migrator::migrate] upgrades.
+    async fn read_history(&self, args: &MigrateArgs) -> Result<Vec<CqlFile>, MigrateError> {
+        let history_exists = get_keyspace_table_names(&self.session, &args.history_keyspace)
+            .is_ok_and(|table_names| table_names.contains(&args.history_table));
+        if !history_exists {
+            return Ok(Vec::new());
+        }
+        if !crate::missing_history_columns(
+            &self.session,
+            &args.history_keyspace,
+            &args.history_table,
+        )?
+        .is_empty()
+        {
+            return Err(MigrateError::OutdatedHistoryTable {
+                cquill_keyspace: args.history_keyspace.clone(),
+                cquill_table: args.history_table.clone(),
+            });
+        }
+        migrate::select_history(&self.session, args).await
+    }
+
+    fn migrate_args(&self, source: &MigrationSource) -> MigrateArgs {
+        MigrateArgs {
+            allow_destructive: self.allow_destructive,
+            allow_out_of_order: self.allow_out_of_order,
+            checksum_algorithm: self.checksum_algorithm,
+            cql_dir: source.cql_dir.clone(),
+            history_keyspace: self.history_keyspace.name.clone(),
+            history_table: source.history_table.clone(),
+            keyspace_map: self.keyspace_map.clone(),
+            listener: self.listener.clone(),
+            profile: self.profile.clone(),
+            vars: self.vars.clone(),
+        }
+    }
+
+    fn source_error(
+        &self,
+        source: &MigrationSource,
+        migrated: Vec<MigratedSource>,
+        error: MigrateError,
+    ) -> MigrateError {
+        if self.named_sources {
+            MigrateError::SourceMigration {
+                name: source.name.clone(),
+                migrated,
+                error: Box::new(error),
+            }
+        } else {
+            error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use crate::cql_file::CqlStatement;
+    use crate::test_utils;
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct RecordingListener {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MigrationListener for RecordingListener {
+        fn source_started(&self, name: &str) {
+            self.events.lock().unwrap().push(format!("source {name}"));
+        }
+
+        fn source_finished(&self, source: &MigratedSource) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("migrated {}", source.migrated.len()));
+        }
+
+        fn file_started(&self, cql_file: &CqlFile) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("start {cql_file}"));
+        }
+
+        fn file_finished(&self, cql_file: &CqlFile, _duration: Duration) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("finish {cql_file}"));
+        }
+
+        fn statement_started(&self, _cql_file: &CqlFile, statement: &CqlStatement) {
+            self.events.lock().unwrap().push(format!(
+                "exec lines {}-{}",
+                statement.lines.0, statement.lines.1
+            ));
+        }
+
+        fn statement_finished(
+            &self,
+            _cql_file: &CqlFile,
+            statement: &CqlStatement,
+            _duration: Duration,
+        ) {
+            self.events.lock().unwrap().push(format!(
+                "executed lines {}-{}",
+                statement.lines.0, statement.lines.1
+            ));
+        }
+
+        fn history_inserted(&self, cql_file: &CqlFile) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("recorded {cql_file}"));
+        }
+
+        fn errored(&self, _error: &MigrateError) {
+            self.events.lock().unwrap().push(String::from("errored"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_migrator_reports_progress_to_listener() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file(
+                "v001.cql",
+                "create table accounts (id uuid primary key);\n\nalter table accounts\n    add name text;",
+            )
+            .cql_file("v002.cql", "alter table not_accounts add name text;")
+            .initialize()
+            .await;
+        let session = test_utils::cql_session().await;
+        session
+            .use_keyspace(&harness.cquill_keyspace, false)
+            .await
+            .expect("use keyspace");
+        let listener = RecordingListener::default();
+        let migrator = Migrator::builder()
+            .session(Arc::new(session))
+            .listener(listener.clone())
+            .cql_dir(harness.cql_dir.clone())
+            .history_keyspace(KeyspaceOpts::simple(harness.cquill_keyspace.clone(), 1))
+            .history_table(harness.cquill_table.clone())
+            .build()
+            .await
+            .expect("build migrator");
+
+        assert!(migrator.migrate().await.is_err());
+        assert_eq!(
+            *listener.events.lock().unwrap(),
+            vec![
+                format!("source {}", harness.cquill_table),
+                String::from("start v001.cql"),
+                String::from("exec lines 1-1"),
+                String::from("executed lines 1-1"),
+                String::from("exec lines 3-4"),
+                String::from("executed lines 3-4"),
+                String::from("recorded v001.cql"),
+                String::from("finish v001.cql"),
+                String::from("start v002.cql"),
+                String::from("exec lines 1-1"),
+                String::from("recorded v002.cql"),
+                String::from("errored"),
+            ]
+        );
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrator_with_session() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "create table accounts (id uuid primary key);")
+            .cql_file("v002.cql", "alter table accounts add name text;")
+            .initialize()
+            .await;
+        let session = test_utils::cql_session().await;
+        session
+            .use_keyspace(&harness.cquill_keyspace, false)
+            .await
+            .expect("use keyspace");
+        let migrator = Migrator::builder()
+            .session(session)
+            .cql_dir(harness.cql_dir.clone())
+            .history_keyspace(KeyspaceOpts::simple(harness.cquill_keyspace.clone(), 1))
+            .history_table(harness.cquill_table.clone())
+            .build()
+            .await
+            .expect("build migrator");
+
+        let status = migrator.status().await.expect("status");
+        assert!(status[0].migrated.is_empty());
+        assert_eq!(status[0].pending.len(), 2);
+        let plan = migrator.plan().await.expect("plan");
+        assert_eq!(
+            plan[0]
+                .planned
+                .iter()
+                .map(|planned| planned.statements[0].cql.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "create table accounts (id uuid primary key)",
+                "alter table accounts add name text"
+            ]
+        );
+        let verified = migrator.verify().expect("verify");
+        assert_eq!(verified.cql_files.len(), 2);
+
+        let migrated = migrator.migrate().await.expect("migrate");
+        assert_eq!(migrated[0].migrated.len(), 2);
+        let status = migrator.status().await.expect("status");
+        assert_eq!(status[0].migrated.len(), 2);
+        assert!(status[0].pending.is_empty());
+        assert!(migrator.plan().await.expect("plan")[0].planned.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrator_status_and_plan_error_without_upgrading_history_table() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001.cql", "create table accounts (id uuid primary key);")
+            .initialize()
+            .await;
+        let session = test_utils::cql_session().await;
+        let history_table = String::from("migrated_v1");
+        crate::queries::exec(
+            &session,
+            format!(
+                "create table {}.{history_table} (id timeuuid primary key, ver smallint, name varchar, hash varchar)",
+                harness.cquill_keyspace
+            ),
+        )
+        .await
+        .expect("create v1 history table");
+        session.refresh_metadata().await.expect("refresh metadata");
+        let migrator = Migrator::builder()
+            .session(session)
+            .cql_dir(harness.cql_dir.clone())
+            .history_keyspace(KeyspaceOpts::simple(harness.cquill_keyspace.clone(), 1))
+            .history_table(history_table.clone())
+            .build()
+            .await
+            .expect("build migrator");
+
+        assert!(matches!(
+            migrator.status().await,
+            Err(MigrateError::OutdatedHistoryTable { .. })
+        ));
+        assert!(matches!(
+            migrator.plan().await,
+            Err(MigrateError::OutdatedHistoryTable { .. })
+        ));
+        let column_names = crate::keyspace::get_table_column_names(
+            migrator.session(),
+            &harness.cquill_keyspace,
+            &history_table,
+        )
+        .unwrap();
+        assert_eq!(column_names.len(), 4);
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_migrator_errors_with_embedded_migrations_and_sources() {
+        let temp_dir = temp_dir::TempDir::new().unwrap();
+        let build_result = Migrator::builder()
+            .embedded(crate::embed_migrations!("examples/cql"))
+            .source(MigrationSource::new("accounts", temp_dir.path().to_path_buf()).unwrap())
+            .build()
+            .await;
+        assert!(matches!(
+            build_result,
+            Err(MigrateError::EmbeddedWithSources)
+        ));
+    }
+}
@@ -1,20 +1,71 @@
+use std::collections::HashMap;
 use std::path::Path;
+use std::time::Duration;
 
 use scylla::client::session::Session;
 use scylla::value::CqlTimeuuid;
 
-use crate::cql_file::CqlFile;
+use crate::checksum::ChecksumAlgorithm;
+use crate::cql_file::{CqlFile, CqlFileKind};
+use crate::keyspace::KeyspaceMap;
 use crate::queries::QueryError;
 
+/// The outcome of executing a cql file's statements, recorded with the cql file in the history
+/// table.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Execution {
+    pub duration: Duration,
+    /// Statements executed successfully, which for a failed execution excludes the failed statement.
+    pub statement_count: usize,
+    pub status: ExecutionStatus,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) enum ExecutionStatus {
+    #[default]
+    Success,
+    Failure,
+}
+
+impl ExecutionStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ExecutionStatus::Success => "success",
+            ExecutionStatus::Failure => "failure",
+        }
+    }
+}
+
 pub(crate) async fn insert(
     session: &Session,
     keyspace: &String,
     table: &String,
+    cql_dir: &Path,
     cql_file: &CqlFile,
+    keyspace_map: &KeyspaceMap,
+    execution: &Execution,
 ) -> Result<(), QueryError> {
-    let cql =
-        format!("insert into {keyspace}.{table} (id, ver, name, hash) values (now(), ?, ?, ?)");
-    let values = (&cql_file.version, &cql_file.filename, &cql_file.hash);
+    let cql = format!(
+        "insert into {keyspace}.{table} (id, ver, version, name, hash, keyspace_map, kind, profile, duration_ms, statement_count, client_host, client_user, cquill_version, checksum_algorithm, status, path) values (now(), ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    );
+    let values = (
+        // smallint versions are still recorded for earlier versions of cquill
+        i16::try_from(cql_file.version).ok(),
+        &cql_file.version,
+        &cql_file.filename,
+        &cql_file.hash,
+        keyspace_map.as_map(),
+        cql_file.kind.as_str(),
+        &cql_file.profile,
+        i64::try_from(execution.duration.as_millis()).unwrap_or(i64::MAX),
+        i32::try_from(execution.statement_count).unwrap_or(i32::MAX),
+        client_host(),
+        client_user(),
+        env!("CARGO_PKG_VERSION"),
+        cql_file.checksum_algorithm.as_str(),
+        execution.status.as_str(),
+        cql_file.relative_path(cql_dir),
+    );
     session
         .query_unpaged(cql, values)
         .await
@@ -22,38 +73,134 @@ pub(crate) async fn insert(
     Ok(())
 }
 
+/// Hostname of the machine running cquill.
+fn client_host() -> Option<String> {
+    std::env::var("HOSTNAME")
+        .ok()
+        .or_else(|| std::env::var("COMPUTERNAME").ok())
+        .or_else(|| {
+            std::fs::read_to_string("/etc/hostname")
+                .ok()
+                .map(|hostname| hostname.trim().to_string())
+        })
+        .filter(|hostname| !hostname.is_empty())
+}
+
+/// Name of the OS user running cquill.
+fn client_user() -> Option<String> {
+    std::env::var("USER")
+        .ok()
+        .or_else(|| std::env::var("USERNAME").ok())
+        .filter(|user| !user.is_empty())
+}
+
+/// Selects the migrated versioned cql files in version order followed by the most recently migrated
+/// hash of each repeatable cql file in filename order. Failed executions are not selected.
 pub(crate) async fn select_all(
     session: &Session,
     keyspace: &String,
     table: &String,
     cql_dir: &Path,
+    keyspace_map: &KeyspaceMap,
 ) -> Result<Vec<CqlFile>, QueryError> {
-    let cql = format!("select id, name, hash, ver from {keyspace}.{table}");
+    let cql = format!(
+        "select id, name, hash, ver, keyspace_map, kind, profile, status, checksum_algorithm, version, path from {keyspace}.{table}"
+    );
     let query_result = session
         .query_unpaged(cql, ())
         .await
         .map_err(|err| QueryError::Execution(err.to_string()))?;
     let mut result = Vec::new();
+    let mut repeatable: HashMap<String, (CqlTimeuuid, CqlFile)> = HashMap::new();
     let rows_result = query_result
         .into_rows_result()
         .map_err(|err| QueryError::Deserialize(err.to_string()))?;
     for row_result in rows_result
-        .rows::<(CqlTimeuuid, String, String, i16)>()
+        .rows::<(
+            CqlTimeuuid,
+            String,
+            String,
+            Option<i16>,
+            Option<HashMap<String, String>>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<String>,
+            Option<i64>,
+            Option<String>,
+        )>()
         .map_err(|err| QueryError::Deserialize(err.to_string()))?
     {
         let row_values = row_result.unwrap();
+        // files migrated with a different keyspace map belong to a separate deploy
+        if KeyspaceMap::from(row_values.4.unwrap_or_default()) != *keyspace_map {
+            continue;
+        }
+        // rows recorded before execution status was recorded are of successful executions
+        if row_values.7.as_deref() == Some(ExecutionStatus::Failure.as_str()) {
+            continue;
+        }
         let filename = row_values.1;
         let hash = row_values.2;
-        let path = cql_dir.join(&filename);
-        let version = row_values.3;
-        result.push(CqlFile {
+        // rows recorded before subdirectories were supported are of files in the cql dir
+        let path = match &row_values.10 {
+            None => cql_dir.join(&filename),
+            Some(relative_path) => relative_path
+                .split('/')
+                .fold(cql_dir.to_path_buf(), |path, component| {
+                    path.join(component)
+                }),
+        };
+        // rows recorded before versions were widened to bigint only have a smallint version
+        let version = row_values
+            .9
+            .or(row_values.3.map(i64::from))
+            .unwrap_or_default();
+        // rows recorded before repeatable cql files were supported do not have a kind
+        let kind = match row_values.5 {
+            None => CqlFileKind::Versioned,
+            Some(kind) => kind
+                .parse()
+                .map_err(|err: anyhow::Error| QueryError::Deserialize(err.to_string()))?,
+        };
+        // rows recorded before checksum algorithms were recorded are hashed with md5
+        let checksum_algorithm = match row_values.8 {
+            None => ChecksumAlgorithm::Md5,
+            Some(checksum_algorithm) => checksum_algorithm
+                .parse()
+                .map_err(|err: anyhow::Error| QueryError::Deserialize(err.to_string()))?,
+        };
+        let cql_file = CqlFile {
+            checksum_algorithm,
+            embedded_cql: None,
             filename,
             hash,
+            kind,
+            out_of_order: false,
             path,
+            profile: row_values.6,
             version,
-        })
+        };
+        match kind {
+            CqlFileKind::Versioned => result.push(cql_file),
+            CqlFileKind::Repeatable => {
+                let id = row_values.0;
+                match repeatable.get(&cql_file.filename) {
+                    Some((latest_id, _)) if *latest_id > id => {}
+                    _ => {
+                        repeatable.insert(cql_file.filename.clone(), (id, cql_file));
+                    }
+                }
+            }
+        }
     }
-    result.sort_by(|a, b| a.version.cmp(&b.version));
+    result.sort_by_key(|cql_file| cql_file.version);
+    let mut repeatable: Vec<CqlFile> = repeatable
+        .into_values()
+        .map(|(_, cql_file)| cql_file)
+        .collect();
+    repeatable.sort_by(|a, b| a.filename.cmp(&b.filename));
+    result.append(&mut repeatable);
     Ok(result)
 }
 
@@ -69,9 +216,14 @@ mod tests {
     async fn test_insert() {
         let harness = test_utils::TestHarness::builder().initialize().await;
         let cql_file = CqlFile {
+            checksum_algorithm: ChecksumAlgorithm::Md5,
+            embedded_cql: None,
             filename: "v073-more_tables.cql".to_string(),
             hash: "7f5b4bdccd3863f31be5c257ff497704".to_string(),
+            kind: CqlFileKind::Versioned,
+            out_of_order: false,
             path: PathBuf::from("v073-more_tables.cql"),
+            profile: None,
             version: 73,
         };
 
@@ -79,7 +231,10 @@ mod tests {
             &harness.session,
             &harness.cquill_keyspace,
             &harness.cquill_table,
+            &harness.cql_dir,
             &cql_file,
+            &KeyspaceMap::default(),
+            &Execution::default(),
         )
         .await
         .unwrap();
@@ -127,6 +282,7 @@ mod tests {
             &harness.cquill_keyspace,
             &harness.cquill_table,
             harness.cql_dir.as_path(),
+            &KeyspaceMap::default(),
         )
         .await
         .expect("select all migrated cql files");
@@ -149,7 +305,10 @@ mod tests {
                 &harness.session,
                 &harness.cquill_keyspace,
                 &harness.cquill_table,
+                &harness.cql_dir,
                 cql_file,
+                &KeyspaceMap::default(),
+                &Execution::default(),
             )
             .await
             .expect("save migrated cql file history");
@@ -160,6 +319,7 @@ mod tests {
             &harness.cquill_keyspace,
             &harness.cquill_table,
             harness.cql_dir.as_path(),
+            &KeyspaceMap::default(),
         )
         .await
         .expect("select all migrated cql files");
@@ -182,4 +342,149 @@ mod tests {
 
         harness.drop_keyspace().await;
     }
+
+    #[tokio::test]
+    async fn test_select_all_filters_by_keyspace_map() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001-more_cql.cql", "abc")
+            .initialize()
+            .await;
+        let keyspace_map = "api=api_pr123".parse::<KeyspaceMap>().unwrap();
+        insert(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            &harness.cql_dir,
+            harness.cql_files.first().unwrap(),
+            &keyspace_map,
+            &Execution::default(),
+        )
+        .await
+        .expect("save migrated cql file history");
+
+        for (keyspace_map, expected_len) in [(KeyspaceMap::default(), 0), (keyspace_map, 1)] {
+            let migrated_cql_files = select_all(
+                &harness.session,
+                &harness.cquill_keyspace,
+                &harness.cquill_table,
+                harness.cql_dir.as_path(),
+                &keyspace_map,
+            )
+            .await
+            .expect("select all migrated cql files");
+            assert_eq!(migrated_cql_files.len(), expected_len);
+        }
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_select_all_returns_latest_repeatable_hash() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001-more_cql.cql", "abc")
+            .cql_file("R__views.cql", "def")
+            .initialize()
+            .await;
+        let repeatable = harness.cql_files.get(1).unwrap();
+        for hash in ["outdated", repeatable.hash.as_str()] {
+            insert(
+                &harness.session,
+                &harness.cquill_keyspace,
+                &harness.cquill_table,
+                &harness.cql_dir,
+                &CqlFile {
+                    hash: hash.to_string(),
+                    ..repeatable.clone()
+                },
+                &KeyspaceMap::default(),
+                &Execution::default(),
+            )
+            .await
+            .expect("save migrated cql file history");
+        }
+
+        let migrated_cql_files = select_all(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            harness.cql_dir.as_path(),
+            &KeyspaceMap::default(),
+        )
+        .await
+        .expect("select all migrated cql files");
+        assert_eq!(migrated_cql_files.len(), 1);
+        assert_eq!(migrated_cql_files[0].filename, "R__views.cql");
+        assert_eq!(migrated_cql_files[0].kind, CqlFileKind::Repeatable);
+        assert_eq!(migrated_cql_files[0].hash, repeatable.hash);
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_select_all_skips_failed_executions() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("v001-more_cql.cql", "abc")
+            .initialize()
+            .await;
+        insert(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            &harness.cql_dir,
+            harness.cql_files.first().unwrap(),
+            &KeyspaceMap::default(),
+            &Execution {
+                status: ExecutionStatus::Failure,
+                ..Execution::default()
+            },
+        )
+        .await
+        .expect("save failed cql file history");
+
+        let migrated_cql_files = select_all(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            harness.cql_dir.as_path(),
+            &KeyspaceMap::default(),
+        )
+        .await
+        .expect("select all migrated cql files");
+        assert!(migrated_cql_files.is_empty());
+
+        harness.drop_keyspace().await;
+    }
+
+    #[tokio::test]
+    async fn test_select_all_returns_bigint_versions() {
+        let harness = test_utils::TestHarness::builder()
+            .cql_file("V20261018153000__accounts.cql", "abc")
+            .initialize()
+            .await;
+        insert(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            &harness.cql_dir,
+            harness.cql_files.first().unwrap(),
+            &KeyspaceMap::default(),
+            &Execution::default(),
+        )
+        .await
+        .expect("save migrated cql file history");
+
+        let migrated_cql_files = select_all(
+            &harness.session,
+            &harness.cquill_keyspace,
+            &harness.cquill_table,
+            harness.cql_dir.as_path(),
+            &KeyspaceMap::default(),
+        )
+        .await
+        .expect("select all migrated cql files");
+        assert_eq!(migrated_cql_files.len(), 1);
+        assert_eq!(migrated_cql_files[0].version, 20261018153000);
+
+        harness.drop_keyspace().await;
+    }
 }
@@ -1,12 +1,37 @@
 use super::*;
 
+/// Columns added to the history table after its first release with the cql files they record:
+/// the [crate::keyspace::KeyspaceMap] a file was migrated with, the file's
+/// [crate::cql_file::CqlFileKind], the migrate profile of a profile-specific file, the details
+/// of a file's execution, a version wider than the `ver` smallint and the file's path relative to
+/// its cql dir. History tables created by earlier versions of cquill are upgraded in
+/// place by adding the columns they are missing.
+pub(crate) const ADDED_COLUMNS: [(&str, &str); 12] = [
+    ("keyspace_map", "map<varchar, varchar>"),
+    ("kind", "varchar"),
+    ("profile", "varchar"),
+    ("duration_ms", "bigint"),
+    ("statement_count", "int"),
+    ("client_host", "varchar"),
+    ("client_user", "varchar"),
+    ("cquill_version", "varchar"),
+    ("checksum_algorithm", "varchar"),
+    ("status", "varchar"),
+    ("version", "bigint"),
+    ("path", "varchar"),
+];
+
 pub(crate) async fn create(
     session: &Session,
     keyspace_name: &String,
     table_name: &String,
 ) -> Result<(), QueryError> {
+    let added_columns = ADDED_COLUMNS
+        .iter()
+        .map(|(column_name, data_type)| format!(", {column_name} {data_type}"))
+        .collect::<String>();
     let cql = format!(
-        "create table {keyspace_name}.{table_name} (id timeuuid primary key, ver smallint, name varchar, hash varchar)"
+        "create table {keyspace_name}.{table_name} (id timeuuid primary key, ver smallint, name varchar, hash varchar{added_columns})"
     );
     session
         .query_unpaged(cql, ())
@@ -15,6 +40,21 @@ pub(crate) async fn create(
     Ok(())
 }
 
+/// Adds one of the [ADDED_COLUMNS] to a history table created by an earlier version of cquill.
+pub(crate) async fn add_column(
+    session: &Session,
+    keyspace_name: &String,
+    table_name: &String,
+    (column_name, data_type): (&str, &str),
+) -> Result<(), QueryError> {
+    let cql = format!("alter table {keyspace_name}.{table_name} add {column_name} {data_type}");
+    session
+        .query_unpaged(cql, ())
+        .await
+        .map_err(|err| QueryError::Execution(err.to_string()))?;
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub(crate) async fn drop(
     session: &Session,
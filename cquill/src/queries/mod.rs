@@ -4,6 +4,7 @@ use crate::keyspace::KeyspaceOpts;
 
 pub(crate) mod keyspace;
 pub(crate) mod migrated;
+pub(crate) mod system_schema;
 
 #[derive(thiserror::Error, Debug)]
 pub enum QueryError {
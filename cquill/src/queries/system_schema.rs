@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use scylla::client::session::Session;
+
+use crate::queries::QueryError;
+
+/// An index as `(table_name, index_name, target)` where target is the indexed column.
+pub(crate) async fn select_indexes(
+    session: &Session,
+    keyspace: &String,
+) -> Result<Vec<(String, String, String)>, QueryError> {
+    let cql =
+        "select table_name, index_name, options from system_schema.indexes where keyspace_name = ?";
+    let query_result = session
+        .query_unpaged(cql, (keyspace,))
+        .await
+        .map_err(|err| QueryError::Execution(err.to_string()))?;
+    let rows_result = query_result
+        .into_rows_result()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?;
+    let mut result = Vec::new();
+    for row_result in rows_result
+        .rows::<(String, String, Option<HashMap<String, String>>)>()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?
+    {
+        let (table_name, index_name, options) =
+            row_result.map_err(|err| QueryError::Deserialize(err.to_string()))?;
+        let target = options
+            .and_then(|mut options| options.remove("target"))
+            .unwrap_or_default();
+        result.push((table_name, index_name, target));
+    }
+    Ok(result)
+}
+
+/// A clustering column of a table or view as `(table_name, column_name, clustering_order)` where
+/// clustering order is `asc` or `desc`.
+pub(crate) async fn select_clustering_orders(
+    session: &Session,
+    keyspace: &String,
+) -> Result<Vec<(String, String, String)>, QueryError> {
+    let cql = "select table_name, column_name, kind, clustering_order from system_schema.columns where keyspace_name = ?";
+    let query_result = session
+        .query_unpaged(cql, (keyspace,))
+        .await
+        .map_err(|err| QueryError::Execution(err.to_string()))?;
+    let rows_result = query_result
+        .into_rows_result()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?;
+    let mut result = Vec::new();
+    for row_result in rows_result
+        .rows::<(String, String, String, String)>()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?
+    {
+        let (table_name, column_name, kind, clustering_order) =
+            row_result.map_err(|err| QueryError::Deserialize(err.to_string()))?;
+        if kind == "clustering" {
+            result.push((table_name, column_name, clustering_order));
+        }
+    }
+    Ok(result)
+}
+
+/// A materialized view as `(view_name, where_clause)`.
+pub(crate) async fn select_view_where_clauses(
+    session: &Session,
+    keyspace: &String,
+) -> Result<Vec<(String, String)>, QueryError> {
+    let cql = "select view_name, where_clause from system_schema.views where keyspace_name = ?";
+    let query_result = session
+        .query_unpaged(cql, (keyspace,))
+        .await
+        .map_err(|err| QueryError::Execution(err.to_string()))?;
+    let rows_result = query_result
+        .into_rows_result()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?;
+    let mut result = Vec::new();
+    for row_result in rows_result
+        .rows::<(String, String)>()
+        .map_err(|err| QueryError::Deserialize(err.to_string()))?
+    {
+        result.push(row_result.map_err(|err| QueryError::Deserialize(err.to_string()))?);
+    }
+    Ok(result)
+}
@@ -0,0 +1,92 @@
+use cquill_ast::ast::TokenView;
+use cquill_ast::parse_cql;
+use cquill_ast::visit_mut::VisitMut;
+
+use crate::keyspace::KeyspaceMap;
+
+struct KeyspaceRewriter<'a> {
+    keyspace_map: &'a KeyspaceMap,
+}
+
+impl VisitMut for KeyspaceRewriter<'_> {
+    fn visit_keyspace_name_mut(&mut self, node: &mut TokenView) {
+        if let Some(mapped_name) = self.keyspace_map.get(node.value().as_str()) {
+            *node = TokenView::from_value(mapped_name);
+        }
+    }
+}
+
+/// Renders `cql` with every keyspace reference renamed by `keyspace_map`. Returns None if the
+/// CQL cannot be parsed, because its keyspace references cannot be found.
+pub(crate) fn rewrite_keyspace_names(cql: &str, keyspace_map: &KeyspaceMap) -> Option<String> {
+    let mut statements = parse_cql(cql.to_string()).ok()?;
+    let mut rewriter = KeyspaceRewriter { keyspace_map };
+    for statement in statements.iter_mut() {
+        rewriter.visit_statement_mut(statement);
+    }
+    Some(
+        statements
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<String>>()
+            .join(";\n"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(cql: &str) -> Option<String> {
+        rewrite_keyspace_names(cql, &"api=api_pr123".parse::<KeyspaceMap>().unwrap())
+    }
+
+    #[test]
+    fn test_rewrite_create_keyspace() {
+        assert_eq!(
+            rewrite(
+                "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1}"
+            )
+            .unwrap(),
+            "CREATE KEYSPACE api_pr123 WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1}"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_use() {
+        assert_eq!(rewrite("USE API").unwrap(), "USE api_pr123");
+    }
+
+    #[test]
+    fn test_rewrite_qualified_names() {
+        assert_eq!(
+            rewrite("insert into api.accounts (id, name) values (api.next_id(), 'big data!')")
+                .unwrap(),
+            "INSERT INTO api_pr123.accounts (id, name) VALUES (api_pr123.next_id(), 'big data!')"
+        );
+        assert_eq!(
+            rewrite(
+                "create table api.accounts (id uuid primary key, profile frozen<account_profile>)"
+            )
+            .unwrap(),
+            "CREATE TABLE api_pr123.accounts (\n    id uuid PRIMARY KEY,\n    profile frozen<account_profile>\n)"
+        );
+        assert_eq!(
+            rewrite("alter table api.accounts add email text").unwrap(),
+            "ALTER TABLE api_pr123.accounts ADD email text"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_ignores_unmapped_keyspaces() {
+        assert_eq!(
+            rewrite("drop table billing.invoices").unwrap(),
+            "DROP TABLE billing.invoices"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_unsupported_cql() {
+        assert_eq!(rewrite("grant select on keyspace api to api_role"), None);
+    }
+}
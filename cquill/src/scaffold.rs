@@ -0,0 +1,194 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::{Result, anyhow};
+
+use crate::cql_file::{self, CqlFile, CqlFileKind, DOWN_FILE_SUFFIX, VersionScheme};
+
+pub struct NewOpts {
+    pub cql_dir: PathBuf,
+    /// Describes the new cql file's changes for its filename and header comment.
+    pub description: String,
+    /// Also creates a `.down.cql` file for the cql reverting the new cql file's changes.
+    pub down: bool,
+    /// Names the new cql file with the next version of this scheme.
+    pub version_scheme: VersionScheme,
+}
+
+/// `new_cql_file` creates the next versioned cql file in [NewOpts::cql_dir] with a header comment
+/// of [NewOpts::description], erroring if the existing cql files skip or repeat a version. Only
+/// sequential versions are checked for skipped versions. A successful method result contains the
+/// paths of the created files.
+pub fn new_cql_file(opts: NewOpts) -> Result<Vec<PathBuf>> {
+    let cql_files = if cql_file::is_dir_without_cql_files(&opts.cql_dir) {
+        Vec::new()
+    } else {
+        cql_file::files_from_dir(&opts.cql_dir)?
+    };
+    let filename = cql_file::next_filename(&cql_files, &opts.description, opts.version_scheme)?;
+    if opts.version_scheme == VersionScheme::Sequential {
+        validate_no_version_gaps(&cql_files)?;
+    }
+    let mut created = vec![create_file(
+        opts.cql_dir.join(&filename),
+        format!("-- {}\n\n", opts.description.trim()),
+    )?];
+    if opts.down {
+        let down_filename = filename.replace(".cql", DOWN_FILE_SUFFIX);
+        created.push(create_file(
+            opts.cql_dir.join(down_filename),
+            format!("-- Reverts {filename}\n\n"),
+        )?);
+    }
+    Ok(created)
+}
+
+/// Errors with the first versioned cql file that skips a version, since a new cql file should not be
+/// versioned after a gap that a file merged from another branch would fill out of order.
+fn validate_no_version_gaps(cql_files: &[CqlFile]) -> Result<()> {
+    let versioned = cql_files
+        .iter()
+        .filter(|cql_file| cql_file.kind == CqlFileKind::Versioned && cql_file.profile.is_none());
+    for (expected_version, cql_file) in (1..).zip(versioned) {
+        if cql_file.version != expected_version {
+            return Err(anyhow!(
+                "{} found without a preceding v{:0>3} version cql file",
+                cql_file.filename,
+                expected_version
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn create_file(path: PathBuf, content: String) -> Result<PathBuf> {
+    let filename = path.file_name().unwrap().to_string_lossy().to_string();
+    let mut file = match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+    {
+        Err(err) => return Err(anyhow!("failed creating file {filename}: {err}")),
+        Ok(file) => file,
+    };
+    if let Err(err) = file.write_all(content.as_bytes()) {
+        return Err(anyhow!("failed writing file {filename}: {err}"));
+    }
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::test_utils::make_file;
+
+    use super::*;
+
+    fn filenames(paths: &[PathBuf]) -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_new_cql_file_with_next_version() {
+        let temp_dir = TempDir::new().unwrap();
+        make_file(temp_dir.path().join("v001-init.cql"), "");
+        make_file(temp_dir.path().join("v002-accounts.cql"), "");
+
+        let created = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "Add account emails".to_string(),
+            down: false,
+            version_scheme: VersionScheme::Sequential,
+        })
+        .unwrap();
+        assert_eq!(filenames(&created), vec!["v003-add-account-emails.cql"]);
+        assert_eq!(
+            fs::read_to_string(&created[0]).unwrap(),
+            "-- Add account emails\n\n"
+        );
+    }
+
+    #[test]
+    fn test_new_cql_file_with_down_file() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let created = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "init schema".to_string(),
+            down: true,
+            version_scheme: VersionScheme::Sequential,
+        })
+        .unwrap();
+        assert_eq!(
+            filenames(&created),
+            vec!["v001-init-schema.cql", "v001-init-schema.down.cql"]
+        );
+        assert_eq!(
+            fs::read_to_string(&created[1]).unwrap(),
+            "-- Reverts v001-init-schema.cql\n\n"
+        );
+
+        let created = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "accounts".to_string(),
+            down: false,
+            version_scheme: VersionScheme::Sequential,
+        })
+        .unwrap();
+        assert_eq!(filenames(&created), vec!["v002-accounts.cql"]);
+    }
+
+    #[test]
+    fn test_new_cql_file_errors_with_version_gap() {
+        let temp_dir = TempDir::new().unwrap();
+        make_file(temp_dir.path().join("v001-init.cql"), "");
+        make_file(temp_dir.path().join("v003-accounts.cql"), "");
+
+        let result = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "emails".to_string(),
+            down: false,
+            version_scheme: VersionScheme::Sequential,
+        });
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "v003-accounts.cql found without a preceding v002 version cql file"
+        );
+    }
+
+    #[test]
+    fn test_new_cql_file_with_timestamp_version() {
+        let temp_dir = TempDir::new().unwrap();
+        make_file(temp_dir.path().join("V20240101000000__init.cql"), "");
+
+        let created = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "accounts".to_string(),
+            down: false,
+            version_scheme: VersionScheme::Timestamp,
+        })
+        .unwrap();
+        let filename = &filenames(&created)[0];
+        assert!(
+            regex::Regex::new(r"^v20\d{12}-accounts\.cql$")
+                .unwrap()
+                .is_match(filename)
+        );
+
+        let result = new_cql_file(NewOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+            description: "emails".to_string(),
+            down: false,
+            version_scheme: VersionScheme::Sequential,
+        });
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "V20240101000000__init.cql is not named with a sequential version such as v001"
+        );
+    }
+}
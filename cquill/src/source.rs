@@ -0,0 +1,166 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::TABLE;
+use crate::cql_file::CqlFile;
+use crate::migrate::PlannedCql;
+
+lazy_static! {
+    static ref SOURCE_NAME_REGEX: Regex =
+        regex::Regex::new(r"^[a-zA-Z][_\da-zA-Z]*$").expect("migration source name regex");
+}
+
+/// MigrationSource is a named directory of cql files with its own version sequence, recorded in
+/// its own history table, such as the cql files of a keyspace released independently of a
+/// service's other keyspaces.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationSource {
+    pub name: String,
+    pub cql_dir: PathBuf,
+    pub history_table: String,
+}
+
+impl MigrationSource {
+    /// A source recorded in a history table named after the source, such as
+    /// `migrated_cql_accounts` for the `accounts` source.
+    pub fn new(name: &str, cql_dir: PathBuf) -> Result<Self> {
+        if !SOURCE_NAME_REGEX.is_match(name) {
+            return Err(anyhow!("{name} is not a valid migration source name"));
+        }
+        Ok(MigrationSource {
+            name: name.to_string(),
+            cql_dir,
+            history_table: format!("{TABLE}_{}", name.to_lowercase()),
+        })
+    }
+}
+
+/// Parses a `NAME=CQL_DIR` source or a `NAME=CQL_DIR,HISTORY_TABLE` source with a history table.
+impl FromStr for MigrationSource {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((name, location)) = s.split_once('=') else {
+            return Err(anyhow!(
+                "migration source {s} is not a NAME=CQL_DIR or NAME=CQL_DIR,HISTORY_TABLE pair"
+            ));
+        };
+        let (cql_dir, history_table) = match location.rsplit_once(',') {
+            None => (location, None),
+            Some((cql_dir, history_table)) => (cql_dir, Some(history_table.trim())),
+        };
+        let mut source = MigrationSource::new(name.trim(), PathBuf::from(cql_dir.trim()))?;
+        if let Some(history_table) = history_table {
+            if !SOURCE_NAME_REGEX.is_match(history_table) {
+                return Err(anyhow!("{history_table} is not a valid history table name"));
+            }
+            source.history_table = history_table.to_string();
+        }
+        Ok(source)
+    }
+}
+
+/// The cql files migrated from a [MigrationSource].
+#[derive(Debug)]
+pub struct MigratedSource {
+    pub name: String,
+    pub migrated: Vec<CqlFile>,
+}
+
+/// The migrated and pending cql files of a [MigrationSource].
+#[derive(Debug)]
+pub struct SourceStatus {
+    pub name: String,
+    /// Cql files recorded in the source's history table.
+    pub migrated: Vec<CqlFile>,
+    /// Cql files not recorded in the source's history table, including repeatable cql files that
+    /// changed since they were migrated.
+    pub pending: Vec<CqlFile>,
+}
+
+/// The cql files a migration of a [MigrationSource] would execute.
+#[derive(Debug)]
+pub struct SourcePlan {
+    pub name: String,
+    pub planned: Vec<PlannedCql>,
+}
+
+/// Errors with the first source sharing a name or history table with a preceding source.
+pub(crate) fn validate_sources(sources: &[MigrationSource]) -> Result<()> {
+    for (i, source) in sources.iter().enumerate() {
+        if let Some(other) = sources[..i]
+            .iter()
+            .find(|other| other.name == source.name || other.history_table == source.history_table)
+        {
+            return Err(anyhow!(
+                "migration sources {} and {} must have different names and history tables",
+                other.name,
+                source.name
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_migration_source() {
+        assert_eq!(
+            "accounts=./cql/accounts"
+                .parse::<MigrationSource>()
+                .unwrap(),
+            MigrationSource {
+                name: "accounts".to_string(),
+                cql_dir: PathBuf::from("./cql/accounts"),
+                history_table: "migrated_cql_accounts".to_string(),
+            }
+        );
+        assert_eq!(
+            "billing=./cql/billing,billing_history"
+                .parse::<MigrationSource>()
+                .unwrap()
+                .history_table,
+            "billing_history"
+        );
+        assert_eq!(
+            "./cql/accounts"
+                .parse::<MigrationSource>()
+                .unwrap_err()
+                .to_string(),
+            "migration source ./cql/accounts is not a NAME=CQL_DIR or NAME=CQL_DIR,HISTORY_TABLE pair"
+        );
+        assert_eq!(
+            "account-events=./cql"
+                .parse::<MigrationSource>()
+                .unwrap_err()
+                .to_string(),
+            "account-events is not a valid migration source name"
+        );
+    }
+
+    #[test]
+    fn test_validate_sources() {
+        let accounts = MigrationSource::new("accounts", PathBuf::from("./cql/accounts")).unwrap();
+        let billing = MigrationSource::new("billing", PathBuf::from("./cql/billing")).unwrap();
+        assert!(validate_sources(&[accounts.clone(), billing.clone()]).is_ok());
+        assert_eq!(
+            validate_sources(&[
+                accounts,
+                MigrationSource {
+                    history_table: "migrated_cql_accounts".to_string(),
+                    ..billing
+                }
+            ])
+            .unwrap_err()
+            .to_string(),
+            "migration sources accounts and billing must have different names and history tables"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use anyhow::{Result, anyhow};
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+
+lazy_static! {
+    static ref PLACEHOLDER_REGEX: Regex = regex::Regex::new(
+        r"\$\{(?P<dollar>[a-zA-Z_][\da-zA-Z_]*)\}|\{\{\s*(?P<braces>[a-zA-Z_][\da-zA-Z_]*)\s*\}\}"
+    )
+    .expect("template placeholder regex");
+    static ref VAR_NAME_REGEX: Regex =
+        regex::Regex::new(r"^[a-zA-Z_][\da-zA-Z_]*$").expect("template var name regex");
+}
+
+/// Prefix of the environment variables template vars are read from, such as `CQUILL_VAR_keyspace`
+/// for `${keyspace}`, so unrelated environment variables are never rendered into cql.
+pub const ENV_VAR_PREFIX: &str = "CQUILL_VAR_";
+
+/// TemplateVars holds the values of `${name}` and `{{ name }}` placeholders rendered into cql
+/// files before they are migrated. A placeholder's value is resolved from the vars inserted with
+/// [TemplateVars::insert], then an environment variable of the name prefixed with [ENV_VAR_PREFIX]
+/// and last the defaults inserted with [TemplateVars::insert_default], such as the `[vars]` table
+/// of a `cquill.toml`. Rendering errors on a placeholder without a value.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TemplateVars {
+    defaults: HashMap<String, String>,
+    vars: HashMap<String, String>,
+}
+
+impl TemplateVars {
+    pub fn insert(&mut self, name: &str, value: &str) -> Result<()> {
+        validate_name(name)?;
+        self.vars.insert(name.to_string(), value.to_string());
+        Ok(())
+    }
+
+    pub fn insert_default(&mut self, name: &str, value: &str) -> Result<()> {
+        validate_name(name)?;
+        self.defaults.insert(name.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn get(&self, name: &str) -> Option<String> {
+        self.vars
+            .get(name)
+            .cloned()
+            .or_else(|| std::env::var(format!("{ENV_VAR_PREFIX}{name}")).ok())
+            .or_else(|| self.defaults.get(name).cloned())
+    }
+
+    /// Replaces the placeholders of `cql` with their values, erroring with the line of the first
+    /// placeholder without a value.
+    pub(crate) fn render(&self, cql: &str) -> Result<String> {
+        let mut undefined: Option<(usize, String)> = None;
+        let rendered = PLACEHOLDER_REGEX.replace_all(cql, |captures: &Captures| {
+            let placeholder = captures.get(0).unwrap();
+            let name = captures
+                .name("dollar")
+                .or_else(|| captures.name("braces"))
+                .unwrap()
+                .as_str();
+            match self.get(name) {
+                Some(value) => value,
+                None => {
+                    if undefined.is_none() {
+                        let line = cql[..placeholder.start()].matches('\n').count() + 1;
+                        undefined = Some((line, name.to_string()));
+                    }
+                    placeholder.as_str().to_string()
+                }
+            }
+        });
+        match undefined {
+            Some((line, name)) => Err(anyhow!("variable {name} on line {line} is not defined")),
+            None => Ok(rendered.into_owned()),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<()> {
+    if VAR_NAME_REGEX.is_match(name) {
+        Ok(())
+    } else {
+        Err(anyhow!("{name} is not a valid variable name"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_placeholders() {
+        let mut vars = TemplateVars::default();
+        vars.insert("keyspace", "api").unwrap();
+        vars.insert_default("keyspace", "ignored").unwrap();
+        vars.insert_default("replication_factor", "3").unwrap();
+
+        assert_eq!(
+            vars.render(
+                "create keyspace ${keyspace} with replication = {'class': 'SimpleStrategy', 'replication_factor': {{replication_factor}}};\ncreate table {{ keyspace }}.accounts (id uuid primary key);"
+            )
+            .unwrap(),
+            "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 3};\ncreate table api.accounts (id uuid primary key);"
+        );
+        assert_eq!(
+            vars.render("insert into api.tags (id, tags) values (1, {{'a'}})")
+                .unwrap(),
+            "insert into api.tags (id, tags) values (1, {{'a'}})"
+        );
+    }
+
+    #[test]
+    fn test_render_errors_with_undefined_var() {
+        let vars = TemplateVars::default();
+        assert_eq!(
+            vars.render("create table api.accounts (id uuid primary key);\n\nalter table ${cquill_undefined_keyspace}.accounts add name text;")
+                .unwrap_err()
+                .to_string(),
+            "variable cquill_undefined_keyspace on line 3 is not defined"
+        );
+    }
+
+    #[test]
+    fn test_render_reads_prefixed_env_vars() {
+        // SAFETY: the env vars are only read by this test
+        unsafe {
+            std::env::set_var("CQUILL_VAR_cquill_test_prefixed_keyspace", "api");
+            std::env::set_var("cquill_test_unprefixed_keyspace", "api");
+        }
+        let vars = TemplateVars::default();
+        assert_eq!(
+            vars.render("use ${cquill_test_prefixed_keyspace};")
+                .unwrap(),
+            "use api;"
+        );
+        assert_eq!(
+            vars.render("use ${cquill_test_unprefixed_keyspace};")
+                .unwrap_err()
+                .to_string(),
+            "variable cquill_test_unprefixed_keyspace on line 1 is not defined"
+        );
+    }
+
+    #[test]
+    fn test_insert_validates_name() {
+        let mut vars = TemplateVars::default();
+        assert_eq!(
+            vars.insert("api-keyspace", "api").unwrap_err().to_string(),
+            "api-keyspace is not a valid variable name"
+        );
+    }
+}
@@ -2,15 +2,18 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use rand::Rng;
 use scylla::client::session::Session;
 use scylla::client::session_builder::SessionBuilder;
 use temp_dir::TempDir;
 
+use crate::checksum::ChecksumAlgorithm;
 use crate::cql_file::CqlFile;
-use crate::keyspace::KeyspaceOpts;
+use crate::keyspace::{KeyspaceMap, KeyspaceOpts};
 use crate::migrate::MigrateArgs;
+use crate::template::TemplateVars;
 use crate::{CassandraOpts, TABLE, cql_file, queries};
 
 pub(crate) fn make_file(path: PathBuf, content: &str) {
@@ -90,9 +93,16 @@ impl TestHarness {
 
     pub fn migrate_args(&self) -> MigrateArgs {
         MigrateArgs {
+            allow_destructive: false,
+            allow_out_of_order: false,
+            checksum_algorithm: ChecksumAlgorithm::default(),
             cql_dir: self.cql_dir.clone(),
             history_keyspace: self.cquill_keyspace.clone(),
             history_table: self.cquill_table.clone(),
+            keyspace_map: KeyspaceMap::default(),
+            listener: Arc::new(()),
+            profile: None,
+            vars: TemplateVars::default(),
         }
     }
 }
@@ -130,7 +140,9 @@ impl TestHarnessBuilder {
 
     pub async fn initialize(self) -> TestHarness {
         for (filename, content) in self.cql_files.iter() {
-            make_file(self.directory.path().join(filename), content);
+            let path = self.directory.path().join(filename);
+            fs::create_dir_all(path.parent().unwrap()).expect("create cql subdirectory");
+            make_file(path, content);
         }
         let temp_dir_path = self.directory.path().canonicalize().unwrap();
         let cql_files = cql_file::files_from_dir(&temp_dir_path).unwrap_or_default();
@@ -145,7 +157,7 @@ impl TestHarnessBuilder {
             .expect("create table");
         TestHarness {
             session,
-            cql_dir: self.directory.path().to_path_buf(),
+            cql_dir: temp_dir_path,
             _directory: self.directory,
             cquill_keyspace: cquill_keyspace.name,
             cquill_table,
@@ -0,0 +1,188 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use cquill_ast::schema::{SchemaCatalog, SchemaError};
+
+use crate::cql_file::{self, CqlFile};
+
+pub struct VerifyOpts {
+    pub cql_dir: PathBuf,
+}
+
+/// The schema created by verified cql scripts.
+#[derive(Debug)]
+pub struct VerifiedCql {
+    pub cql_files: Vec<CqlFile>,
+    pub schema: SchemaCatalog,
+    /// Statements that could not be parsed, which the schema does not reflect.
+    pub unverified: Vec<UnverifiedCql>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct UnverifiedCql {
+    pub filename: String,
+    pub line: usize,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum VerifyError {
+    #[error("{error} on line {line} of {filename}")]
+    InvalidCql {
+        filename: String,
+        line: usize,
+        error: SchemaError,
+    },
+    #[error("{source}")]
+    Other {
+        #[from]
+        source: anyhow::Error,
+    },
+}
+
+/// `verify_cql` replays the cql scripts in [VerifyOpts::cql_dir] in version order without a
+/// database connection, erroring on the first statement the database would reject such as
+/// altering a table that does not exist. A successful method result contains the resulting schema.
+pub fn verify_cql(opts: VerifyOpts) -> Result<VerifiedCql, VerifyError> {
+    verify_cql_files(cql_file::files_from_dir(&opts.cql_dir)?)
+}
+
+/// Replays `cql_files` in order like [verify_cql].
+pub(crate) fn verify_cql_files(cql_files: Vec<CqlFile>) -> Result<VerifiedCql, VerifyError> {
+    let mut schema = SchemaCatalog::new();
+    let mut unverified = Vec::new();
+    for cql_file in &cql_files {
+        let cql = match cql_file.read_unrendered_cql() {
+            Err(err) => return Err(anyhow!(err).into()),
+            Ok(cql) => cql,
+        };
+        let statements = match cquill_ast::parse_cql_script(cql.as_str()) {
+            Err(err) => return Err(anyhow!("failed parsing file {cql_file}: {err}").into()),
+            Ok(statements) => statements,
+        };
+        for script_statement in statements {
+            match script_statement.statement {
+                Err(_) => unverified.push(UnverifiedCql {
+                    filename: cql_file.filename.clone(),
+                    line: script_statement.line,
+                }),
+                Ok(statement) => {
+                    if let Err(error) = schema.apply(&statement) {
+                        return Err(VerifyError::InvalidCql {
+                            filename: cql_file.filename.clone(),
+                            line: script_statement.line,
+                            error,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    Ok(VerifiedCql {
+        cql_files,
+        schema,
+        unverified,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use temp_dir::TempDir;
+
+    use crate::test_utils::make_file;
+
+    use super::*;
+
+    fn verify(cql_files: &[(&str, &str)]) -> Result<VerifiedCql, VerifyError> {
+        let temp_dir = TempDir::new().unwrap();
+        for (filename, cql) in cql_files {
+            make_file(temp_dir.path().join(filename), cql);
+        }
+        verify_cql(VerifyOpts {
+            cql_dir: temp_dir.path().to_path_buf(),
+        })
+    }
+
+    #[test]
+    fn test_verify_cql_replays_schema() {
+        let verified = verify(&[
+            (
+                "v001.cql",
+                "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};\nuse api;",
+            ),
+            (
+                "v002.cql",
+                "create table accounts (id uuid primary key);\nalter table accounts add name text;",
+            ),
+        ])
+        .unwrap();
+        assert_eq!(verified.cql_files.len(), 2);
+        assert!(verified.unverified.is_empty());
+        let accounts = verified.schema.table("api", "accounts").unwrap();
+        assert!(accounts.column("name").is_some());
+    }
+
+    #[test]
+    fn test_verify_cql_errors_with_file_and_line() {
+        match verify(&[
+            (
+                "v001.cql",
+                "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};",
+            ),
+            (
+                "v002.cql",
+                "create table api.accounts (id uuid primary key);\n\ndrop table api.invoices;",
+            ),
+        ]) {
+            Err(err @ VerifyError::InvalidCql { .. }) => {
+                assert_eq!(
+                    err.to_string(),
+                    "table api.invoices does not exist on line 3 of v002.cql"
+                );
+            }
+            result => panic!("{result:?}"),
+        }
+    }
+
+    #[test]
+    fn test_verify_cql_replays_qualified_function_overloads() {
+        let verified = verify(&[
+            (
+                "v001.cql",
+                "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};",
+            ),
+            (
+                "v002.cql",
+                "CREATE FUNCTION api.plus(a int) RETURNS NULL ON NULL INPUT RETURNS int LANGUAGE java AS 'return a + 1;';\nCREATE FUNCTION api.plus(a text) RETURNS NULL ON NULL INPUT RETURNS text LANGUAGE java AS 'return a + \"1\";';\nDROP FUNCTION api.plus(int);",
+            ),
+        ])
+        .unwrap();
+        assert!(verified.unverified.is_empty());
+        assert_eq!(
+            verified
+                .schema
+                .keyspace("api")
+                .unwrap()
+                .functions
+                .keys()
+                .map(|signature| signature.to_string())
+                .collect::<Vec<_>>(),
+            vec!["plus(text)"]
+        );
+    }
+
+    #[test]
+    fn test_verify_cql_reports_unparsed_statements() {
+        let verified = verify(&[(
+            "v001.cql",
+            "create keyspace api with replication = {'class': 'SimpleStrategy', 'replication_factor': 1};\ngrant select on keyspace api to api_role;",
+        )])
+        .unwrap();
+        assert_eq!(
+            verified.unverified,
+            vec![UnverifiedCql {
+                filename: "v001.cql".to_string(),
+                line: 2,
+            }]
+        );
+    }
+}
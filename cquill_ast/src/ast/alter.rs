@@ -0,0 +1,105 @@
+use crate::ast::{
+    AuthPassword, CqlDataType, CreateUserStatus, KeyspaceReplication, RoleConfigAttribute,
+    TableDefinitionAttribute, TokenView,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum AlterStatement {
+    Keyspace(AlterKeyspaceStatement),
+    MaterializedView(AlterMaterializedViewStatement),
+    Role(AlterRoleStatement),
+    Table(AlterTableStatement),
+    Type(AlterTypeStatement),
+    User(AlterUserStatement),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterKeyspaceStatement {
+    pub if_exists: bool,
+    pub keyspace_name: TokenView,
+    pub replication: Option<KeyspaceReplication>,
+    pub durable_writes: Option<bool>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterMaterializedViewStatement {
+    pub if_exists: bool,
+    pub keyspace_name: Option<TokenView>,
+    pub view_name: TokenView,
+    pub attributes: Vec<TableDefinitionAttribute>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterRoleStatement {
+    pub if_exists: bool,
+    pub role_name: TokenView,
+    pub attributes: Vec<RoleConfigAttribute>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterTableStatement {
+    pub if_exists: bool,
+    pub keyspace_name: Option<TokenView>,
+    pub table_name: TokenView,
+    pub instruction: AlterTableInstruction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterTableInstruction {
+    /// `ADD [IF NOT EXISTS] column_name data_type [STATIC]` or
+    /// `ADD [IF NOT EXISTS] (column_name data_type [STATIC], ...)`
+    AddColumns {
+        if_not_exists: bool,
+        columns: Vec<AlterTableColumn>,
+    },
+    /// `DROP [IF EXISTS] column_name` or `DROP [IF EXISTS] (column_name, ...)`
+    DropColumns {
+        if_exists: bool,
+        column_names: Vec<TokenView>,
+    },
+    /// `RENAME [IF EXISTS] column_name TO column_name AND ...`
+    RenameColumns {
+        if_exists: bool,
+        renames: Vec<(TokenView, TokenView)>,
+    },
+    /// `WITH table_option AND ...`
+    With(Vec<TableDefinitionAttribute>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterTableColumn {
+    pub column_name: TokenView,
+    pub data_type: CqlDataType,
+    pub is_static: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterTypeStatement {
+    pub if_exists: bool,
+    pub keyspace_name: Option<TokenView>,
+    pub type_name: TokenView,
+    pub instruction: AlterTypeInstruction,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlterTypeInstruction {
+    /// `ADD [IF NOT EXISTS] field_name data_type`
+    AddField {
+        if_not_exists: bool,
+        field_name: TokenView,
+        data_type: CqlDataType,
+    },
+    /// `RENAME [IF EXISTS] field_name TO field_name AND ...`
+    RenameFields {
+        if_exists: bool,
+        renames: Vec<(TokenView, TokenView)>,
+    },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct AlterUserStatement {
+    pub if_exists: bool,
+    pub user_name: TokenView,
+    pub password: Option<AuthPassword>,
+    pub user_status: Option<CreateUserStatus>,
+}
@@ -0,0 +1,16 @@
+use crate::ast::{CqlStatement, UpdateParameter};
+
+#[derive(Debug, PartialEq)]
+pub struct BatchStatement {
+    pub batch_type: BatchType,
+    pub using: Vec<UpdateParameter>,
+    /// `INSERT`, `UPDATE` and `DELETE` statements of the batch.
+    pub statements: Vec<CqlStatement>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BatchType {
+    Counter,
+    Logged,
+    Unlogged,
+}
@@ -1,5 +1,5 @@
 use crate::ast::table::TableAlias;
-use crate::ast::{CqlDataType, StringView, TokenView};
+use crate::ast::{CqlDataType, ExpressionTerm, SelectClause, StringView, TokenView, WhereClause};
 use std::collections::HashMap;
 
 // todo create custom index
@@ -9,7 +9,6 @@ pub enum CreateStatement {
     Function(CreateFunctionStatement),
     Index(CreateIndexStatement),
     Keyspace(CreateKeyspaceStatement),
-    // todo
     MaterializedView(CreateMaterializedViewStatement),
     Role(CreateRoleStatement),
     Table(CreateTableStatement),
@@ -18,21 +17,22 @@ pub enum CreateStatement {
     User(CreateUserStatement),
 }
 
-// todo represent init_condition with an AST node
 #[derive(Debug, PartialEq)]
 pub struct CreateAggregateStatement {
     pub if_exists_behavior: CreateIfExistsBehavior,
+    pub keyspace_name: Option<TokenView>,
     pub function_name: TokenView,
     pub function_arg: CqlDataType,
     pub state_function: TokenView,
     pub state_type: CqlDataType,
     pub final_function: Option<TokenView>,
-    pub init_condition: bool,
+    pub init_condition: Option<ExpressionTerm>,
 }
 
 #[derive(Debug, PartialEq)]
 pub struct CreateFunctionStatement {
     pub if_exists_behavior: CreateIfExistsBehavior,
+    pub keyspace_name: Option<TokenView>,
     pub function_name: TokenView,
     pub function_args: Vec<(TokenView, CqlDataType)>,
     pub on_null_input: OnNullInput,
@@ -88,7 +88,7 @@ pub struct CreateKeyspaceStatement {
     pub durable_writes: Option<bool>,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum KeyspaceReplication {
     NetworkTopology(HashMap<String, i8>),
     Simple(i8),
@@ -96,8 +96,15 @@ pub enum KeyspaceReplication {
 
 #[derive(Debug, PartialEq)]
 pub struct CreateMaterializedViewStatement {
-    if_not_exists: bool,
-    role_name: TokenView,
+    pub if_not_exists: bool,
+    pub keyspace_name: Option<TokenView>,
+    pub view_name: TokenView,
+    pub select_clause: SelectClause,
+    pub base_keyspace_name: Option<TokenView>,
+    pub base_table_name: TokenView,
+    pub where_clause: WhereClause,
+    pub primary_key: PrimaryKeyDefinition,
+    pub attributes: Option<Vec<TableDefinitionAttribute>>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -148,7 +155,8 @@ pub enum TableDefinitionAttribute {
     Compaction(HashMap<StringView, TokenView>),
     CompactStorage,
     Id(String),
-    TableOptions(TableOptions),
+    /// Any other `option_name = value` table option such as `default_time_to_live = 0`.
+    Option(TokenView, ExpressionTerm),
 }
 
 #[derive(Debug, PartialEq)]
@@ -157,15 +165,7 @@ pub struct ClusteringOrderDefinition {
     pub order: Option<ClusteringOrder>,
 }
 
-// todo impl for
-//  create table
-//  alter table
-//  create materialized view
-//  alter materialized view
-#[derive(Debug, PartialEq)]
-pub struct TableOptions {}
-
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ClusteringOrder {
     Asc,
     Desc,
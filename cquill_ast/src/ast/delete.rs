@@ -0,0 +1,12 @@
+use crate::ast::{AssignmentSelection, TokenView, UpdateIfBehavior, UpdateParameter, WhereClause};
+
+#[derive(Debug, PartialEq)]
+pub struct DeleteStatement {
+    /// Columns, collection elements or UDT fields to delete with an empty vec deleting the row.
+    pub selections: Vec<AssignmentSelection>,
+    pub keyspace_name: Option<TokenView>,
+    pub table_name: TokenView,
+    pub using: Vec<UpdateParameter>,
+    pub where_clause: WhereClause,
+    pub if_behavior: Option<UpdateIfBehavior>,
+}
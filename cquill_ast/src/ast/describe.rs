@@ -0,0 +1,58 @@
+use crate::ast::TokenView;
+
+#[derive(Debug, PartialEq)]
+pub struct DescribeStatement {
+    pub target: DescribeTarget,
+    /// Specifies `WITH INTERNALS` to include internal details such as dropped columns.
+    pub with_internals: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DescribeTarget {
+    Aggregate {
+        keyspace_name: Option<TokenView>,
+        aggregate_name: TokenView,
+    },
+    Aggregates,
+    Cluster,
+    Function {
+        keyspace_name: Option<TokenView>,
+        function_name: TokenView,
+    },
+    Functions,
+    Index {
+        keyspace_name: Option<TokenView>,
+        index_name: TokenView,
+    },
+    /// `DESCRIBE [ONLY] KEYSPACE [keyspace_name]` describes the keyspace in use when
+    /// `keyspace_name` is omitted.
+    Keyspace {
+        only: bool,
+        keyspace_name: Option<TokenView>,
+    },
+    Keyspaces,
+    MaterializedView {
+        keyspace_name: Option<TokenView>,
+        view_name: TokenView,
+    },
+    /// `DESCRIBE object_name` for describing a keyspace, table, view, index, type, function or
+    /// aggregate by name.
+    Object {
+        keyspace_name: Option<TokenView>,
+        object_name: TokenView,
+    },
+    /// `DESCRIBE [FULL] SCHEMA`
+    Schema {
+        full: bool,
+    },
+    Table {
+        keyspace_name: Option<TokenView>,
+        table_name: TokenView,
+    },
+    Tables,
+    Type {
+        keyspace_name: Option<TokenView>,
+        type_name: TokenView,
+    },
+    Types,
+}
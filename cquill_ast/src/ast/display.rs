@@ -0,0 +1,1253 @@
+//! Renders AST nodes as normalized CQL with uppercase keywords, lowercase data types and
+//! identifiers, literals and string constants written as they were in the parsed source.
+//! Statements are rendered without a terminating semicolon.
+
+use std::fmt::{Display, Formatter, Result};
+
+use crate::ast::*;
+
+const INDENT: &str = "    ";
+
+/// Writes items separated by `separator`.
+struct Joined<'a, T: Display>(&'a [T], &'static str);
+
+impl<T: Display> Display for Joined<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for (i, item) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.1)?;
+            }
+            write!(f, "{item}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes the columns of an `ALTER TABLE` instruction, parenthesized when there are several since
+/// Cassandra only accepts multiple columns as a parenthesized list.
+struct ColumnList<'a, T: Display>(&'a [T]);
+
+impl<T: Display> Display for ColumnList<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.0.len() > 1 {
+            write!(f, "({})", Joined(self.0, ", "))
+        } else {
+            write!(f, "{}", Joined(self.0, ", "))
+        }
+    }
+}
+
+/// Writes an object name with its keyspace qualifier such as `big_data_keyspace.big_data_table`.
+struct Qualified<'a>(&'a Option<TokenView>, &'a TokenView);
+
+impl Display for Qualified<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 {
+            None => write!(f, "{}", self.1),
+            Some(keyspace_name) => write!(f, "{keyspace_name}.{}", self.1),
+        }
+    }
+}
+
+fn if_exists(if_exists: bool) -> &'static str {
+    if if_exists { " IF EXISTS" } else { "" }
+}
+
+fn if_not_exists(if_not_exists: bool) -> &'static str {
+    if if_not_exists { " IF NOT EXISTS" } else { "" }
+}
+
+fn write_using(f: &mut Formatter<'_>, using: &[UpdateParameter]) -> Result {
+    if using.is_empty() {
+        Ok(())
+    } else {
+        write!(f, " USING {}", Joined(using, " AND "))
+    }
+}
+
+/// Writes `{'key': value, ...}` map literals sorted by key.
+fn write_sorted_map<K: Display, V: Display>(f: &mut Formatter<'_>, entries: Vec<(K, V)>) -> Result {
+    let mut entries: Vec<(String, V)> = entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    f.write_str("{")?;
+    for (i, (key, value)) in entries.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{key}: {value}")?;
+    }
+    f.write_str("}")
+}
+
+impl Display for CqlStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CqlStatement::Alter(statement) => statement.fmt(f),
+            CqlStatement::Batch(statement) => statement.fmt(f),
+            CqlStatement::Create(statement) => statement.fmt(f),
+            CqlStatement::Delete(statement) => statement.fmt(f),
+            CqlStatement::Describe(statement) => statement.fmt(f),
+            CqlStatement::Drop(statement) => statement.fmt(f),
+            CqlStatement::Insert(statement) => statement.fmt(f),
+            CqlStatement::Select(statement) => statement.fmt(f),
+            CqlStatement::Truncate(statement) => statement.fmt(f),
+            CqlStatement::Update(statement) => statement.fmt(f),
+            CqlStatement::Use(statement) => statement.fmt(f),
+        }
+    }
+}
+
+// data types and literals
+
+impl Display for StringView {
+    /// Writes the string constant with its quotes or dollar signs.
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(&self.cql[self.range.begin()..=self.range.end()])
+    }
+}
+
+impl Display for CqlDataType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CqlDataType::CollectionType(collection_type) => collection_type.fmt(f),
+            CqlDataType::CustomType(class_name) => class_name.fmt(f),
+            CqlDataType::Frozen(data_type) => write!(f, "frozen<{data_type}>"),
+            CqlDataType::ValueType(value_type) => value_type.fmt(f),
+            CqlDataType::Tuple(data_types) => write!(f, "tuple<{}>", Joined(data_types, ", ")),
+            CqlDataType::Vector(data_type, dimension) => {
+                write!(f, "vector<{data_type}, {dimension}>")
+            }
+        }
+    }
+}
+
+impl Display for CqlCollectionType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CqlCollectionType::List(data_type) => write!(f, "list<{data_type}>"),
+            CqlCollectionType::Map(key_type, value_type) => {
+                write!(f, "map<{key_type}, {value_type}>")
+            }
+            CqlCollectionType::Set(data_type) => write!(f, "set<{data_type}>"),
+        }
+    }
+}
+
+impl Display for CqlValueType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CqlValueType::NativeType(native_type) => native_type.fmt(f),
+            CqlValueType::UserDefinedType(keyspace_name, type_name) => {
+                Qualified(keyspace_name, type_name).fmt(f)
+            }
+        }
+    }
+}
+
+impl Display for CqlNativeType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            CqlNativeType::Ascii => "ascii",
+            CqlNativeType::BigInt => "bigint",
+            CqlNativeType::Blob => "blob",
+            CqlNativeType::Boolean => "boolean",
+            CqlNativeType::Counter => "counter",
+            CqlNativeType::Date => "date",
+            CqlNativeType::Decimal => "decimal",
+            CqlNativeType::Double => "double",
+            CqlNativeType::Duration => "duration",
+            CqlNativeType::Float => "float",
+            CqlNativeType::INet => "inet",
+            CqlNativeType::Int => "int",
+            CqlNativeType::SmallInt => "smallint",
+            CqlNativeType::Text => "text",
+            CqlNativeType::Time => "time",
+            CqlNativeType::Timestamp => "timestamp",
+            CqlNativeType::TimeUuid => "timeuuid",
+            CqlNativeType::TinyInt => "tinyint",
+            CqlNativeType::Uuid => "uuid",
+            CqlNativeType::VarChar => "varchar",
+            CqlNativeType::VarInt => "varint",
+        })
+    }
+}
+
+// expressions
+
+/// Writes an operand of an arithmetic operation with parentheses when the operand's operator
+/// binds looser than its parent's, or as tightly on the right side of the left-associative parent.
+fn write_arithmetic_operand(
+    f: &mut Formatter<'_>,
+    operand: &ExpressionTerm,
+    parent: &ArithmeticOperator,
+    right: bool,
+) -> Result {
+    match operand {
+        ExpressionTerm::Arithmetic { operator, .. }
+            if operator.precedence() < parent.precedence()
+                || (right && operator.precedence() == parent.precedence()) =>
+        {
+            write!(f, "({operand})")
+        }
+        _ => operand.fmt(f),
+    }
+}
+
+impl Display for ExpressionTerm {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ExpressionTerm::Arithmetic {
+                left,
+                operator,
+                right,
+            } => {
+                write_arithmetic_operand(f, left, operator, false)?;
+                write!(f, " {operator} ")?;
+                write_arithmetic_operand(f, right, operator, true)
+            }
+            ExpressionTerm::BindMarker(bind_marker) => bind_marker.fmt(f),
+            ExpressionTerm::Blob(view)
+            | ExpressionTerm::Column(view)
+            | ExpressionTerm::Duration(view)
+            | ExpressionTerm::Number(view)
+            | ExpressionTerm::Uuid(view) => view.fmt(f),
+            ExpressionTerm::Boolean(b) => write!(f, "{b}"),
+            ExpressionTerm::FunctionCall(function_call) => function_call.fmt(f),
+            ExpressionTerm::List(terms) => write!(f, "[{}]", Joined(terms, ", ")),
+            ExpressionTerm::Map(entries) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                f.write_str("}")
+            }
+            ExpressionTerm::Negated(term) => match term.as_ref() {
+                ExpressionTerm::Arithmetic { .. } => write!(f, "-({term})"),
+                _ => write!(f, "-{term}"),
+            },
+            ExpressionTerm::Null => f.write_str("null"),
+            ExpressionTerm::Set(terms) => write!(f, "{{{}}}", Joined(terms, ", ")),
+            ExpressionTerm::String(string) => string.fmt(f),
+            ExpressionTerm::Tuple(terms) => write!(f, "({})", Joined(terms, ", ")),
+            ExpressionTerm::UserDefinedType(fields) => {
+                f.write_str("{")?;
+                for (i, (field_name, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{field_name}: {value}")?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+impl Display for ArithmeticOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            ArithmeticOperator::Add => "+",
+            ArithmeticOperator::Divide => "/",
+            ArithmeticOperator::Modulo => "%",
+            ArithmeticOperator::Multiply => "*",
+            ArithmeticOperator::Subtract => "-",
+        })
+    }
+}
+
+impl Display for BindMarker {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            BindMarker::Anonymous => f.write_str("?"),
+            BindMarker::Named(name) => write!(f, ":{name}"),
+        }
+    }
+}
+
+impl Display for FunctionCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}({})",
+            Qualified(&self.keyspace_name, &self.function_name),
+            Joined(&self.args, ", ")
+        )
+    }
+}
+
+impl Display for WhereClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "WHERE {}", Joined(&self.relations, " AND "))
+    }
+}
+
+impl Display for WhereClauseRelation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} {} {}", self.columns, self.operator, self.expr_term)
+    }
+}
+
+impl Display for RelationColumns {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            RelationColumns::Single(column_name) => column_name.fmt(f),
+            RelationColumns::Tuple(column_names) => {
+                write!(f, "({})", Joined(column_names, ", "))
+            }
+            RelationColumns::Token(column_names) => {
+                write!(f, "token({})", Joined(column_names, ", "))
+            }
+        }
+    }
+}
+
+impl Display for RelationOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self {
+            RelationOperator::Contains => "CONTAINS",
+            RelationOperator::ContainsKey => "CONTAINS KEY",
+            RelationOperator::Equal => "=",
+            RelationOperator::GreaterThan => ">",
+            RelationOperator::GreaterThanEqual => ">=",
+            RelationOperator::In => "IN",
+            RelationOperator::IsNot => "IS NOT",
+            RelationOperator::LessThan => "<",
+            RelationOperator::LessThanEqual => "<=",
+            RelationOperator::NotEqual => "!=",
+        })
+    }
+}
+
+// dml statements
+
+impl Display for BatchStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self.batch_type {
+            BatchType::Counter => "BEGIN COUNTER BATCH",
+            BatchType::Logged => "BEGIN BATCH",
+            BatchType::Unlogged => "BEGIN UNLOGGED BATCH",
+        })?;
+        write_using(f, &self.using)?;
+        for statement in &self.statements {
+            write!(f, "\n{INDENT}{statement};")?;
+        }
+        f.write_str("\nAPPLY BATCH")
+    }
+}
+
+impl Display for DeleteStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("DELETE ")?;
+        if !self.selections.is_empty() {
+            write!(f, "{} ", Joined(&self.selections, ", "))?;
+        }
+        write!(
+            f,
+            "FROM {}",
+            Qualified(&self.keyspace_name, &self.table_name)
+        )?;
+        write_using(f, &self.using)?;
+        write!(f, " {}", self.where_clause)?;
+        if let Some(if_behavior) = &self.if_behavior {
+            write!(f, " {if_behavior}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for InsertStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "INSERT INTO {} {}{}",
+            Qualified(&self.keyspace_name, &self.table_name),
+            self.values,
+            if_not_exists(self.if_not_exists),
+        )?;
+        write_using(f, &self.using)
+    }
+}
+
+impl Display for InsertValues {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            InsertValues::Json { json, default } => {
+                write!(f, "JSON {json}")?;
+                match default {
+                    None => Ok(()),
+                    Some(JsonDefault::Null) => f.write_str(" DEFAULT NULL"),
+                    Some(JsonDefault::Unset) => f.write_str(" DEFAULT UNSET"),
+                }
+            }
+            InsertValues::Values {
+                column_names,
+                values,
+            } => write!(
+                f,
+                "({}) VALUES ({})",
+                Joined(column_names, ", "),
+                Joined(values, ", ")
+            ),
+        }
+    }
+}
+
+impl Display for SelectStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("SELECT ")?;
+        if self.json {
+            f.write_str("JSON ")?;
+        }
+        if self.distinct {
+            f.write_str("DISTINCT ")?;
+        }
+        write!(
+            f,
+            "{} FROM {}",
+            self.select_clause,
+            Qualified(&self.keyspace_name, &self.table_name)
+        )?;
+        if let Some(where_clause) = &self.where_clause {
+            write!(f, " {where_clause}")?;
+        }
+        if let Some(group_by) = &self.group_by {
+            write!(f, " GROUP BY {}", Joined(group_by, ", "))?;
+        }
+        if let Some(order_by) = &self.order_by {
+            write!(f, " ORDER BY {}", Joined(order_by, ", "))?;
+        }
+        if let Some(per_partition_limit) = &self.per_partition_limit {
+            write!(f, " PER PARTITION LIMIT {per_partition_limit}")?;
+        }
+        if let Some(limit) = &self.limit {
+            write!(f, " LIMIT {limit}")?;
+        }
+        if self.allow_filtering {
+            f.write_str(" ALLOW FILTERING")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for SelectClause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SelectClause::All => f.write_str("*"),
+            SelectClause::Selectors(selectors) => Joined(selectors, ", ").fmt(f),
+        }
+    }
+}
+
+impl Display for SelectorDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match &self.alias {
+            None => self.selector.fmt(f),
+            Some(alias) => write!(f, "{} AS {alias}", self.selector),
+        }
+    }
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Selector::Cast {
+                selector,
+                data_type,
+            } => write!(f, "CAST({selector} AS {data_type})"),
+            Selector::CountAll => f.write_str("COUNT(*)"),
+            Selector::Term(term) => term.fmt(f),
+        }
+    }
+}
+
+impl Display for UpdateStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "UPDATE {}",
+            Qualified(&self.keyspace_name, &self.table_name)
+        )?;
+        write_using(f, &self.using)?;
+        write!(
+            f,
+            " SET {} {}",
+            Joined(&self.assignments, ", "),
+            self.where_clause
+        )?;
+        if let Some(if_behavior) = &self.if_behavior {
+            write!(f, " {if_behavior}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Assignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} = {}", self.selection, self.expr_term)
+    }
+}
+
+impl Display for AssignmentSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            AssignmentSelection::Column { column_name } => column_name.fmt(f),
+            AssignmentSelection::ColumnAccess {
+                column_name,
+                expr_term,
+            } => write!(f, "{column_name}[{expr_term}]"),
+            AssignmentSelection::ColumnField {
+                column_name,
+                field_name,
+            } => write!(f, "{column_name}.{field_name}"),
+        }
+    }
+}
+
+impl Display for UpdateParameter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            UpdateParameter::Timestamp(term) => write!(f, "TIMESTAMP {term}"),
+            UpdateParameter::Ttl(term) => write!(f, "TTL {term}"),
+        }
+    }
+}
+
+impl Display for UpdateIfBehavior {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            UpdateIfBehavior::Conditional(conditions) => {
+                write!(f, "IF {}", Joined(conditions, " AND "))
+            }
+            UpdateIfBehavior::Exists => f.write_str("IF EXISTS"),
+        }
+    }
+}
+
+impl Display for UpdateIfCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} {} {}", self.selection, self.operator, self.expr_term)
+    }
+}
+
+// ddl statements
+
+impl Display for CreateStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CreateStatement::Aggregate(statement) => statement.fmt(f),
+            CreateStatement::Function(statement) => statement.fmt(f),
+            CreateStatement::Index(statement) => statement.fmt(f),
+            CreateStatement::Keyspace(statement) => statement.fmt(f),
+            CreateStatement::MaterializedView(statement) => statement.fmt(f),
+            CreateStatement::Role(statement) => statement.fmt(f),
+            CreateStatement::Table(statement) => statement.fmt(f),
+            CreateStatement::Trigger(statement) => statement.fmt(f),
+            CreateStatement::Type(statement) => statement.fmt(f),
+            CreateStatement::User(statement) => statement.fmt(f),
+        }
+    }
+}
+
+/// Writes `CREATE [OR REPLACE] object_type [IF NOT EXISTS]` for functions and aggregates.
+fn write_create_or_replace(
+    f: &mut Formatter<'_>,
+    object_type: &str,
+    if_exists_behavior: &CreateIfExistsBehavior,
+) -> Result {
+    match if_exists_behavior {
+        CreateIfExistsBehavior::DoNotError => write!(f, "CREATE {object_type} IF NOT EXISTS"),
+        CreateIfExistsBehavior::Error => write!(f, "CREATE {object_type}"),
+        CreateIfExistsBehavior::Replace => write!(f, "CREATE OR REPLACE {object_type}"),
+    }
+}
+
+impl Display for CreateAggregateStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_create_or_replace(f, "AGGREGATE", &self.if_exists_behavior)?;
+        write!(
+            f,
+            " {}({})\n{INDENT}SFUNC {}\n{INDENT}STYPE {}",
+            Qualified(&self.keyspace_name, &self.function_name),
+            self.function_arg,
+            self.state_function,
+            self.state_type
+        )?;
+        if let Some(final_function) = &self.final_function {
+            write!(f, "\n{INDENT}FINALFUNC {final_function}")?;
+        }
+        if let Some(init_condition) = &self.init_condition {
+            write!(f, "\n{INDENT}INITCOND {init_condition}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for CreateFunctionStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_create_or_replace(f, "FUNCTION", &self.if_exists_behavior)?;
+        write!(
+            f,
+            " {}(",
+            Qualified(&self.keyspace_name, &self.function_name)
+        )?;
+        for (i, (arg_name, data_type)) in self.function_args.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{arg_name} {data_type}")?;
+        }
+        write!(
+            f,
+            ")\n{INDENT}{}\n{INDENT}RETURNS {}\n{INDENT}LANGUAGE {}\n{INDENT}AS {}",
+            match self.on_null_input {
+                OnNullInput::Called => "CALLED ON NULL INPUT",
+                OnNullInput::ReturnsNull => "RETURNS NULL ON NULL INPUT",
+            },
+            self.returns,
+            self.language,
+            self.function_body
+        )
+    }
+}
+
+impl Display for CreateIndexStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "CREATE INDEX{}", if_not_exists(self.if_not_exists))?;
+        if let Some(index_name) = &self.index_name {
+            write!(f, " {index_name}")?;
+        }
+        write!(
+            f,
+            " ON {} ({})",
+            Qualified(&self.keyspace_name, &self.table_name),
+            self.on_column
+        )
+    }
+}
+
+impl Display for CreateIndexColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            CreateIndexColumn::Column(column_name) => column_name.fmt(f),
+            CreateIndexColumn::FullCollection(column_name) => write!(f, "FULL({column_name})"),
+            CreateIndexColumn::MapEntries(column_name) => write!(f, "ENTRIES({column_name})"),
+            CreateIndexColumn::MapValues(column_name) => write!(f, "VALUES({column_name})"),
+            CreateIndexColumn::MapKeys(column_name) => write!(f, "KEYS({column_name})"),
+        }
+    }
+}
+
+impl Display for CreateKeyspaceStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE KEYSPACE{} {} WITH replication = {}",
+            if_not_exists(self.if_not_exists),
+            self.keyspace_name,
+            self.replication
+        )?;
+        if let Some(durable_writes) = self.durable_writes {
+            write!(f, " AND durable_writes = {durable_writes}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for KeyspaceReplication {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            KeyspaceReplication::NetworkTopology(datacenter_factors) => {
+                let mut datacenter_factors: Vec<(&String, &i8)> =
+                    datacenter_factors.iter().collect();
+                datacenter_factors.sort();
+                f.write_str("{'class': 'NetworkTopologyStrategy'")?;
+                for (datacenter, factor) in datacenter_factors {
+                    write!(f, ", '{datacenter}': {factor}")?;
+                }
+                f.write_str("}")
+            }
+            KeyspaceReplication::Simple(factor) => write!(
+                f,
+                "{{'class': 'SimpleStrategy', 'replication_factor': {factor}}}"
+            ),
+        }
+    }
+}
+
+impl Display for CreateMaterializedViewStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE MATERIALIZED VIEW{} {} AS\n{INDENT}SELECT {} FROM {}\n{INDENT}{}\n{INDENT}PRIMARY KEY ({})",
+            if_not_exists(self.if_not_exists),
+            Qualified(&self.keyspace_name, &self.view_name),
+            self.select_clause,
+            Qualified(&self.base_keyspace_name, &self.base_table_name),
+            self.where_clause,
+            self.primary_key
+        )?;
+        if let Some(attributes) = &self.attributes {
+            write!(f, "\n{INDENT}")?;
+            write_table_attributes(f, attributes)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for CreateRoleStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE ROLE{} {}",
+            if_not_exists(self.if_not_exists),
+            self.role_name
+        )?;
+        if let Some(attributes) = &self.attributes {
+            write!(f, " WITH {}", Joined(attributes, " AND "))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for RoleConfigAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            RoleConfigAttribute::Superuser(superuser) => write!(f, "SUPERUSER = {superuser}"),
+            RoleConfigAttribute::Login(login) => write!(f, "LOGIN = {login}"),
+            RoleConfigAttribute::Password(AuthPassword::Hashed(password)) => {
+                write!(f, "HASHED PASSWORD = {password}")
+            }
+            RoleConfigAttribute::Password(AuthPassword::PlainText(password)) => {
+                write!(f, "PASSWORD = {password}")
+            }
+            RoleConfigAttribute::Options(options) => {
+                f.write_str("OPTIONS = ")?;
+                write_sorted_map(f, options.iter().collect())
+            }
+            RoleConfigAttribute::Access(Datacenters::All) => {
+                f.write_str("ACCESS TO ALL DATACENTERS")
+            }
+            RoleConfigAttribute::Access(Datacenters::Explicit(datacenters)) => {
+                write!(f, "ACCESS TO DATACENTERS {{{}}}", Joined(datacenters, ", "))
+            }
+        }
+    }
+}
+
+impl Display for CreateTableStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE {}{} {} (",
+            match self.table_alias {
+                None => "TABLE",
+                Some(TableAlias::ColumnFamily) => "COLUMNFAMILY",
+            },
+            if_not_exists(self.if_not_exists),
+            Qualified(&self.keyspace_name, &self.table_name)
+        )?;
+        for (i, column_definition) in self.column_definitions.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "\n{INDENT}{column_definition}")?;
+        }
+        f.write_str("\n)")?;
+        if let Some(attributes) = &self.attributes {
+            f.write_str(" ")?;
+            write_table_attributes(f, attributes)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `WITH option AND option` with each option after the first on an indented line.
+fn write_table_attributes(
+    f: &mut Formatter<'_>,
+    attributes: &[TableDefinitionAttribute],
+) -> Result {
+    for (i, attribute) in attributes.iter().enumerate() {
+        if i == 0 {
+            write!(f, "WITH {attribute}")?;
+        } else {
+            write!(f, "\n{INDENT}AND {attribute}")?;
+        }
+    }
+    Ok(())
+}
+
+impl Display for ColumnDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ColumnDefinition::Column {
+                column_name,
+                data_type,
+                attribute,
+            } => {
+                write!(f, "{column_name} {data_type}")?;
+                match attribute {
+                    None => Ok(()),
+                    Some(ColumnDefinitionAttribute::PrimaryKey) => f.write_str(" PRIMARY KEY"),
+                    Some(ColumnDefinitionAttribute::Static) => f.write_str(" STATIC"),
+                }
+            }
+            ColumnDefinition::PrimaryKey(primary_key) => write!(f, "PRIMARY KEY ({primary_key})"),
+        }
+    }
+}
+
+impl Display for PrimaryKeyDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            PrimaryKeyDefinition::Single(partition) => partition.fmt(f),
+            PrimaryKeyDefinition::Compound {
+                partition,
+                clustering,
+            } => write!(f, "{partition}, {}", Joined(clustering, ", ")),
+            PrimaryKeyDefinition::CompositePartition {
+                partition,
+                clustering,
+            } => {
+                write!(f, "({})", Joined(partition, ", "))?;
+                if !clustering.is_empty() {
+                    write!(f, ", {}", Joined(clustering, ", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Display for TableDefinitionAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            TableDefinitionAttribute::ClusteringOrderBy(clustering_orders) => {
+                write!(
+                    f,
+                    "CLUSTERING ORDER BY ({})",
+                    Joined(clustering_orders, ", ")
+                )
+            }
+            TableDefinitionAttribute::Comment(comment) => write!(f, "comment = {comment}"),
+            TableDefinitionAttribute::Compaction(compaction) => {
+                f.write_str("compaction = ")?;
+                write_sorted_map(f, compaction.iter().collect())
+            }
+            TableDefinitionAttribute::CompactStorage => f.write_str("COMPACT STORAGE"),
+            TableDefinitionAttribute::Id(id) => write!(f, "ID = '{id}'"),
+            TableDefinitionAttribute::Option(option_name, value) => {
+                write!(f, "{option_name} = {value}")
+            }
+        }
+    }
+}
+
+impl Display for ClusteringOrderDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.order {
+            None => self.column_name.fmt(f),
+            Some(ClusteringOrder::Asc) => write!(f, "{} ASC", self.column_name),
+            Some(ClusteringOrder::Desc) => write!(f, "{} DESC", self.column_name),
+        }
+    }
+}
+
+impl Display for CreateTriggerStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE TRIGGER{} {} ON {} USING {}",
+            if_not_exists(self.if_not_exists),
+            self.trigger_name,
+            Qualified(&self.keyspace_name, &self.table_name),
+            self.index_classpath
+        )
+    }
+}
+
+impl Display for CreateTypeStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE TYPE{} {} (",
+            if_not_exists(self.if_not_exists),
+            Qualified(&self.keyspace_name, &self.type_name)
+        )?;
+        for (i, (field_name, data_type)) in self.fields.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "\n{INDENT}{field_name} {data_type}")?;
+        }
+        f.write_str("\n)")
+    }
+}
+
+impl Display for CreateUserStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "CREATE USER{} {}",
+            if_not_exists(self.if_not_exists),
+            self.user_name
+        )?;
+        match &self.password {
+            None => {}
+            Some(AuthPassword::Hashed(password)) => write!(f, " WITH HASHED PASSWORD {password}")?,
+            Some(AuthPassword::PlainText(password)) => write!(f, " WITH PASSWORD {password}")?,
+        }
+        match &self.user_status {
+            None => Ok(()),
+            Some(CreateUserStatus::NoSuperuser) => f.write_str(" NOSUPERUSER"),
+            Some(CreateUserStatus::Superuser) => f.write_str(" SUPERUSER"),
+        }
+    }
+}
+
+impl Display for AlterStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            AlterStatement::Keyspace(statement) => statement.fmt(f),
+            AlterStatement::MaterializedView(statement) => statement.fmt(f),
+            AlterStatement::Role(statement) => statement.fmt(f),
+            AlterStatement::Table(statement) => statement.fmt(f),
+            AlterStatement::Type(statement) => statement.fmt(f),
+            AlterStatement::User(statement) => statement.fmt(f),
+        }
+    }
+}
+
+impl Display for AlterKeyspaceStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER KEYSPACE{} {} WITH ",
+            if_exists(self.if_exists),
+            self.keyspace_name
+        )?;
+        if let Some(replication) = &self.replication {
+            write!(f, "replication = {replication}")?;
+            if self.durable_writes.is_some() {
+                f.write_str(" AND ")?;
+            }
+        }
+        if let Some(durable_writes) = self.durable_writes {
+            write!(f, "durable_writes = {durable_writes}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for AlterMaterializedViewStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER MATERIALIZED VIEW{} {} ",
+            if_exists(self.if_exists),
+            Qualified(&self.keyspace_name, &self.view_name)
+        )?;
+        write_table_attributes(f, &self.attributes)
+    }
+}
+
+impl Display for AlterRoleStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER ROLE{} {} WITH {}",
+            if_exists(self.if_exists),
+            self.role_name,
+            Joined(&self.attributes, " AND ")
+        )
+    }
+}
+
+impl Display for AlterTableStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER TABLE{} {} ",
+            if_exists(self.if_exists),
+            Qualified(&self.keyspace_name, &self.table_name)
+        )?;
+        match &self.instruction {
+            AlterTableInstruction::AddColumns {
+                if_not_exists: add_if_not_exists,
+                columns,
+            } => write!(
+                f,
+                "ADD{} {}",
+                if_not_exists(*add_if_not_exists),
+                ColumnList(columns)
+            ),
+            AlterTableInstruction::DropColumns {
+                if_exists: drop_if_exists,
+                column_names,
+            } => write!(
+                f,
+                "DROP{} {}",
+                if_exists(*drop_if_exists),
+                ColumnList(column_names)
+            ),
+            AlterTableInstruction::RenameColumns {
+                if_exists: rename_if_exists,
+                renames,
+            } => {
+                write!(f, "RENAME{} ", if_exists(*rename_if_exists))?;
+                write_renames(f, renames)
+            }
+            AlterTableInstruction::With(attributes) => write_table_attributes(f, attributes),
+        }
+    }
+}
+
+impl Display for AlterTableColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "{} {}", self.column_name, self.data_type)?;
+        if self.is_static {
+            f.write_str(" STATIC")?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `name TO name AND name TO name` renames of `ALTER TABLE` and `ALTER TYPE` statements.
+fn write_renames(f: &mut Formatter<'_>, renames: &[(TokenView, TokenView)]) -> Result {
+    for (i, (from, to)) in renames.iter().enumerate() {
+        if i > 0 {
+            f.write_str(" AND ")?;
+        }
+        write!(f, "{from} TO {to}")?;
+    }
+    Ok(())
+}
+
+impl Display for AlterTypeStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER TYPE{} {} ",
+            if_exists(self.if_exists),
+            Qualified(&self.keyspace_name, &self.type_name)
+        )?;
+        match &self.instruction {
+            AlterTypeInstruction::AddField {
+                if_not_exists: add_if_not_exists,
+                field_name,
+                data_type,
+            } => write!(
+                f,
+                "ADD{} {field_name} {data_type}",
+                if_not_exists(*add_if_not_exists)
+            ),
+            AlterTypeInstruction::RenameFields {
+                if_exists: rename_if_exists,
+                renames,
+            } => {
+                write!(f, "RENAME{} ", if_exists(*rename_if_exists))?;
+                write_renames(f, renames)
+            }
+        }
+    }
+}
+
+impl Display for AlterUserStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "ALTER USER{} {}",
+            if_exists(self.if_exists),
+            self.user_name
+        )?;
+        match &self.password {
+            None => {}
+            Some(AuthPassword::Hashed(password)) => write!(f, " WITH HASHED PASSWORD {password}")?,
+            Some(AuthPassword::PlainText(password)) => write!(f, " WITH PASSWORD {password}")?,
+        }
+        match &self.user_status {
+            None => Ok(()),
+            Some(CreateUserStatus::NoSuperuser) => f.write_str(" NOSUPERUSER"),
+            Some(CreateUserStatus::Superuser) => f.write_str(" SUPERUSER"),
+        }
+    }
+}
+
+impl Display for DropStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            DropStatement::Aggregate(statement) => {
+                write!(
+                    f,
+                    "DROP AGGREGATE{} {}",
+                    if_exists(statement.if_exists),
+                    Qualified(&statement.keyspace_name, &statement.aggregate_name)
+                )?;
+                write_signature(f, &statement.signature)
+            }
+            DropStatement::Function(statement) => {
+                write!(
+                    f,
+                    "DROP FUNCTION{} {}",
+                    if_exists(statement.if_exists),
+                    Qualified(&statement.keyspace_name, &statement.function_name)
+                )?;
+                write_signature(f, &statement.signature)
+            }
+            DropStatement::Index(statement) => write!(
+                f,
+                "DROP INDEX{} {}",
+                if_exists(statement.if_exists),
+                Qualified(&statement.keyspace_name, &statement.index_name)
+            ),
+            DropStatement::Keyspace(statement) => write!(
+                f,
+                "DROP KEYSPACE{} {}",
+                if_exists(statement.if_exists),
+                statement.keyspace_name
+            ),
+            DropStatement::MaterializedView(statement) => write!(
+                f,
+                "DROP MATERIALIZED VIEW{} {}",
+                if_exists(statement.if_exists),
+                Qualified(&statement.keyspace_name, &statement.view_name)
+            ),
+            DropStatement::Role(statement) => write!(
+                f,
+                "DROP ROLE{} {}",
+                if_exists(statement.if_exists),
+                statement.role_name
+            ),
+            DropStatement::Table(statement) => write!(
+                f,
+                "DROP {}{} {}",
+                match statement.alias {
+                    None => "TABLE",
+                    Some(TableAlias::ColumnFamily) => "COLUMNFAMILY",
+                },
+                if_exists(statement.if_exists),
+                Qualified(&statement.keyspace_name, &statement.table_name)
+            ),
+            DropStatement::Trigger(statement) => write!(
+                f,
+                "DROP TRIGGER{} {} ON {}",
+                if_exists(statement.if_exists),
+                statement.trigger_name,
+                Qualified(&statement.keyspace_name, &statement.table_name)
+            ),
+            DropStatement::Type(statement) => write!(
+                f,
+                "DROP TYPE{} {}",
+                if_exists(statement.if_exists),
+                Qualified(&statement.keyspace_name, &statement.type_name)
+            ),
+            DropStatement::User(statement) => write!(
+                f,
+                "DROP USER{} {}",
+                if_exists(statement.if_exists),
+                statement.user_name
+            ),
+        }
+    }
+}
+
+fn write_signature(f: &mut Formatter<'_>, signature: &Option<Vec<CqlDataType>>) -> Result {
+    match signature {
+        None => Ok(()),
+        Some(data_types) => write!(f, "({})", Joined(data_types, ", ")),
+    }
+}
+
+// other statements
+
+impl Display for DescribeStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "DESCRIBE {}", self.target)?;
+        if self.with_internals {
+            f.write_str(" WITH INTERNALS")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for DescribeTarget {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            DescribeTarget::Aggregate {
+                keyspace_name,
+                aggregate_name,
+            } => write!(f, "AGGREGATE {}", Qualified(keyspace_name, aggregate_name)),
+            DescribeTarget::Aggregates => f.write_str("AGGREGATES"),
+            DescribeTarget::Cluster => f.write_str("CLUSTER"),
+            DescribeTarget::Function {
+                keyspace_name,
+                function_name,
+            } => write!(f, "FUNCTION {}", Qualified(keyspace_name, function_name)),
+            DescribeTarget::Functions => f.write_str("FUNCTIONS"),
+            DescribeTarget::Index {
+                keyspace_name,
+                index_name,
+            } => write!(f, "INDEX {}", Qualified(keyspace_name, index_name)),
+            DescribeTarget::Keyspace {
+                only,
+                keyspace_name,
+            } => {
+                if *only {
+                    f.write_str("ONLY ")?;
+                }
+                f.write_str("KEYSPACE")?;
+                if let Some(keyspace_name) = keyspace_name {
+                    write!(f, " {keyspace_name}")?;
+                }
+                Ok(())
+            }
+            DescribeTarget::Keyspaces => f.write_str("KEYSPACES"),
+            DescribeTarget::MaterializedView {
+                keyspace_name,
+                view_name,
+            } => write!(
+                f,
+                "MATERIALIZED VIEW {}",
+                Qualified(keyspace_name, view_name)
+            ),
+            DescribeTarget::Object {
+                keyspace_name,
+                object_name,
+            } => Qualified(keyspace_name, object_name).fmt(f),
+            DescribeTarget::Schema { full } => {
+                f.write_str(if *full { "FULL SCHEMA" } else { "SCHEMA" })
+            }
+            DescribeTarget::Table {
+                keyspace_name,
+                table_name,
+            } => write!(f, "TABLE {}", Qualified(keyspace_name, table_name)),
+            DescribeTarget::Tables => f.write_str("TABLES"),
+            DescribeTarget::Type {
+                keyspace_name,
+                type_name,
+            } => write!(f, "TYPE {}", Qualified(keyspace_name, type_name)),
+            DescribeTarget::Types => f.write_str("TYPES"),
+        }
+    }
+}
+
+impl Display for TruncateStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "TRUNCATE {}",
+            Qualified(&self.keyspace_name, &self.table_name)
+        )
+    }
+}
+
+impl Display for UseStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "USE {}", self.keyspace_name)
+    }
+}
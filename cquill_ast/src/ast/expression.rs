@@ -0,0 +1,99 @@
+use crate::ast::{StringView, TokenView};
+
+/// A CQL term such as a literal, bind marker, function call or arithmetic operation used as a
+/// value in DML statements, where clauses and conditions.
+#[derive(Debug, PartialEq)]
+pub enum ExpressionTerm {
+    /// Binary arithmetic such as `int_column + 1` or `list_column - ['big data!']`.
+    Arithmetic {
+        left: Box<ExpressionTerm>,
+        operator: ArithmeticOperator,
+        right: Box<ExpressionTerm>,
+    },
+    BindMarker(BindMarker),
+    /// Hex blob constant such as `0xcafe`.
+    Blob(TokenView),
+    Boolean(bool),
+    /// Reference to a column's current value such as `int_column` in `int_column = int_column + 1`.
+    Column(TokenView),
+    /// Duration constant such as `1h30m` or ISO 8601 `P1DT2H`.
+    Duration(TokenView),
+    FunctionCall(FunctionCall),
+    /// `[term, term, ...]` literal for lists and vectors.
+    List(Vec<ExpressionTerm>),
+    /// `{term: term, ...}` literal where `{}` is an empty map.
+    Map(Vec<(ExpressionTerm, ExpressionTerm)>),
+    /// Unary minus such as `-1`.
+    Negated(Box<ExpressionTerm>),
+    Null,
+    Number(TokenView),
+    /// `{term, term, ...}` literal.
+    Set(Vec<ExpressionTerm>),
+    String(StringView),
+    /// `(term, term, ...)` literal for tuples and the values of an `IN` relation.
+    Tuple(Vec<ExpressionTerm>),
+    /// `{field_name: term, ...}` literal.
+    UserDefinedType(Vec<(TokenView, ExpressionTerm)>),
+    Uuid(TokenView),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ArithmeticOperator {
+    Add,
+    Divide,
+    Modulo,
+    Multiply,
+    Subtract,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BindMarker {
+    /// `?`
+    Anonymous,
+    /// `:name`
+    Named(TokenView),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct FunctionCall {
+    pub keyspace_name: Option<TokenView>,
+    pub function_name: TokenView,
+    pub args: Vec<ExpressionTerm>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WhereClause {
+    pub relations: Vec<WhereClauseRelation>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WhereClauseRelation {
+    pub columns: RelationColumns,
+    pub operator: RelationOperator,
+    pub expr_term: ExpressionTerm,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RelationColumns {
+    /// `column_name`
+    Single(TokenView),
+    /// `(column_name1, column_name2)` for multi-column relations on clustering columns.
+    Tuple(Vec<TokenView>),
+    /// `token(column_name1, column_name2)` for relations on the partition key's token.
+    Token(Vec<TokenView>),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RelationOperator {
+    Contains,
+    ContainsKey,
+    Equal,
+    GreaterThan,
+    GreaterThanEqual,
+    In,
+    /// `IS NOT` of an `IS NOT NULL` relation restricting the columns of a materialized view.
+    IsNot,
+    LessThan,
+    LessThanEqual,
+    NotEqual,
+}
@@ -0,0 +1,31 @@
+use crate::ast::{ExpressionTerm, TokenView, UpdateParameter};
+
+#[derive(Debug, PartialEq)]
+pub struct InsertStatement {
+    pub keyspace_name: Option<TokenView>,
+    pub table_name: TokenView,
+    pub values: InsertValues,
+    pub if_not_exists: bool,
+    pub using: Vec<UpdateParameter>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum InsertValues {
+    /// `JSON 'json_object' [DEFAULT NULL | DEFAULT UNSET]`
+    Json {
+        json: ExpressionTerm,
+        default: Option<JsonDefault>,
+    },
+    /// `(column_name, ...) VALUES (term, ...)`
+    Values {
+        column_names: Vec<TokenView>,
+        values: Vec<ExpressionTerm>,
+    },
+}
+
+/// Behavior for columns omitted from an `INSERT JSON` statement's json object.
+#[derive(Debug, PartialEq)]
+pub enum JsonDefault {
+    Null,
+    Unset,
+}
@@ -1,24 +1,48 @@
+mod alter;
+mod batch;
 mod create;
+mod delete;
+mod describe;
+mod display;
 mod drop;
+mod expression;
+mod insert;
+mod select;
 mod table;
 mod token;
+mod truncate;
 mod update;
+mod use_keyspace;
 
 #[cfg(test)]
 mod token_test;
 
+pub use alter::*;
+pub use batch::*;
 pub use create::*;
+pub use delete::*;
+pub use describe::*;
 pub use drop::*;
+pub use expression::*;
+pub use insert::*;
+pub use select::*;
 pub use table::*;
 pub use token::*;
+pub use truncate::*;
 pub use update::*;
+pub use use_keyspace::*;
 
 #[derive(Debug, PartialEq)]
 pub enum CqlStatement {
+    Alter(AlterStatement),
+    Batch(BatchStatement),
     Create(CreateStatement),
-    Delete,
+    Delete(DeleteStatement),
+    Describe(DescribeStatement),
     Drop(DropStatement),
-    Insert,
-    Select,
+    Insert(InsertStatement),
+    Select(SelectStatement),
+    Truncate(TruncateStatement),
     Update(UpdateStatement),
+    Use(UseStatement),
 }
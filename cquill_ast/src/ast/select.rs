@@ -0,0 +1,43 @@
+use crate::ast::{ClusteringOrderDefinition, CqlDataType, ExpressionTerm, TokenView, WhereClause};
+
+#[derive(Debug, PartialEq)]
+pub struct SelectStatement {
+    pub json: bool,
+    pub distinct: bool,
+    pub select_clause: SelectClause,
+    pub keyspace_name: Option<TokenView>,
+    pub table_name: TokenView,
+    pub where_clause: Option<WhereClause>,
+    pub group_by: Option<Vec<TokenView>>,
+    pub order_by: Option<Vec<ClusteringOrderDefinition>>,
+    pub per_partition_limit: Option<ExpressionTerm>,
+    pub limit: Option<ExpressionTerm>,
+    pub allow_filtering: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SelectClause {
+    /// `SELECT *`
+    All,
+    Selectors(Vec<SelectorDefinition>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct SelectorDefinition {
+    pub selector: Selector,
+    /// Specified with `AS alias`.
+    pub alias: Option<TokenView>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Selector {
+    /// `CAST(selector AS data_type)`
+    Cast {
+        selector: Box<Selector>,
+        data_type: CqlDataType,
+    },
+    /// `COUNT(*)`
+    CountAll,
+    /// Columns, literals and function calls.
+    Term(ExpressionTerm),
+}
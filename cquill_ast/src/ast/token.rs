@@ -3,12 +3,6 @@ use std::sync::Arc;
 
 use crate::lex::Token;
 
-// todo support vector
-// todo collections cannot be nested
-//  can a tuple be nested in a collection such as `map<text, tuple<int, text>>`?
-// todo frozen can be around a collection such as `frozen<map<text, someUDT>>`
-// todo frozen can be around a UDT such as frozen<someUDT>
-// todo frozen can be in a collection such as `map<text, frozen<someUDT>>`
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub enum CqlDataType {
     CollectionType(CqlCollectionType),
@@ -17,13 +11,16 @@ pub enum CqlDataType {
     Frozen(Box<CqlDataType>),
     /// Composite of native type and user defined types.
     ValueType(CqlValueType),
-    Tuple(Box<CqlDataType>, Box<CqlDataType>),
+    Tuple(Vec<CqlDataType>),
+    /// A fixed-length `vector<float, 768>` of an element type and dimension added in Cassandra 5.
+    Vector(Box<CqlDataType>, TokenView),
 }
 
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub enum CqlValueType {
     NativeType(CqlNativeType),
-    UserDefinedType(TokenView),
+    /// A user defined type name with its optional keyspace qualifier.
+    UserDefinedType(Option<TokenView>, TokenView),
 }
 
 #[derive(Debug, Eq, Hash, PartialEq)]
@@ -51,13 +48,11 @@ pub enum CqlNativeType {
     VarInt,
 }
 
-// todo should CqlValueType for collection generics be CqlDataType
-// todo are collection generics optional
 #[derive(Debug, Eq, Hash, PartialEq)]
 pub enum CqlCollectionType {
-    List(CqlValueType),
-    Map(CqlValueType, CqlValueType),
-    Set(CqlValueType),
+    List(Box<CqlDataType>),
+    Map(Box<CqlDataType>, Box<CqlDataType>),
+    Set(Box<CqlDataType>),
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -104,6 +99,14 @@ impl TokenView {
         }
     }
 
+    /// Creates a view of a non-empty value that is not from parsed CQL for rewriting an identifier.
+    pub fn from_value(value: &str) -> Self {
+        Self {
+            cql: Arc::new(value.to_string()),
+            range: TokenRange::new(0, value.len() - 1),
+        }
+    }
+
     pub fn value(&self) -> String {
         String::from(&self.cql[self.range.begin()..=self.range.end()])
     }
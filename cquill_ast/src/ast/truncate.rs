@@ -0,0 +1,7 @@
+use crate::ast::TokenView;
+
+#[derive(Debug, PartialEq)]
+pub struct TruncateStatement {
+    pub keyspace_name: Option<TokenView>,
+    pub table_name: TokenView,
+}
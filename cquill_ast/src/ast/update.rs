@@ -1,14 +1,18 @@
-use crate::ast::{StringView, TokenView};
+use crate::ast::{ExpressionTerm, RelationOperator, TokenView, WhereClause};
 
 #[derive(Debug, PartialEq)]
 pub struct UpdateStatement {
+    pub keyspace_name: Option<TokenView>,
     pub table_name: TokenView,
+    pub using: Vec<UpdateParameter>,
     pub assignments: Vec<Assignment>,
     pub where_clause: WhereClause,
     pub if_behavior: Option<UpdateIfBehavior>,
 }
 
-// todo operator
+/// An assignment of `SET` such as `int_column = 1`, `int_column = int_column + 1` or
+/// `map_column['key'] = 'big data!'` where an operation on the column's current value is
+/// represented with [ExpressionTerm::Arithmetic].
 #[derive(Debug, PartialEq)]
 pub struct Assignment {
     pub selection: AssignmentSelection,
@@ -30,23 +34,11 @@ pub enum AssignmentSelection {
     },
 }
 
+/// Parameters of a `USING` clause for `INSERT`, `UPDATE`, `DELETE` and `BATCH` statements.
 #[derive(Debug, PartialEq)]
-pub enum ExpressionTerm {
-    Number(TokenView),
-    String(StringView),
-}
-
-#[derive(Debug, PartialEq)]
-pub struct WhereClause {
-    pub relations: Vec<WhereClauseRelation>,
-}
-
-#[derive(Debug, PartialEq)]
-pub struct WhereClauseRelation {
-    pub column_name: TokenView,
-    // todo TokenView does not have TokenName to resolve kind of operator
-    // pub operator: TokenView,
-    pub expr_term: ExpressionTerm,
+pub enum UpdateParameter {
+    Timestamp(ExpressionTerm),
+    Ttl(ExpressionTerm),
 }
 
 #[derive(Debug, PartialEq)]
@@ -55,9 +47,9 @@ pub enum UpdateIfBehavior {
     Exists,
 }
 
-// todo operator
 #[derive(Debug, PartialEq)]
 pub struct UpdateIfCondition {
     pub selection: AssignmentSelection,
+    pub operator: RelationOperator,
     pub expr_term: ExpressionTerm,
 }
@@ -0,0 +1,43 @@
+use crate::ast::{CqlStatement, TokenView};
+
+#[derive(Debug, PartialEq)]
+pub struct UseStatement {
+    pub keyspace_name: TokenView,
+}
+
+/// Tracks the keyspace selected by `USE` statements while iterating over parsed statements so
+/// unqualified object names such as `create table big_data_table` can be resolved to the
+/// keyspace they will be created in.
+#[derive(Clone, Debug, Default)]
+pub struct KeyspaceScope {
+    keyspace_name: Option<String>,
+}
+
+impl KeyspaceScope {
+    /// Starts with a keyspace already in use, as with a session created for a keyspace.
+    pub fn new(keyspace_name: Option<String>) -> Self {
+        Self { keyspace_name }
+    }
+
+    /// The keyspace selected by the most recent `USE` statement.
+    pub fn current(&self) -> Option<&String> {
+        self.keyspace_name.as_ref()
+    }
+
+    /// Updates the current keyspace if the statement is a `USE` statement.
+    pub fn update(&mut self, statement: &CqlStatement) {
+        if let CqlStatement::Use(use_statement) = statement {
+            self.keyspace_name = Some(use_statement.keyspace_name.value());
+        }
+    }
+
+    /// Resolves the keyspace of an object name by preferring the explicit keyspace of a
+    /// qualified name such as `big_data_keyspace.big_data_table` and falling back to the
+    /// keyspace in use.
+    pub fn resolve(&self, keyspace_name: &Option<TokenView>) -> Option<String> {
+        match keyspace_name {
+            Some(keyspace_name) => Some(keyspace_name.value()),
+            None => self.keyspace_name.clone(),
+        }
+    }
+}
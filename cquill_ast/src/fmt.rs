@@ -0,0 +1,137 @@
+use crate::lex::{Token, TokenName, Tokenizer};
+use crate::parser::{ParseError, ParseResult, parse_statement, skip_statement};
+use std::sync::Arc;
+
+/// Rewrites each statement of a CQL script in its canonical form, terminated by a semicolon.
+///
+/// Text between statements is kept as written. Statements that contain comments or that cannot
+/// be parsed are kept as written, so formatting never discards content from the source.
+pub fn format_cql(cql: &str) -> ParseResult<String> {
+    let tokens = Tokenizer::new(cql)
+        .tokenize()
+        .map_err(|_| ParseError::InvalidCql)?;
+    let cql = Arc::new(cql.to_string());
+    let mut formatted = String::with_capacity(cql.len());
+    let mut cursor = 0;
+    let mut i = 0;
+    while i < tokens.len() {
+        let mut iter = tokens[i..].iter().peekable();
+        let parsed = parse_statement(&cql, &mut iter);
+        let end = tokens.len() - iter.len();
+        match parsed {
+            Ok(statement)
+                if end == tokens.len() || matches!(tokens[end].name, TokenName::Semicolon) =>
+            {
+                let statement_tokens = &tokens[i..end];
+                formatted.push_str(&cql[cursor..tokens[i].range.begin()]);
+                if has_commentary(&cql, statement_tokens) {
+                    let last = &statement_tokens[statement_tokens.len() - 1];
+                    formatted.push_str(&cql[tokens[i].range.begin()..=last.range.end()]);
+                } else {
+                    formatted.push_str(&statement.to_string());
+                }
+                formatted.push(';');
+                cursor = match tokens.get(end) {
+                    Some(semicolon) => semicolon.range.end() + 1,
+                    None => statement_tokens[statement_tokens.len() - 1].range.end() + 1,
+                };
+                i = end + 1;
+            }
+            _ => i = skip_statement(&tokens, i),
+        }
+    }
+    formatted.push_str(&cql[cursor..]);
+    Ok(formatted)
+}
+
+/// Renders the tokens of a CQL script separated by single spaces with uppercase keywords, which is
+/// the same for scripts that differ only by comments, whitespace or the casing of keywords.
+/// Template placeholders such as `${keyspace}` are rendered verbatim as a single token.
+pub fn normalize_cql(cql: &str) -> ParseResult<String> {
+    let tokens = Tokenizer::new(cql)
+        .tokenize()
+        .map_err(|_| ParseError::InvalidCql)?;
+    let placeholders = template_placeholders(cql);
+    let mut normalized: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut rendered_placeholder = None;
+    for token in &tokens {
+        let begin = token.range.begin();
+        if let Some(&(placeholder_begin, placeholder_end)) =
+            placeholders
+                .iter()
+                .find(|(placeholder_begin, placeholder_end)| {
+                    (*placeholder_begin..*placeholder_end).contains(&begin)
+                })
+        {
+            if rendered_placeholder != Some(placeholder_begin) {
+                normalized.push(cql[placeholder_begin..placeholder_end].to_string());
+                rendered_placeholder = Some(placeholder_begin);
+            }
+            continue;
+        }
+        let text = &cql[begin..=token.range.end()];
+        normalized.push(match token.name {
+            TokenName::BlobLiteral
+            | TokenName::Identifier
+            | TokenName::NumberLiteral
+            | TokenName::StringLiteral(_)
+            | TokenName::UuidLiteral => text.to_string(),
+            _ => text.to_uppercase(),
+        });
+    }
+    Ok(normalized.join(" "))
+}
+
+/// Byte ranges of the `${name}` and `{{ name }}` template placeholders of a CQL script.
+fn template_placeholders(cql: &str) -> Vec<(usize, usize)> {
+    let bytes = cql.as_bytes();
+    let name_end = |mut i: usize| {
+        if i < bytes.len() && (bytes[i].is_ascii_alphabetic() || bytes[i] == b'_') {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Some(i)
+        } else {
+            None
+        }
+    };
+    let whitespace_end = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        i
+    };
+    let mut placeholders = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = if bytes[i..].starts_with(b"${") {
+            name_end(i + 2)
+                .filter(|&end| bytes.get(end) == Some(&b'}'))
+                .map(|end| end + 1)
+        } else if bytes[i..].starts_with(b"{{") {
+            name_end(whitespace_end(i + 2))
+                .map(whitespace_end)
+                .filter(|&end| bytes[end..].starts_with(b"}}"))
+                .map(|end| end + 2)
+        } else {
+            None
+        };
+        match end {
+            Some(end) => {
+                placeholders.push((i, end));
+                i = end;
+            }
+            None => i += 1,
+        }
+    }
+    placeholders
+}
+
+/// Checks whether anything other than whitespace sits between the tokens of a statement.
+fn has_commentary(cql: &str, tokens: &[Token]) -> bool {
+    tokens.windows(2).any(|pair| {
+        !cql[pair[0].range.end() + 1..pair[1].range.begin()]
+            .trim()
+            .is_empty()
+    })
+}
@@ -0,0 +1,228 @@
+use crate::format_cql;
+use crate::normalize_cql;
+use crate::parse_cql;
+use crate::test_cql::*;
+
+#[test]
+fn test_format_cql_is_idempotent() {
+    for cql in [
+        USE_KEYSPACE_THEN_CREATE_TABLE,
+        CREATE_KEYSPACE_WITH_NETWORK_REPLICATION,
+        CREATE_KEYSPACE_WITH_DURABLE_WRITES_FALSE,
+        CREATE_TABLE_WITH_NESTED_DATA_TYPES,
+        CREATE_TABLE_WITH_COMPOSITE_PARTITION_PRIMARY_KEY,
+        CREATE_TABLE_WITH_COMMENT_AND_COMPACT_STORAGE,
+        CREATE_TABLE_WITH_MULTIPLE_CLUSTERING_ORDERS,
+        CREATE_TABLE_WITH_TABLE_OPTIONS,
+        CREATE_MATERIALIZED_VIEW_WITH_OPTIONS,
+        CREATE_MATERIALIZED_VIEW_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_FUNCTION_CALLED_ON_NULL_AS_DOLLAR_DOLLAR,
+        CREATE_AGGREGATE_WITH_FINALFUNC_AND_INITCOND,
+        CREATE_EXPLICIT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES,
+        CREATE_ROLE_WITH_OPTIONS_MAP,
+        CREATE_USER_WITH_HASHED_PASSWORD_SUPERUSER,
+        CREATE_INDEX_ON_ENTRIES,
+        CREATE_TRIGGER_EXPLICIT_KEYSPACE,
+        ALTER_KEYSPACE_WITH_REPLICATION,
+        ALTER_TABLE_ADD_MULTIPLE_COLUMNS,
+        ALTER_TABLE_DROP_MULTIPLE_COLUMNS,
+        ALTER_TABLE_RENAME_MULTIPLE_COLUMNS,
+        ALTER_UDT_RENAME_MULTIPLE_FIELDS,
+        ALTER_MATERIALIZED_VIEW_IF_EXISTS,
+        ALTER_ROLE_WITH_MULTIPLE_ROLE_OPTIONS,
+        ALTER_USER_WITH_PASSWORD_SUPERUSER,
+        DROP_FUNCTION_WITH_EXPLICIT_KEYSPACE_AND_MULTIPLE_ARGS,
+        DROP_TRIGGER_EXPLICIT_KEYSPACE_IF_EXISTS,
+        DESCRIBE_KEYSPACE_WITH_INTERNALS,
+        SELECT_WHERE_IN_TUPLE,
+        SELECT_WHERE_TOKEN,
+        SELECT_BIND_MARKERS,
+        INSERT_COLLECTION_LITERALS,
+        INSERT_JSON_DEFAULT_UNSET,
+        INSERT_UDT_LITERAL,
+        UPDATE_EXPLICIT_KEYSPACE,
+        UPDATE_COLLECTION_ELEMENT,
+        DELETE_IF_CONDITION,
+        COUNTER_BATCH,
+        BATCH_USING_TIMESTAMP,
+    ] {
+        let formatted = format_cql(cql).unwrap();
+        assert_eq!(formatted, format_cql(formatted.as_str()).unwrap());
+        assert_eq!(
+            parse_cql(cql.to_string()).unwrap().len(),
+            parse_cql(formatted).unwrap().len()
+        );
+    }
+}
+
+#[test]
+fn test_format_cql_create_table() {
+    assert_eq!(
+        format_cql(CREATE_TABLE_WITH_MULTIPLE_CLUSTERING_ORDERS).unwrap(),
+        "\
+CREATE TABLE big_data_table (
+    text_column text,
+    uuid_column uuid,
+    time_column timestamp,
+    PRIMARY KEY (text_column, time_column, uuid_column)
+) WITH CLUSTERING ORDER BY (time_column DESC, uuid_column ASC);
+"
+    );
+}
+
+#[test]
+fn test_format_cql_create_materialized_view() {
+    assert_eq!(
+        format_cql(CREATE_MATERIALIZED_VIEW_WITH_OPTIONS).unwrap(),
+        "\
+CREATE MATERIALIZED VIEW big_data_view AS
+    SELECT * FROM big_data_table
+    WHERE int_column > 4
+    PRIMARY KEY (int_column)
+    WITH comment = 'comment ca va';
+"
+    );
+}
+
+#[test]
+fn test_format_cql_alter_table() {
+    assert_eq!(
+        format_cql(ALTER_TABLE_RENAME_MULTIPLE_COLUMNS).unwrap(),
+        "ALTER TABLE big_data_table RENAME text_column TO text_col AND uuid_column TO uuid_col;\n"
+    );
+    assert_eq!(
+        format_cql(
+            "alter table big_data_table with default_time_to_live = 0 and comment = 'big data!';"
+        )
+        .unwrap(),
+        "ALTER TABLE big_data_table WITH default_time_to_live = 0\n    AND comment = 'big data!';"
+    );
+}
+
+#[test]
+fn test_format_cql_alter_table_multiple_columns() {
+    for (cql, formatted) in [
+        (
+            ALTER_TABLE_ADD_MULTIPLE_COLUMNS,
+            "ALTER TABLE big_data_table ADD (text_column text, uuid_column uuid);\n",
+        ),
+        (
+            ALTER_TABLE_ADD_PARENTHESIZED_COLUMNS,
+            "ALTER TABLE big_data_table ADD (text_column text, uuid_column uuid);\n",
+        ),
+        (
+            ALTER_TABLE_DROP_MULTIPLE_COLUMNS,
+            "ALTER TABLE big_data_table DROP (text_column, uuid_column);\n",
+        ),
+        (
+            ALTER_TABLE_DROP_PARENTHESIZED_COLUMNS,
+            "ALTER TABLE big_data_table DROP (text_column, uuid_column);\n",
+        ),
+        (
+            ALTER_TABLE_DROP_COLUMN,
+            "ALTER TABLE big_data_table DROP text_column;\n",
+        ),
+    ] {
+        assert_eq!(format_cql(cql).unwrap(), formatted);
+        let rendered = parse_cql(cql.to_string()).unwrap().remove(0).to_string();
+        assert_eq!(
+            parse_cql(rendered.clone()).unwrap().remove(0).to_string(),
+            rendered
+        );
+    }
+}
+
+#[test]
+fn test_format_cql_batch() {
+    assert_eq!(
+        format_cql(COUNTER_BATCH).unwrap(),
+        "\
+BEGIN COUNTER BATCH
+    UPDATE big_data_table SET count_col = count_col + 1 WHERE text_col = 'big data!';
+    DELETE FROM big_data_table WHERE text_col = 'more data!';
+APPLY BATCH;
+"
+    );
+}
+
+#[test]
+fn test_format_cql_keyspace_replication_is_sorted() {
+    assert_eq!(
+        format_cql(
+            "create keyspace ks with replication = {'class': 'NetworkTopologyStrategy', 'dc2': 1, 'dc1': 3}"
+        )
+        .unwrap(),
+        "CREATE KEYSPACE ks WITH replication = {'class': 'NetworkTopologyStrategy', 'dc1': 3, 'dc2': 1};"
+    );
+}
+
+#[test]
+fn test_format_cql_arithmetic_parentheses() {
+    assert_eq!(
+        format_cql("update t set a = (a + 1) * 2, b = b - (c - 1), c = (c * 2) + 1 where k = 1;")
+            .unwrap(),
+        "UPDATE t SET a = (a + 1) * 2, b = b - (c - 1), c = (c * 2) + 1 WHERE k = 1;"
+    );
+}
+
+#[test]
+fn test_format_cql_keeps_text_between_statements() {
+    assert_eq!(
+        format_cql("-- the first table\ncreate table t (k int primary key);\n\n// a keyspace switch\nuse ks;\n").unwrap(),
+        "-- the first table\nCREATE TABLE t (\n    k int PRIMARY KEY\n);\n\n// a keyspace switch\nUSE ks;\n"
+    );
+}
+
+#[test]
+fn test_format_cql_keeps_statement_with_comment() {
+    let cql = "create table t (\n    k int primary key, -- the key\n    v text\n);\nuse ks;";
+    assert_eq!(
+        format_cql(cql).unwrap(),
+        "create table t (\n    k int primary key, -- the key\n    v text\n);\nUSE ks;"
+    );
+}
+
+#[test]
+fn test_format_cql_keeps_unsupported_statement() {
+    assert_eq!(format_cql(BATCH_UNLOGGED).unwrap(), BATCH_UNLOGGED);
+    assert_eq!(
+        format_cql("grant select on keyspace ks to r;\nuse ks;").unwrap(),
+        "grant select on keyspace ks to r;\nUSE ks;"
+    );
+}
+
+#[test]
+fn test_format_cql_terminates_last_statement() {
+    assert_eq!(format_cql("use ks").unwrap(), "USE ks;");
+}
+
+#[test]
+fn test_normalize_cql_ignores_comments_whitespace_and_keyword_casing() {
+    let normalized = normalize_cql(
+        "create table api.Accounts (id uuid primary key, name text);\nINSERT INTO api.Accounts (id, name) VALUES (uuid(), 'Ada Lovelace');",
+    )
+    .unwrap();
+    assert_eq!(
+        normalized,
+        "CREATE TABLE api . Accounts ( id UUID PRIMARY KEY , name TEXT ) ; INSERT INTO api . Accounts ( id , name ) VALUES ( UUID ( ) , 'Ada Lovelace' ) ;"
+    );
+    assert_eq!(
+        normalize_cql(
+            "-- accounts\nCREATE TABLE api.Accounts (\n    id uuid PRIMARY KEY, /* display name */ name text\n);\n\ninsert into api.Accounts (id, name)\n    values (uuid(), 'Ada Lovelace');\n"
+        )
+        .unwrap(),
+        normalized
+    );
+}
+
+#[test]
+fn test_normalize_cql_keeps_template_placeholders() {
+    assert_eq!(
+        normalize_cql("create table ${keyspace}.accounts (id uuid primary key);").unwrap(),
+        "CREATE TABLE ${keyspace} . accounts ( id UUID PRIMARY KEY ) ;"
+    );
+    assert_eq!(
+        normalize_cql("create table {{ keyspace }}.accounts (id uuid primary key);").unwrap(),
+        "CREATE TABLE {{ keyspace }} . accounts ( id UUID PRIMARY KEY ) ;"
+    );
+}
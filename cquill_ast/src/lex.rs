@@ -10,19 +10,16 @@ pub enum TokenName {
     RightCurvedBracket,
     LeftParenthesis,
     RightParenthesis,
-    LeftSquareBracket,  // todo test lex
-    RightSquareBracket, // todo test lex
+    LeftSquareBracket,
+    RightSquareBracket,
     Comma,
     Semicolon,
     Colon,
     Dot,
     Star,
-    #[allow(unused)]
-    Divide, // todo impl
-    #[allow(unused)]
-    Modulus, // todo impl
-    #[allow(unused)]
-    Plus, // todo impl
+    Divide,
+    Modulus,
+    Plus,
     Minus,
     #[allow(unused)]
     DoubleMinus, // todo impl
@@ -32,6 +29,7 @@ pub enum TokenName {
     GreaterThan,
     LessThanEqual,
     GreaterThanEqual,
+    QuestionMark,
 
     AccessKeyword,
     AddKeyword,
@@ -93,6 +91,7 @@ pub enum TokenName {
     InsertKeyword,
     IntKeyword,
     IntoKeyword,
+    IsKeyword,
     JsonKeyword,
     KeyKeyword,
     KeyspaceKeyword,
@@ -243,6 +242,7 @@ impl TokenName {
             "insert" => InsertKeyword,
             "int" => IntKeyword,
             "into" => IntoKeyword,
+            "is" => IsKeyword,
             "json" => JsonKeyword,
             "key" => KeyKeyword,
             "keys" => KeysKeyword,
@@ -322,7 +322,6 @@ impl TokenName {
 
 #[derive(Debug)]
 pub struct Token {
-    #[allow(unused)]
     pub line: usize,
     pub name: TokenName,
     pub range: TokenRange,
@@ -391,6 +390,7 @@ impl<'a> Tokenizer<'a> {
                     if self.match_next("/") {
                         self.current.next_char();
                         self.skip_until_line_term();
+                        None
                     } else if self.match_next("*") {
                         self.current.next_char();
                         loop {
@@ -398,6 +398,7 @@ impl<'a> Tokenizer<'a> {
                                 None => break,
                                 Some(s) => {
                                     if s == "*" && self.match_next_nth(1, "/") {
+                                        self.current.next_char();
                                         self.current.next_char();
                                         break;
                                     } else {
@@ -409,8 +410,10 @@ impl<'a> Tokenizer<'a> {
                                 }
                             }
                         }
+                        None
+                    } else {
+                        Some(Divide)
                     }
-                    None
                 }
                 "." => Some(Dot),
                 "=" => Some(Equal),
@@ -426,6 +429,9 @@ impl<'a> Tokenizer<'a> {
                 "," => Some(Comma),
                 ";" => Some(Semicolon),
                 "*" => Some(Star),
+                "+" => Some(Plus),
+                "%" => Some(Modulus),
+                "?" => Some(QuestionMark),
                 "<" => {
                     if self.match_next("=") {
                         self.advance();
@@ -447,6 +453,10 @@ impl<'a> Tokenizer<'a> {
                     Ok(name) => Some(name),
                     Err(err) => return Err(err),
                 },
+                "\"" => match self.quoted_identifier() {
+                    Ok(name) => Some(name),
+                    Err(err) => return Err(err),
+                },
                 &_ => self.constant_or_identifier_or_keyword().ok(),
             };
             if let Some(name) = maybe_name {
@@ -505,6 +515,33 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    /// Lexes a case-sensitive identifier enclosed in double quotes, escaping double quotes with
+    /// successive double quotes such as `"Big""Data"`.
+    fn quoted_identifier(&mut self) -> Result<TokenName, anyhow::Error> {
+        let mut escaped_double_quote = false;
+        loop {
+            match self.peek() {
+                None => return Err(anyhow!("unclosed quoted identifier")),
+                Some(c) => {
+                    if c == "\n" {
+                        self.line += 1;
+                    }
+                    self.advance();
+                    if c == "\"" {
+                        if escaped_double_quote {
+                            escaped_double_quote = false;
+                        } else if self.match_next("\"") {
+                            escaped_double_quote = true;
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Identifier)
+    }
+
     fn dollar_sign_string(&mut self) -> Option<TokenName> {
         if self.match_next("$") {
             self.advance();
@@ -527,6 +564,9 @@ impl<'a> Tokenizer<'a> {
     }
 
     fn constant_or_identifier_or_keyword(&mut self) -> Result<TokenName, ()> {
+        if let Some(name) = self.exponent_number() {
+            return Ok(name);
+        }
         if self.splice() == "0" {
             match self.blob() {
                 Ok(maybe_name) => {
@@ -543,6 +583,16 @@ impl<'a> Tokenizer<'a> {
         let mut has_decimal = false;
         let mut has_underscore = false;
         let mut line_commented_started = false;
+        for c in self.splice().chars() {
+            if c == '_' {
+                has_underscore = true;
+            } else if !c.is_ascii_digit() {
+                only_digit = false;
+                if !c.is_ascii_hexdigit() {
+                    only_hex = false;
+                }
+            }
+        }
         loop {
             if let Some(s) = self.peek() {
                 let mut advance = true;
@@ -591,6 +641,45 @@ impl<'a> Tokenizer<'a> {
         }
     }
 
+    /// Lexes a float with an exponent such as `1.5e10`, `2E-3` or `1e+6`, which is not a number
+    /// when followed by the characters of identifiers and uuids such as `123e4567-e89b-...`.
+    fn exponent_number(&mut self) -> Option<TokenName> {
+        let bytes = self.cql.as_bytes();
+        let digits = |i: &mut usize| {
+            let begin = *i;
+            while *i < bytes.len() && bytes[*i].is_ascii_digit() {
+                *i += 1;
+            }
+            *i > begin
+        };
+        let mut i = self.current.begin();
+        if !digits(&mut i) {
+            return None;
+        }
+        if bytes.get(i) == Some(&b'.') {
+            i += 1;
+            digits(&mut i);
+        }
+        if !matches!(bytes.get(i), Some(b'e' | b'E')) {
+            return None;
+        }
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        if !digits(&mut i)
+            || bytes
+                .get(i)
+                .is_some_and(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'))
+        {
+            return None;
+        }
+        while self.current.end() + 1 < i {
+            self.advance();
+        }
+        Some(NumberLiteral)
+    }
+
     fn blob(&mut self) -> Result<Option<TokenName>, ()> {
         if !self.peek().is_some_and(|c| c.eq_ignore_ascii_case("x")) {
             Ok(None)
@@ -602,7 +691,9 @@ impl<'a> Tokenizer<'a> {
                     let mut advance = false;
                     for c in s.chars() {
                         advance = c.is_ascii_alphanumeric();
-                        hex = hex && c.is_ascii_hexdigit();
+                        if advance {
+                            hex = hex && c.is_ascii_hexdigit();
+                        }
                     }
                     if advance && self.advance().is_some() {
                         continue;
@@ -24,6 +24,11 @@ mod lexing_errors {
         let cql = "CREATE KEYSPACE some_keyspace WITH replication = {'class': 'NetworkTopologyStrategy', 'DC1' : '3/1'', 'DC2' : '5/2'}";
         tokenize_expect_err(cql);
     }
+
+    #[test]
+    fn test_unclosed_quoted_identifier() {
+        tokenize_expect_err("DROP TABLE big_data_keyspace.\"BigData;");
+    }
 }
 
 mod comments {
@@ -121,6 +126,19 @@ mod data_types {
         );
     }
 
+    #[test]
+    fn test_single_character_identifiers_are_not_digits() {
+        tokenize_expect(
+            "k v1 a_ 1",
+            vec![
+                (Identifier, "k"),
+                (Identifier, "v1"),
+                (Identifier, "a_"),
+                (NumberLiteral, "1"),
+            ],
+        );
+    }
+
     #[test]
     fn test_decimal_does_not_misread_keyspace_qualified_table_name() {
         tokenize_expect(
@@ -160,10 +178,53 @@ mod data_types {
         );
     }
 
+    #[test]
+    fn test_quoted_identifier() {
+        tokenize_expect(
+            "my_keyspace.\"Big\"\"Data\".select",
+            vec![
+                (Identifier, "my_keyspace"),
+                (Dot, "."),
+                (Identifier, "\"Big\"\"Data\""),
+                (Dot, "."),
+                (SelectKeyword, "select"),
+            ],
+        );
+        tokenize_expect("\"select\"", vec![(Identifier, "\"select\"")]);
+    }
+
     #[test]
     fn test_blob_literal() {
         tokenize_expect("0xaaaa", vec![(BlobLiteral, "0xaaaa")]);
         tokenize_expect("0xo", vec![]);
+        tokenize_expect(
+            "values (0xCAFE);",
+            vec![
+                (ValuesKeyword, "values"),
+                (LeftParenthesis, "("),
+                (BlobLiteral, "0xCAFE"),
+                (RightParenthesis, ")"),
+                (Semicolon, ";"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_number_literal_with_exponent() {
+        tokenize_expect("1.5e10", vec![(NumberLiteral, "1.5e10")]);
+        tokenize_expect("2E-3", vec![(NumberLiteral, "2E-3")]);
+        tokenize_expect(
+            "(1e+6)",
+            vec![
+                (LeftParenthesis, "("),
+                (NumberLiteral, "1e+6"),
+                (RightParenthesis, ")"),
+            ],
+        );
+        tokenize_expect(
+            "123e4567-e89b-12d3-a456-426614174000",
+            vec![(UuidLiteral, "123e4567-e89b-12d3-a456-426614174000")],
+        );
     }
 
     #[test]
@@ -179,6 +240,59 @@ mod data_types {
     }
 }
 
+mod operators {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_operators() {
+        tokenize_expect(
+            "1 + 2 - 3 * 4 / 5 % 6",
+            vec![
+                (NumberLiteral, "1"),
+                (Plus, "+"),
+                (NumberLiteral, "2"),
+                (Minus, "-"),
+                (NumberLiteral, "3"),
+                (Star, "*"),
+                (NumberLiteral, "4"),
+                (Divide, "/"),
+                (NumberLiteral, "5"),
+                (Modulus, "%"),
+                (NumberLiteral, "6"),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_divide_does_not_misread_comments() {
+        tokenize_expect(
+            "4/2 // commented out",
+            vec![(NumberLiteral, "4"), (Divide, "/"), (NumberLiteral, "2")],
+        );
+    }
+
+    #[test]
+    fn test_bind_markers() {
+        tokenize_expect(
+            "? :big_data",
+            vec![(QuestionMark, "?"), (Colon, ":"), (Identifier, "big_data")],
+        );
+    }
+
+    #[test]
+    fn test_collection_access() {
+        tokenize_expect(
+            "map_column['key']",
+            vec![
+                (Identifier, "map_column"),
+                (LeftSquareBracket, "["),
+                (StringLiteral(StringStyle::SingleQuote), "'key'"),
+                (RightSquareBracket, "]"),
+            ],
+        );
+    }
+}
+
 mod data_definition {
     use super::*;
 
@@ -1,13 +1,28 @@
 pub mod ast;
+mod fmt;
 mod lex;
 mod parser;
+pub mod schema;
+pub mod visit;
+pub mod visit_mut;
 
+#[cfg(test)]
+mod fmt_test;
 #[cfg(test)]
 mod lex_test;
 #[cfg(test)]
 mod sample_tests;
 #[cfg(test)]
+mod schema_diff_test;
+#[cfg(test)]
+mod schema_migration_test;
+#[cfg(test)]
+mod schema_test;
+#[cfg(test)]
 #[allow(unused)]
 mod test_cql;
+#[cfg(test)]
+mod visit_test;
 
+pub use fmt::{format_cql, normalize_cql};
 pub use parser::*;
@@ -0,0 +1,214 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::create::{
+    pop_keyspace_options, pop_role_config_attributes, pop_table_definition_attributes,
+    pop_user_password, pop_user_status,
+};
+use crate::parser::iter::{
+    pop_cql_data_type, pop_identifier, pop_keyspace_object_name, pop_next, pop_next_if,
+    pop_next_match, pop_sequence,
+};
+use crate::parser::{ParseError, ParseResult};
+use std::iter::Peekable;
+use std::slice::Iter;
+use std::sync::Arc;
+
+pub fn parse_alter_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterStatement> {
+    match iter.next() {
+        None => Err(ParseError::InvalidCql),
+        Some(token) => match token.name {
+            KeyspaceKeyword => {
+                parse_alter_keyspace_statement(cql, iter).map(AlterStatement::Keyspace)
+            }
+            MaterializedKeyword => {
+                pop_next_match(iter, ViewKeyword)?;
+                parse_alter_materialized_view_statement(cql, iter)
+                    .map(AlterStatement::MaterializedView)
+            }
+            RoleKeyword => parse_alter_role_statement(cql, iter).map(AlterStatement::Role),
+            TableKeyword => parse_alter_table_statement(cql, iter).map(AlterStatement::Table),
+            TypeKeyword => parse_alter_type_statement(cql, iter).map(AlterStatement::Type),
+            UserKeyword => parse_alter_user_statement(cql, iter).map(AlterStatement::User),
+            _ => Err(ParseError::InvalidCql),
+        },
+    }
+}
+
+fn parse_alter_keyspace_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterKeyspaceStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let keyspace_name = pop_identifier(cql, iter)?;
+    pop_next_match(iter, WithKeyword)?;
+    let (replication, durable_writes) = pop_keyspace_options(cql, iter)?;
+    Ok(AlterKeyspaceStatement {
+        if_exists,
+        keyspace_name,
+        replication,
+        durable_writes,
+    })
+}
+
+fn parse_alter_materialized_view_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterMaterializedViewStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let (keyspace_name, view_name) = pop_keyspace_object_name(cql, iter)?;
+    pop_next_match(iter, WithKeyword)?;
+    let attributes = pop_table_definition_attributes(cql, iter)?;
+    Ok(AlterMaterializedViewStatement {
+        if_exists,
+        keyspace_name,
+        view_name,
+        attributes,
+    })
+}
+
+fn parse_alter_role_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterRoleStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let role_name = pop_identifier(cql, iter)?;
+    pop_next_match(iter, WithKeyword)?;
+    let attributes = pop_role_config_attributes(cql, iter)?;
+    Ok(AlterRoleStatement {
+        if_exists,
+        role_name,
+        attributes,
+    })
+}
+
+fn parse_alter_table_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterTableStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+    let instruction = match pop_next(iter)?.name {
+        AddKeyword => {
+            let if_not_exists = pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?;
+            let parenthesized = pop_next_if(iter, LeftParenthesis).is_some();
+            let mut columns = Vec::new();
+            loop {
+                let column_name = pop_identifier(cql, iter)?;
+                let data_type = pop_cql_data_type(cql, iter)?;
+                let is_static = pop_next_if(iter, StaticKeyword).is_some();
+                columns.push(AlterTableColumn {
+                    column_name,
+                    data_type,
+                    is_static,
+                });
+                if pop_next_if(iter, Comma).is_none() {
+                    break;
+                }
+            }
+            if parenthesized {
+                pop_next_match(iter, RightParenthesis)?;
+            }
+            AlterTableInstruction::AddColumns {
+                if_not_exists,
+                columns,
+            }
+        }
+        DropKeyword => {
+            let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+            let parenthesized = pop_next_if(iter, LeftParenthesis).is_some();
+            let mut column_names = vec![pop_identifier(cql, iter)?];
+            loop {
+                _ = pop_next_if(iter, Comma);
+                match pop_next_if(iter, Identifier) {
+                    None => break,
+                    Some(popped) => column_names.push(popped.to_token_view(cql)),
+                }
+            }
+            if parenthesized {
+                pop_next_match(iter, RightParenthesis)?;
+            }
+            AlterTableInstruction::DropColumns {
+                if_exists,
+                column_names,
+            }
+        }
+        RenameKeyword => {
+            let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+            AlterTableInstruction::RenameColumns {
+                if_exists,
+                renames: pop_renames(cql, iter)?,
+            }
+        }
+        WithKeyword => AlterTableInstruction::With(pop_table_definition_attributes(cql, iter)?),
+        _ => return Err(ParseError::InvalidCql),
+    };
+    Ok(AlterTableStatement {
+        if_exists,
+        keyspace_name,
+        table_name,
+        instruction,
+    })
+}
+
+fn parse_alter_type_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterTypeStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let (keyspace_name, type_name) = pop_keyspace_object_name(cql, iter)?;
+    let instruction = match pop_next(iter)?.name {
+        AddKeyword => AlterTypeInstruction::AddField {
+            if_not_exists: pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?,
+            field_name: pop_identifier(cql, iter)?,
+            data_type: pop_cql_data_type(cql, iter)?,
+        },
+        RenameKeyword => AlterTypeInstruction::RenameFields {
+            if_exists: pop_sequence(iter, &[IfKeyword, ExistsKeyword])?,
+            renames: pop_renames(cql, iter)?,
+        },
+        _ => return Err(ParseError::InvalidCql),
+    };
+    Ok(AlterTypeStatement {
+        if_exists,
+        keyspace_name,
+        type_name,
+        instruction,
+    })
+}
+
+fn parse_alter_user_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AlterUserStatement> {
+    let if_exists = pop_sequence(iter, &[IfKeyword, ExistsKeyword])?;
+    let user_name = pop_identifier(cql, iter)?;
+    let password = pop_user_password(cql, iter)?;
+    let user_status = pop_user_status(iter)?;
+    Ok(AlterUserStatement {
+        if_exists,
+        user_name,
+        password,
+        user_status,
+    })
+}
+
+/// Pops `name TO name AND name TO name` renames of `ALTER TABLE` and `ALTER TYPE` statements.
+fn pop_renames(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Vec<(TokenView, TokenView)>> {
+    let mut renames = Vec::new();
+    loop {
+        let from = pop_identifier(cql, iter)?;
+        pop_next_match(iter, ToKeyword)?;
+        renames.push((from, pop_identifier(cql, iter)?));
+        if pop_next_if(iter, AndKeyword).is_none() {
+            break;
+        }
+    }
+    Ok(renames)
+}
@@ -0,0 +1,624 @@
+use crate::ast::*;
+use crate::parse_cql;
+use crate::parser::testing::{find_nth_token, find_string_literal, find_token};
+use crate::test_cql::*;
+use std::collections::HashMap;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_alter_keyspace_with_durable_writes() {
+    let cql = ALTER_KEYSPACE_WITH_DURABLE_WRITES;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Keyspace(
+            AlterKeyspaceStatement {
+                if_exists: false,
+                keyspace_name: find_token(cql, "big_data_keyspace"),
+                replication: None,
+                durable_writes: Some(false),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_keyspace_with_replication() {
+    let cql = ALTER_KEYSPACE_WITH_REPLICATION;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Keyspace(
+            AlterKeyspaceStatement {
+                if_exists: false,
+                keyspace_name: find_token(cql, "big_data_keyspace"),
+                replication: Some(KeyspaceReplication::Simple(1)),
+                durable_writes: None,
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_keyspace_if_exists() {
+    let cql = ALTER_KEYSPACE_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Keyspace(
+            AlterKeyspaceStatement {
+                if_exists: true,
+                keyspace_name: find_token(cql, "big_data_keyspace"),
+                replication: None,
+                durable_writes: Some(false),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_materialized_view() {
+    let cql = ALTER_MATERIALIZED_VIEW;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::MaterializedView(
+            AlterMaterializedViewStatement {
+                if_exists: false,
+                keyspace_name: None,
+                view_name: find_token(cql, "big_data_view"),
+                attributes: vec!(TableDefinitionAttribute::Comment(find_string_literal(
+                    cql,
+                    "'quoi de neuf'"
+                ))),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_materialized_view_if_exists() {
+    let cql = ALTER_MATERIALIZED_VIEW_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::MaterializedView(
+            AlterMaterializedViewStatement {
+                if_exists: true,
+                keyspace_name: None,
+                view_name: find_token(cql, "big_data_view"),
+                attributes: vec!(TableDefinitionAttribute::Comment(find_string_literal(
+                    cql,
+                    "'quoi de neuf'"
+                ))),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_role_with_multiple_role_options() {
+    let cql = ALTER_ROLE_WITH_MULTIPLE_ROLE_OPTIONS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Role(
+            AlterRoleStatement {
+                if_exists: false,
+                role_name: find_token(cql, "big_data_role"),
+                attributes: vec!(
+                    RoleConfigAttribute::Password(AuthPassword::PlainText(find_string_literal(
+                        cql, "'asdf'"
+                    ))),
+                    RoleConfigAttribute::Login(true),
+                ),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_role_with_options_map() {
+    let cql = ALTER_ROLE_WITH_OPTIONS_MAP;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Role(
+            AlterRoleStatement {
+                if_exists: false,
+                role_name: find_token(cql, "big_data_role"),
+                attributes: vec!(RoleConfigAttribute::Options(HashMap::from([
+                    (find_string_literal(cql, "'opt1'"), find_token(cql, "'val'")),
+                    (find_string_literal(cql, "'opt2'"), find_token(cql, "99")),
+                ]))),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_role_if_exists() {
+    let cql = ALTER_ROLE_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Role(
+            AlterRoleStatement {
+                if_exists: true,
+                role_name: find_token(cql, "big_data_role"),
+                attributes: vec!(RoleConfigAttribute::Password(AuthPassword::PlainText(
+                    find_string_literal(cql, "'asdf'")
+                ))),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_role_fixtures() {
+    for cql in [
+        ALTER_ROLE_WITH_PASSWORD,
+        ALTER_ROLE_WITH_HASHED_PASSWORD,
+        ALTER_ROLE_WITH_LOGIN_TRUE,
+        ALTER_ROLE_WITH_LOGIN_FALSE,
+        ALTER_ROLE_WITH_SUPERUSER_TRUE,
+        ALTER_ROLE_WITH_SUPERUSER_FALSE,
+        ALTER_ROLE_WITH_ACCESS_TO_DATACENTERS_SET,
+        ALTER_ROLE_WITH_ACCESS_TO_ALL_DATACENTERS,
+    ] {
+        assert!(
+            matches!(
+                parse_cql(cql.to_string()).unwrap().as_slice(),
+                [CqlStatement::Alter(AlterStatement::Role(_))]
+            ),
+            "{cql}"
+        );
+    }
+}
+
+#[test]
+fn test_alter_table_add_column() {
+    let cql = ALTER_TABLE_ADD_COLUMN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::AddColumns {
+                    if_not_exists: false,
+                    columns: vec!(AlterTableColumn {
+                        column_name: find_token(cql, "text_column"),
+                        data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                            CqlNativeType::Text
+                        )),
+                        is_static: false,
+                    }),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_if_exists() {
+    let cql = ALTER_TABLE_IF_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Table(statement)) => {
+            assert!(statement.if_exists);
+            assert!(matches!(
+                statement.instruction,
+                AlterTableInstruction::AddColumns {
+                    if_not_exists: false,
+                    ..
+                }
+            ));
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_table_add_multiple_columns() {
+    let cql = ALTER_TABLE_ADD_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::AddColumns {
+                    if_not_exists: false,
+                    columns: vec!(
+                        AlterTableColumn {
+                            column_name: find_token(cql, "text_column"),
+                            data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                                CqlNativeType::Text
+                            )),
+                            is_static: false,
+                        },
+                        AlterTableColumn {
+                            column_name: find_token(cql, "uuid_column"),
+                            data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                                CqlNativeType::Uuid
+                            )),
+                            is_static: false,
+                        }
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_add_parenthesized_columns() {
+    let cql = ALTER_TABLE_ADD_PARENTHESIZED_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::AddColumns {
+                    if_not_exists: false,
+                    columns: vec!(
+                        AlterTableColumn {
+                            column_name: find_token(cql, "text_column"),
+                            data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                                CqlNativeType::Text
+                            )),
+                            is_static: false,
+                        },
+                        AlterTableColumn {
+                            column_name: find_token(cql, "uuid_column"),
+                            data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                                CqlNativeType::Uuid
+                            )),
+                            is_static: false,
+                        }
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_add_column_if_not_exists() {
+    let cql = ALTER_TABLE_ADD_COLUMN_IF_NOT_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Table(statement)) => {
+            assert!(!statement.if_exists);
+            assert!(matches!(
+                statement.instruction,
+                AlterTableInstruction::AddColumns {
+                    if_not_exists: true,
+                    ..
+                }
+            ));
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_table_with_comment() {
+    let cql = ALTER_TABLE_WITH_COMMENT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::With(vec!(TableDefinitionAttribute::Comment(
+                    find_string_literal(cql, "'big data!'")
+                ))),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_drop_column() {
+    let cql = ALTER_TABLE_DROP_COLUMN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::DropColumns {
+                    if_exists: false,
+                    column_names: vec!(find_token(cql, "text_column")),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_drop_column_if_exists() {
+    let cql = ALTER_TABLE_DROP_COLUMN_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::DropColumns {
+                    if_exists: true,
+                    column_names: vec!(find_token(cql, "text_column")),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_drop_multiple_columns() {
+    let cql = ALTER_TABLE_DROP_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::DropColumns {
+                    if_exists: false,
+                    column_names: vec!(
+                        find_token(cql, "text_column"),
+                        find_token(cql, "uuid_column")
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_drop_parenthesized_columns() {
+    let cql = ALTER_TABLE_DROP_PARENTHESIZED_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::DropColumns {
+                    if_exists: false,
+                    column_names: vec!(
+                        find_token(cql, "text_column"),
+                        find_token(cql, "uuid_column")
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_rename_column() {
+    let cql = ALTER_TABLE_RENAME_COLUMN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::RenameColumns {
+                    if_exists: false,
+                    renames: vec!((
+                        find_token(cql, "text_column"),
+                        find_nth_token(cql, 1, "text_col")
+                    )),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_table_rename_column_if_exists() {
+    let cql = ALTER_TABLE_RENAME_COLUMN_IF_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Table(statement)) => {
+            assert!(matches!(
+                statement.instruction,
+                AlterTableInstruction::RenameColumns {
+                    if_exists: true,
+                    ..
+                }
+            ));
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_table_rename_multiple_columns() {
+    let cql = ALTER_TABLE_RENAME_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Table(
+            AlterTableStatement {
+                if_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                instruction: AlterTableInstruction::RenameColumns {
+                    if_exists: false,
+                    renames: vec!(
+                        (
+                            find_token(cql, "text_column"),
+                            find_nth_token(cql, 1, "text_col")
+                        ),
+                        (
+                            find_token(cql, "uuid_column"),
+                            find_nth_token(cql, 1, "uuid_col")
+                        ),
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_type_add_field() {
+    let cql = ALTER_UDT_ADD_FIELD;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Type(
+            AlterTypeStatement {
+                if_exists: false,
+                keyspace_name: None,
+                type_name: find_token(cql, "big_data_udt"),
+                instruction: AlterTypeInstruction::AddField {
+                    if_not_exists: false,
+                    field_name: find_token(cql, "big_data_text_column"),
+                    data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                        CqlNativeType::Text
+                    )),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_type_if_exists() {
+    let cql = ALTER_UDT_IF_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Type(statement)) => assert!(statement.if_exists),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_type_add_field_if_not_exists() {
+    let cql = ALTER_UDT_ADD_FIELD_IF_NOT_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Type(statement)) => assert!(matches!(
+            statement.instruction,
+            AlterTypeInstruction::AddField {
+                if_not_exists: true,
+                ..
+            }
+        )),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_type_rename_multiple_fields() {
+    let cql = ALTER_UDT_RENAME_MULTIPLE_FIELDS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::Type(
+            AlterTypeStatement {
+                if_exists: false,
+                keyspace_name: None,
+                type_name: find_token(cql, "big_data_udt"),
+                instruction: AlterTypeInstruction::RenameFields {
+                    if_exists: false,
+                    renames: vec!(
+                        (
+                            find_token(cql, "big_data_text_column"),
+                            find_token(cql, "modest_data_text_column")
+                        ),
+                        (
+                            find_token(cql, "big_data_int_column"),
+                            find_token(cql, "gargantuan_int_column")
+                        ),
+                    ),
+                },
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_type_rename_field_if_exists() {
+    let cql = ALTER_UDT_RENAME_FIELD_IF_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Alter(AlterStatement::Type(statement)) => assert!(matches!(
+            statement.instruction,
+            AlterTypeInstruction::RenameFields {
+                if_exists: true,
+                ..
+            }
+        )),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_alter_user() {
+    let cql = ALTER_USER;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::User(
+            AlterUserStatement {
+                if_exists: false,
+                user_name: find_token(cql, "big_data_user"),
+                password: None,
+                user_status: None,
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_user_if_exists() {
+    let cql = ALTER_USER_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::User(
+            AlterUserStatement {
+                if_exists: true,
+                user_name: find_token(cql, "big_data_user"),
+                password: None,
+                user_status: None,
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_user_with_hashed_password_not_superuser() {
+    let cql = ALTER_USER_WITH_HASHED_PASSWORD_NOT_SUPERUSER;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Alter(AlterStatement::User(
+            AlterUserStatement {
+                if_exists: false,
+                user_name: find_token(cql, "big_data_user"),
+                password: Some(AuthPassword::Hashed(find_string_literal(cql, "'aassddff'"))),
+                user_status: Some(CreateUserStatus::NoSuperuser),
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_alter_user_fixtures() {
+    for cql in [
+        ALTER_USER_SUPERUSER,
+        ALTER_USER_NOT_SUPERUSER,
+        ALTER_USER_WITH_PASSWORD,
+        ALTER_USER_WITH_PASSWORD_SUPERUSER,
+        ALTER_USER_WITH_PASSWORD_NOT_SUPERUSER,
+        ALTER_USER_WITH_HASHED_PASSWORD,
+        ALTER_USER_WITH_HASHED_PASSWORD_SUPERUSER,
+    ] {
+        assert!(
+            matches!(
+                parse_cql(cql.to_string()).unwrap().as_slice(),
+                [CqlStatement::Alter(AlterStatement::User(_))]
+            ),
+            "{cql}"
+        );
+    }
+}
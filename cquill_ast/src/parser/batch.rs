@@ -0,0 +1,44 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::delete::parse_delete_statement;
+use crate::parser::expression::pop_if_update_parameters;
+use crate::parser::insert::parse_insert_statement;
+use crate::parser::iter::*;
+use crate::parser::update::parse_update_statement;
+use crate::parser::{ParseError, ParseResult};
+
+use std::{iter::Peekable, slice::Iter, sync::Arc};
+
+pub fn parse_batch_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<BatchStatement> {
+    let batch_type = if pop_next_if(iter, UnloggedKeyword).is_some() {
+        BatchType::Unlogged
+    } else if pop_next_if(iter, CounterKeyword).is_some() {
+        BatchType::Counter
+    } else {
+        BatchType::Logged
+    };
+    pop_next_match(iter, BatchKeyword)?;
+    let using = pop_if_update_parameters(cql, iter)?;
+    let mut statements = Vec::new();
+    loop {
+        while pop_next_if(iter, Semicolon).is_some() {}
+        if pop_sequence(iter, &[ApplyKeyword, BatchKeyword])? {
+            break;
+        }
+        statements.push(match pop_next(iter)?.name {
+            DeleteKeyword => parse_delete_statement(cql, iter).map(CqlStatement::Delete)?,
+            InsertKeyword => parse_insert_statement(cql, iter).map(CqlStatement::Insert)?,
+            UpdateKeyword => parse_update_statement(cql, iter).map(CqlStatement::Update)?,
+            _ => return Err(ParseError::InvalidCql),
+        });
+    }
+    Ok(BatchStatement {
+        batch_type,
+        using,
+        statements,
+    })
+}
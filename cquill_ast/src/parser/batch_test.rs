@@ -0,0 +1,153 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    ast::*,
+    parse_cql,
+    parser::testing::{find_nth_token, find_string_literal, find_token, rfind_token},
+    test_cql::*,
+};
+
+fn insert_statement(cql: &str, nth: usize, column_name: &str, value: &str) -> CqlStatement {
+    CqlStatement::Insert(InsertStatement {
+        keyspace_name: None,
+        table_name: find_nth_token(cql, nth, "big_data_table"),
+        values: InsertValues::Values {
+            column_names: vec![find_token(cql, column_name)],
+            values: vec![ExpressionTerm::String(find_string_literal(cql, value))],
+        },
+        if_not_exists: false,
+        using: vec![],
+    })
+}
+
+#[test]
+fn test_batch() {
+    let cql = BATCH;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Batch(BatchStatement {
+            batch_type: BatchType::Logged,
+            using: vec![],
+            statements: vec![
+                insert_statement(cql, 0, "text_col1", "'big data!'"),
+                insert_statement(cql, 1, "text_col2", "'more data!'"),
+            ],
+        }))
+    );
+}
+
+#[test]
+fn test_batch_using_timestamp() {
+    let cql = BATCH_USING_TIMESTAMP;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Batch(BatchStatement {
+            batch_type: BatchType::Logged,
+            using: vec![UpdateParameter::Timestamp(ExpressionTerm::String(
+                find_string_literal(cql, "'2023-11-14T04:05+0000'")
+            ))],
+            statements: vec![
+                insert_statement(cql, 0, "text_col1", "'big data!'"),
+                insert_statement(cql, 1, "text_col2", "'more data!'"),
+            ],
+        }))
+    );
+}
+
+#[test]
+fn test_unlogged_batch() {
+    let cql = UNLOGGED_BATCH;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Batch(BatchStatement {
+            batch_type: BatchType::Unlogged,
+            using: vec![],
+            statements: vec![
+                insert_statement(cql, 0, "text_col1", "'big data!'"),
+                CqlStatement::Update(UpdateStatement {
+                    keyspace_name: None,
+                    table_name: find_nth_token(cql, 1, "big_data_table"),
+                    using: vec![],
+                    assignments: vec![Assignment {
+                        selection: AssignmentSelection::Column {
+                            column_name: find_token(cql, "text_col2"),
+                        },
+                        expr_term: ExpressionTerm::String(find_string_literal(cql, "'more data!'")),
+                    }],
+                    where_clause: WhereClause {
+                        relations: vec![WhereClauseRelation {
+                            columns: RelationColumns::Single(rfind_token(cql, "text_col1")),
+                            operator: RelationOperator::Equal,
+                            expr_term: ExpressionTerm::String(StringView {
+                                range: rfind_token(cql, "'big data!'").range,
+                                ..find_string_literal(cql, "'big data!'")
+                            }),
+                        }],
+                    },
+                    if_behavior: None,
+                }),
+            ],
+        }))
+    );
+}
+
+#[test]
+fn test_counter_batch() {
+    let cql = COUNTER_BATCH;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Batch(BatchStatement {
+            batch_type: BatchType::Counter,
+            using: vec![],
+            statements: vec![
+                CqlStatement::Update(UpdateStatement {
+                    keyspace_name: None,
+                    table_name: find_token(cql, "big_data_table"),
+                    using: vec![],
+                    assignments: vec![Assignment {
+                        selection: AssignmentSelection::Column {
+                            column_name: find_token(cql, "count_col"),
+                        },
+                        expr_term: ExpressionTerm::Arithmetic {
+                            left: Box::new(ExpressionTerm::Column(find_nth_token(
+                                cql,
+                                1,
+                                "count_col"
+                            ))),
+                            operator: ArithmeticOperator::Add,
+                            right: Box::new(ExpressionTerm::Number(find_token(cql, "1"))),
+                        },
+                    }],
+                    where_clause: WhereClause {
+                        relations: vec![WhereClauseRelation {
+                            columns: RelationColumns::Single(find_token(cql, "text_col")),
+                            operator: RelationOperator::Equal,
+                            expr_term: ExpressionTerm::String(find_string_literal(
+                                cql,
+                                "'big data!'"
+                            )),
+                        }],
+                    },
+                    if_behavior: None,
+                }),
+                CqlStatement::Delete(DeleteStatement {
+                    selections: vec![],
+                    keyspace_name: None,
+                    table_name: find_nth_token(cql, 1, "big_data_table"),
+                    using: vec![],
+                    where_clause: WhereClause {
+                        relations: vec![WhereClauseRelation {
+                            columns: RelationColumns::Single(rfind_token(cql, "text_col")),
+                            operator: RelationOperator::Equal,
+                            expr_term: ExpressionTerm::String(find_string_literal(
+                                cql,
+                                "'more data!'"
+                            )),
+                        }],
+                    },
+                    if_behavior: None,
+                }),
+            ],
+        }))
+    );
+}
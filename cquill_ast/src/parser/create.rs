@@ -1,12 +1,14 @@
 use crate::ast::*;
 use crate::lex::Token;
 use crate::lex::TokenName::*;
-use crate::parser::ParseResult;
+use crate::parser::expression::{parse_expression_term, parse_where_clause};
 use crate::parser::iter::{
-    advance_until, peek_next_match, pop_aggregate_signature, pop_boolean_literal,
-    pop_comma_separated_identifiers, pop_cql_data_type, pop_identifier, pop_keyspace_object_name,
-    pop_next, pop_next_if, pop_next_match, pop_sequence, pop_string_literal,
+    peek_next_match, pop_aggregate_signature, pop_boolean_literal, pop_comma_separated_identifiers,
+    pop_cql_data_type, pop_identifier, pop_keyspace_object_name, pop_next, pop_next_if,
+    pop_next_match, pop_sequence, pop_string_literal,
 };
+use crate::parser::select::parse_select_clause;
+use crate::parser::{ParseError, ParseResult};
 use std::collections::HashMap;
 use std::iter::Peekable;
 use std::slice::Iter;
@@ -17,7 +19,7 @@ pub fn parse_create_statement(
     iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<CreateStatement> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(token) => match token.name {
             AggregateKeyword => {
                 parse_create_aggregate_statement(cql, iter, false).map(CreateStatement::Aggregate)
@@ -32,7 +34,7 @@ pub fn parse_create_statement(
                         .map(CreateStatement::Aggregate),
                     FunctionKeyword => parse_create_function_statement(cql, iter, true)
                         .map(CreateStatement::Function),
-                    _ => todo!("parse error"),
+                    _ => Err(ParseError::InvalidCql),
                 }
             }
             IndexKeyword => parse_create_index_statement(cql, iter).map(CreateStatement::Index),
@@ -51,13 +53,12 @@ pub fn parse_create_statement(
             }
             TypeKeyword => parse_create_type_statement(cql, iter).map(CreateStatement::Type),
             UserKeyword => parse_create_user_statement(cql, iter).map(CreateStatement::User),
-            _ => todo!("parse error"),
+            _ => Err(ParseError::InvalidCql),
         },
     }
 }
 
 // todo can an aggregate stype be frozen?
-// todo parse init condition
 fn parse_create_aggregate_statement(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
@@ -67,7 +68,7 @@ fn parse_create_aggregate_statement(
         create_or_replace,
         pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?,
     )?;
-    let function_name = pop_identifier(cql, iter)?;
+    let (keyspace_name, function_name) = pop_keyspace_object_name(cql, iter)?;
     let function_arg = pop_aggregate_signature(cql, iter)?;
     pop_next_match(iter, SFuncKeyword)?;
     let state_function = pop_identifier(cql, iter)?;
@@ -79,14 +80,13 @@ fn parse_create_aggregate_statement(
     } else {
         None
     };
-    let init_condition = if peek_next_match(iter, InitCondKeyword)? {
-        advance_until(iter, Semicolon);
-        true
-    } else {
-        false
+    let init_condition = match pop_next_if(iter, InitCondKeyword) {
+        None => None,
+        Some(_) => Some(parse_expression_term(cql, iter)?),
     };
     Ok(CreateAggregateStatement {
         if_exists_behavior,
+        keyspace_name,
         function_name,
         function_arg,
         state_function,
@@ -105,7 +105,7 @@ fn parse_create_function_statement(
         create_or_replace,
         pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?,
     )?;
-    let function_name = pop_identifier(cql, iter)?;
+    let (keyspace_name, function_name) = pop_keyspace_object_name(cql, iter)?;
     let function_args = pop_named_data_types_map(cql, iter)?;
     let on_null_input = match pop_next(iter)?.name {
         CalledKeyword => OnNullInput::Called,
@@ -113,7 +113,7 @@ fn parse_create_function_statement(
             pop_next_match(iter, NullKeyword)?;
             OnNullInput::ReturnsNull
         }
-        _ => todo!("parse error"),
+        _ => return Err(ParseError::InvalidCql),
     };
     pop_next_match(iter, OnKeyword)?;
     pop_next_match(iter, NullKeyword)?;
@@ -126,6 +126,7 @@ fn parse_create_function_statement(
     let function_body = pop_string_literal(cql, iter)?;
     Ok(CreateFunctionStatement {
         if_exists_behavior,
+        keyspace_name,
         function_name,
         function_args,
         on_null_input,
@@ -138,7 +139,7 @@ fn parse_create_function_statement(
 impl CreateIfExistsBehavior {
     fn new(create_or_replace: bool, if_not_exists: bool) -> ParseResult<CreateIfExistsBehavior> {
         if create_or_replace && if_not_exists {
-            todo!("parse error")
+            Err(ParseError::InvalidCql)
         } else if create_or_replace {
             Ok(CreateIfExistsBehavior::Replace)
         } else if if_not_exists {
@@ -163,7 +164,7 @@ fn parse_create_index_statement(
     let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
     pop_next_match(iter, LeftParenthesis)?;
     let on_column = match iter.next() {
-        None => todo!("parse error"),
+        None => return Err(ParseError::InvalidCql),
         Some(popped) => match popped.name {
             FullKeyword | EntriesKeyword | KeysKeyword | ValuesKeyword => {
                 pop_next_match(iter, LeftParenthesis)?;
@@ -178,7 +179,7 @@ fn parse_create_index_statement(
                 }
             }
             Identifier => CreateIndexColumn::Column(popped.to_token_view(cql)),
-            _ => todo!("parse error"),
+            _ => return Err(ParseError::InvalidCql),
         },
     };
     pop_next_match(iter, RightParenthesis)?;
@@ -198,74 +199,77 @@ fn parse_create_keyspace_statement(
     let if_not_exists = pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?;
     let keyspace_name = pop_identifier(cql, iter)?;
     pop_next_match(iter, WithKeyword)?;
+    let (replication, durable_writes) = pop_keyspace_options(cql, iter)?;
+    let replication = match replication {
+        None => return Err(ParseError::InvalidCql),
+        Some(replication) => replication,
+    };
+    Ok(CreateKeyspaceStatement {
+        if_not_exists,
+        keyspace_name,
+        durable_writes,
+        replication,
+    })
+}
+
+/// Pops the `replication` and `durable_writes` options following `WITH` of `CREATE KEYSPACE` and
+/// `ALTER KEYSPACE` statements.
+pub fn pop_keyspace_options(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<(Option<KeyspaceReplication>, Option<bool>)> {
     let mut replication = None;
     let mut durable_writes = None;
     loop {
-        println!("looooooop");
         let popped = pop_next(iter)?;
         match popped.name {
             ReplicationKeyword => {
                 pop_next_match(iter, Equal)?;
                 let mut replication_config = pop_map_config(cql, iter)?;
                 replication = Some(match replication_config.remove("class") {
-                    None => todo!("parse error"),
-                    Some(replication_class) => match replication_class {
-                        MapConfigLiteral::String(replication_class) => {
-                            match replication_class.as_str() {
-                                "SimpleStrategy" => {
-                                    match replication_config.get("replication_factor") {
-                                        Some(MapConfigLiteral::Integer(factor)) => {
-                                            KeyspaceReplication::Simple(*factor)
-                                        }
-                                        _ => todo!("parse error"),
+                    None => return Err(ParseError::InvalidCql),
+                    Some(MapConfigLiteral::String(replication_class)) => {
+                        match replication_class.as_str() {
+                            "SimpleStrategy" => {
+                                match replication_config.get("replication_factor") {
+                                    Some(MapConfigLiteral::Integer(factor)) => {
+                                        KeyspaceReplication::Simple(*factor)
                                     }
+                                    _ => return Err(ParseError::InvalidCql),
                                 }
-                                "NetworkTopologyStrategy" => {
-                                    let mut factors = HashMap::new();
-                                    for (dc, factor) in replication_config {
-                                        factors.insert(
-                                            dc,
-                                            match factor {
-                                                MapConfigLiteral::Integer(factor) => factor,
-                                                _ => todo!("parse error"),
-                                            },
-                                        );
-                                    }
-                                    KeyspaceReplication::NetworkTopology(factors)
+                            }
+                            "NetworkTopologyStrategy" => {
+                                let mut factors = HashMap::new();
+                                for (dc, factor) in replication_config {
+                                    factors.insert(
+                                        dc,
+                                        match factor {
+                                            MapConfigLiteral::Integer(factor) => factor,
+                                            _ => return Err(ParseError::InvalidCql),
+                                        },
+                                    );
                                 }
-                                _ => todo!("parse error"),
+                                KeyspaceReplication::NetworkTopology(factors)
                             }
+                            _ => return Err(ParseError::InvalidCql),
                         }
-                        _ => todo!("parse error"),
-                    },
+                    }
+                    Some(_) => return Err(ParseError::InvalidCql),
                 })
             }
-            Identifier => {
-                // todo &str without to_token_view allocations
-                //  maybe `Token::to_str(&self, cql: &Arc<String>)`
-                if popped.to_token_view(cql).value() == *"durable_writes" {
-                    pop_next_match(iter, Equal)?;
-                    durable_writes = Some(pop_boolean_literal(iter)?);
-                } else {
-                    todo!("parse error");
-                }
+            // todo &str without to_token_view allocations
+            //  maybe `Token::to_str(&self, cql: &Arc<String>)`
+            Identifier if popped.to_token_view(cql).value() == *"durable_writes" => {
+                pop_next_match(iter, Equal)?;
+                durable_writes = Some(pop_boolean_literal(iter)?);
             }
-            _ => todo!("parse error"),
+            _ => return Err(ParseError::InvalidCql),
         }
         if pop_next_if(iter, AndKeyword).is_none() {
             break;
         }
     }
-    let replication = match replication {
-        None => todo!("parse error"),
-        Some(replication) => replication,
-    };
-    Ok(CreateKeyspaceStatement {
-        if_not_exists,
-        keyspace_name,
-        durable_writes,
-        replication,
-    })
+    Ok((replication, durable_writes))
 }
 
 fn pop_map_config(
@@ -289,11 +293,15 @@ fn pop_map_config(
                     .value(),
                 ),
                 NumberLiteral => MapConfigLiteral::Integer(
-                    popped.to_token_view(cql).value().parse().expect("integer"),
+                    popped
+                        .to_token_view(cql)
+                        .value()
+                        .parse()
+                        .map_err(|_| ParseError::InvalidCql)?,
                 ),
                 TrueKeyword => MapConfigLiteral::Boolean(true),
                 FalseKeyword => MapConfigLiteral::Boolean(false),
-                _ => todo!("parse error"),
+                _ => return Err(ParseError::InvalidCql),
             }
         };
         map_config.insert(key, value);
@@ -313,10 +321,37 @@ enum MapConfigLiteral {
 }
 
 fn parse_create_materialized_view_statement(
-    _cql: &Arc<String>,
-    _iter: &mut Peekable<Iter<Token>>,
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<CreateMaterializedViewStatement> {
-    unimplemented!()
+    let if_not_exists = pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?;
+    let (keyspace_name, view_name) = pop_keyspace_object_name(cql, iter)?;
+    pop_next_match(iter, AsKeyword)?;
+    pop_next_match(iter, SelectKeyword)?;
+    let select_clause = parse_select_clause(cql, iter)?;
+    pop_next_match(iter, FromKeyword)?;
+    let (base_keyspace_name, base_table_name) = pop_keyspace_object_name(cql, iter)?;
+    pop_next_match(iter, WhereKeyword)?;
+    let where_clause = parse_where_clause(cql, iter)?;
+    pop_next_match(iter, PrimaryKeyword)?;
+    pop_next_match(iter, KeyKeyword)?;
+    let primary_key = pop_primary_key_definition(cql, iter)?;
+    let attributes = if pop_next_if(iter, WithKeyword).is_some() {
+        Some(pop_table_definition_attributes(cql, iter)?)
+    } else {
+        None
+    };
+    Ok(CreateMaterializedViewStatement {
+        if_not_exists,
+        keyspace_name,
+        view_name,
+        select_clause,
+        base_keyspace_name,
+        base_table_name,
+        where_clause,
+        primary_key,
+        attributes,
+    })
 }
 
 fn parse_create_role_statement(
@@ -327,65 +362,7 @@ fn parse_create_role_statement(
     let role_name = pop_identifier(cql, iter)?;
     let attributes = if peek_next_match(iter, WithKeyword)? {
         _ = iter.next();
-        let mut attributes = Vec::new();
-        loop {
-            attributes.push(match iter.next() {
-                None => todo!("parse error"),
-                Some(popped) => match popped.name {
-                    SuperUserKeyword => {
-                        pop_next_match(iter, Equal)?;
-                        RoleConfigAttribute::Superuser(pop_boolean_literal(iter)?)
-                    }
-                    LoginKeyword => {
-                        pop_next_match(iter, Equal)?;
-                        RoleConfigAttribute::Login(pop_boolean_literal(iter)?)
-                    }
-                    PasswordKeyword => {
-                        pop_next_match(iter, Equal)?;
-                        RoleConfigAttribute::Password(AuthPassword::PlainText(pop_string_literal(
-                            cql, iter,
-                        )?))
-                    }
-                    HashedKeyword => {
-                        pop_next_match(iter, PasswordKeyword)?;
-                        pop_next_match(iter, Equal)?;
-                        RoleConfigAttribute::Password(AuthPassword::Hashed(pop_string_literal(
-                            cql, iter,
-                        )?))
-                    }
-                    OptionsKeyword => {
-                        pop_next_match(iter, Equal)?;
-                        RoleConfigAttribute::Options(pop_hacky_map_literal(cql, iter)?)
-                    }
-                    AccessKeyword => {
-                        pop_next_match(iter, ToKeyword)?;
-                        if peek_next_match(iter, AllKeyword)? {
-                            _ = iter.next();
-                            pop_next_match(iter, DatacentersKeyword)?;
-                            RoleConfigAttribute::Access(Datacenters::All)
-                        } else {
-                            pop_next_match(iter, DatacentersKeyword)?;
-                            pop_next_match(iter, LeftCurvedBracket)?;
-                            let mut datacenters = Vec::new();
-                            loop {
-                                datacenters.push(pop_string_literal(cql, iter)?);
-                                match pop_next(iter)?.name {
-                                    Comma => continue,
-                                    RightCurvedBracket => break,
-                                    _ => todo!("parse error"),
-                                }
-                            }
-                            RoleConfigAttribute::Access(Datacenters::Explicit(datacenters))
-                        }
-                    }
-                    _ => todo!("parse error {:?}", popped.name),
-                },
-            });
-            if pop_next_if(iter, AndKeyword).is_none() {
-                break;
-            }
-        }
-        Some(attributes)
+        Some(pop_role_config_attributes(cql, iter)?)
     } else {
         None
     };
@@ -396,6 +373,73 @@ fn parse_create_role_statement(
     })
 }
 
+/// Pops the `AND` separated role options following `WITH` of `CREATE ROLE` and `ALTER ROLE`
+/// statements.
+pub fn pop_role_config_attributes(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Vec<RoleConfigAttribute>> {
+    let mut attributes = Vec::new();
+    loop {
+        attributes.push(match iter.next() {
+            None => return Err(ParseError::InvalidCql),
+            Some(popped) => match popped.name {
+                SuperUserKeyword => {
+                    pop_next_match(iter, Equal)?;
+                    RoleConfigAttribute::Superuser(pop_boolean_literal(iter)?)
+                }
+                LoginKeyword => {
+                    pop_next_match(iter, Equal)?;
+                    RoleConfigAttribute::Login(pop_boolean_literal(iter)?)
+                }
+                PasswordKeyword => {
+                    pop_next_match(iter, Equal)?;
+                    RoleConfigAttribute::Password(AuthPassword::PlainText(pop_string_literal(
+                        cql, iter,
+                    )?))
+                }
+                HashedKeyword => {
+                    pop_next_match(iter, PasswordKeyword)?;
+                    pop_next_match(iter, Equal)?;
+                    RoleConfigAttribute::Password(AuthPassword::Hashed(pop_string_literal(
+                        cql, iter,
+                    )?))
+                }
+                OptionsKeyword => {
+                    pop_next_match(iter, Equal)?;
+                    RoleConfigAttribute::Options(pop_hacky_map_literal(cql, iter)?)
+                }
+                AccessKeyword => {
+                    pop_next_match(iter, ToKeyword)?;
+                    if peek_next_match(iter, AllKeyword)? {
+                        _ = iter.next();
+                        pop_next_match(iter, DatacentersKeyword)?;
+                        RoleConfigAttribute::Access(Datacenters::All)
+                    } else {
+                        pop_next_match(iter, DatacentersKeyword)?;
+                        pop_next_match(iter, LeftCurvedBracket)?;
+                        let mut datacenters = Vec::new();
+                        loop {
+                            datacenters.push(pop_string_literal(cql, iter)?);
+                            match pop_next(iter)?.name {
+                                Comma => continue,
+                                RightCurvedBracket => break,
+                                _ => return Err(ParseError::InvalidCql),
+                            }
+                        }
+                        RoleConfigAttribute::Access(Datacenters::Explicit(datacenters))
+                    }
+                }
+                _ => return Err(ParseError::InvalidCql),
+            },
+        });
+        if pop_next_if(iter, AndKeyword).is_none() {
+            break;
+        }
+    }
+    Ok(attributes)
+}
+
 fn parse_create_table_statement(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
@@ -404,64 +448,7 @@ fn parse_create_table_statement(
     let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
     let column_definitions = pop_table_column_definitions(cql, iter)?;
     let attributes = if pop_next_if(iter, WithKeyword).is_some() {
-        let mut attributes = Vec::new();
-        loop {
-            match iter.next() {
-                None => todo!("parse error"),
-                Some(popped) => match popped.name {
-                    CompactKeyword => {
-                        pop_next_match(iter, StorageKeyword)?;
-                        attributes.push(TableDefinitionAttribute::CompactStorage);
-                    }
-                    ClusteringKeyword => {
-                        pop_next_match(iter, OrderKeyword)?;
-                        pop_next_match(iter, ByKeyword)?;
-                        pop_next_match(iter, LeftParenthesis)?;
-                        let mut clustering_orders = Vec::new();
-                        loop {
-                            clustering_orders.push({
-                                let column_name = pop_identifier(cql, iter)?;
-                                let order = if pop_next_if(iter, AscKeyword).is_some() {
-                                    Some(ClusteringOrder::Asc)
-                                } else if pop_next_if(iter, DescKeyword).is_some() {
-                                    Some(ClusteringOrder::Desc)
-                                } else {
-                                    todo!("parse error");
-                                };
-                                ClusteringOrderDefinition { column_name, order }
-                            });
-                            if pop_next_if(iter, Comma).is_none() {
-                                break;
-                            }
-                        }
-                        attributes.push(TableDefinitionAttribute::ClusteringOrderBy(
-                            clustering_orders,
-                        ));
-                        pop_next_match(iter, RightParenthesis)?;
-                    }
-                    Identifier => match popped.to_token_view(cql).value().to_lowercase().as_str() {
-                        "comment" => {
-                            pop_next_match(iter, Equal)?;
-                            attributes.push(TableDefinitionAttribute::Comment(pop_string_literal(
-                                cql, iter,
-                            )?))
-                        }
-                        "compaction" => {
-                            pop_next_match(iter, Equal)?;
-                            attributes.push(TableDefinitionAttribute::Compaction(
-                                pop_hacky_map_literal(cql, iter)?,
-                            ))
-                        }
-                        _ => todo!("parse error"),
-                    },
-                    _ => todo!("parse error"),
-                },
-            }
-            if pop_next_if(iter, Comma).is_none() {
-                break;
-            }
-        }
-        Some(attributes)
+        Some(pop_table_definition_attributes(cql, iter)?)
     } else {
         None
     };
@@ -476,6 +463,78 @@ fn parse_create_table_statement(
     })
 }
 
+/// Pops the `AND` separated table options following `WITH` of `CREATE TABLE`, `ALTER TABLE`,
+/// `CREATE MATERIALIZED VIEW` and `ALTER MATERIALIZED VIEW` statements.
+pub fn pop_table_definition_attributes(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Vec<TableDefinitionAttribute>> {
+    let mut attributes = Vec::new();
+    loop {
+        match iter.next() {
+            None => return Err(ParseError::InvalidCql),
+            Some(popped) => match popped.name {
+                CompactKeyword => {
+                    pop_next_match(iter, StorageKeyword)?;
+                    attributes.push(TableDefinitionAttribute::CompactStorage);
+                }
+                ClusteringKeyword => {
+                    pop_next_match(iter, OrderKeyword)?;
+                    pop_next_match(iter, ByKeyword)?;
+                    pop_next_match(iter, LeftParenthesis)?;
+                    let mut clustering_orders = Vec::new();
+                    loop {
+                        clustering_orders.push({
+                            let column_name = pop_identifier(cql, iter)?;
+                            let order = if pop_next_if(iter, AscKeyword).is_some() {
+                                Some(ClusteringOrder::Asc)
+                            } else if pop_next_if(iter, DescKeyword).is_some() {
+                                Some(ClusteringOrder::Desc)
+                            } else {
+                                return Err(ParseError::InvalidCql);
+                            };
+                            ClusteringOrderDefinition { column_name, order }
+                        });
+                        if pop_next_if(iter, Comma).is_none() {
+                            break;
+                        }
+                    }
+                    attributes.push(TableDefinitionAttribute::ClusteringOrderBy(
+                        clustering_orders,
+                    ));
+                    pop_next_match(iter, RightParenthesis)?;
+                }
+                Identifier => match popped.to_token_view(cql).value().to_lowercase().as_str() {
+                    "comment" => {
+                        pop_next_match(iter, Equal)?;
+                        attributes.push(TableDefinitionAttribute::Comment(pop_string_literal(
+                            cql, iter,
+                        )?))
+                    }
+                    "compaction" => {
+                        pop_next_match(iter, Equal)?;
+                        attributes.push(TableDefinitionAttribute::Compaction(
+                            pop_hacky_map_literal(cql, iter)?,
+                        ))
+                    }
+                    _ => {
+                        pop_next_match(iter, Equal)?;
+                        attributes.push(TableDefinitionAttribute::Option(
+                            popped.to_token_view(cql),
+                            parse_expression_term(cql, iter)?,
+                        ))
+                    }
+                },
+                _ => return Err(ParseError::InvalidCql),
+            },
+        }
+        if pop_next_if(iter, AndKeyword).is_none() {
+            break;
+        }
+    }
+    Ok(attributes)
+}
+
 fn parse_create_trigger_statement(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
@@ -517,7 +576,23 @@ fn parse_create_user_statement(
 ) -> ParseResult<CreateUserStatement> {
     let if_not_exists = pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?;
     let user_name = pop_identifier(cql, iter)?;
-    let password = match iter.peek() {
+    let password = pop_user_password(cql, iter)?;
+    let user_status = pop_user_status(iter)?;
+    Ok(CreateUserStatement {
+        user_name,
+        if_not_exists,
+        password,
+        user_status,
+    })
+}
+
+/// Pops the optional `WITH [HASHED] PASSWORD 'password'` of `CREATE USER` and `ALTER USER`
+/// statements.
+pub fn pop_user_password(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Option<AuthPassword>> {
+    Ok(match iter.peek() {
         None => None,
         Some(peeked) => match peeked.name {
             WithKeyword => {
@@ -529,32 +604,30 @@ fn parse_create_user_statement(
                         AuthPassword::Hashed(pop_string_literal(cql, iter)?)
                     }
                     PasswordKeyword => AuthPassword::PlainText(pop_string_literal(cql, iter)?),
-                    _ => todo!("parse error"),
+                    _ => return Err(ParseError::InvalidCql),
                 })
             }
             _ => None,
         },
-    };
-    let user_status = match iter.peek() {
+    })
+}
+
+/// Pops the optional `SUPERUSER` or `NOSUPERUSER` of `CREATE USER` and `ALTER USER` statements.
+pub fn pop_user_status(iter: &mut Peekable<Iter<Token>>) -> ParseResult<Option<CreateUserStatus>> {
+    Ok(match iter.peek() {
         None => None,
         Some(peeked) => {
             let status = match peeked.name {
                 NoSuperUserKeyword => Some(CreateUserStatus::NoSuperuser),
                 SuperUserKeyword => Some(CreateUserStatus::Superuser),
                 Semicolon => None,
-                _ => todo!("parse error"),
+                _ => return Err(ParseError::InvalidCql),
             };
             if status.is_some() {
                 _ = iter.next();
             }
             status
         }
-    };
-    Ok(CreateUserStatement {
-        user_name,
-        if_not_exists,
-        password,
-        user_status,
     })
 }
 
@@ -587,34 +660,9 @@ fn pop_table_column_definitions(
     loop {
         if pop_next_if(iter, PrimaryKeyword).is_some() {
             pop_next_match(iter, KeyKeyword)?;
-            pop_next_match(iter, LeftParenthesis)?;
-            definitions.push(ColumnDefinition::PrimaryKey(
-                if pop_next_if(iter, LeftParenthesis).is_some() {
-                    let partition = pop_comma_separated_identifiers(cql, iter)?;
-                    pop_next_match(iter, RightParenthesis)?;
-                    let clustering = if peek_next_match(iter, RightParenthesis)? {
-                        Vec::new()
-                    } else {
-                        pop_next_match(iter, Comma)?;
-                        pop_comma_separated_identifiers(cql, iter)?
-                    };
-                    PrimaryKeyDefinition::CompositePartition {
-                        partition,
-                        clustering,
-                    }
-                } else {
-                    let partition = pop_identifier(cql, iter)?;
-                    if pop_next_if(iter, Comma).is_some() {
-                        PrimaryKeyDefinition::Compound {
-                            partition,
-                            clustering: pop_comma_separated_identifiers(cql, iter)?,
-                        }
-                    } else {
-                        PrimaryKeyDefinition::Single(partition)
-                    }
-                },
-            ));
-            pop_next_match(iter, RightParenthesis)?;
+            definitions.push(ColumnDefinition::PrimaryKey(pop_primary_key_definition(
+                cql, iter,
+            )?));
         } else {
             let column_name = pop_identifier(cql, iter)?;
             let data_type = pop_cql_data_type(cql, iter)?;
@@ -642,6 +690,41 @@ fn pop_table_column_definitions(
     Ok(definitions)
 }
 
+/// Pops the parentheses-enclosed columns following `PRIMARY KEY` of `CREATE TABLE` and
+/// `CREATE MATERIALIZED VIEW` statements.
+fn pop_primary_key_definition(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<PrimaryKeyDefinition> {
+    pop_next_match(iter, LeftParenthesis)?;
+    let primary_key = if pop_next_if(iter, LeftParenthesis).is_some() {
+        let partition = pop_comma_separated_identifiers(cql, iter)?;
+        pop_next_match(iter, RightParenthesis)?;
+        let clustering = if peek_next_match(iter, RightParenthesis)? {
+            Vec::new()
+        } else {
+            pop_next_match(iter, Comma)?;
+            pop_comma_separated_identifiers(cql, iter)?
+        };
+        PrimaryKeyDefinition::CompositePartition {
+            partition,
+            clustering,
+        }
+    } else {
+        let partition = pop_identifier(cql, iter)?;
+        if pop_next_if(iter, Comma).is_some() {
+            PrimaryKeyDefinition::Compound {
+                partition,
+                clustering: pop_comma_separated_identifiers(cql, iter)?,
+            }
+        } else {
+            PrimaryKeyDefinition::Single(partition)
+        }
+    };
+    pop_next_match(iter, RightParenthesis)?;
+    Ok(primary_key)
+}
+
 fn pop_hacky_map_literal(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
@@ -654,13 +737,13 @@ fn pop_hacky_map_literal(
         let popped = pop_next(iter)?;
         match popped.name {
             StringLiteral(_) | UuidLiteral | NumberLiteral | TrueKeyword | FalseKeyword => {}
-            _ => todo!("parse error"),
+            _ => return Err(ParseError::InvalidCql),
         };
         map.insert(key, popped.to_token_view(cql));
         match pop_next(iter)?.name {
             Comma => continue,
             RightCurvedBracket => break,
-            _ => todo!("parse error"),
+            _ => return Err(ParseError::InvalidCql),
         }
     }
     Ok(map)
@@ -6,6 +6,26 @@ use std::collections::HashMap;
 
 use pretty_assertions::assert_eq;
 
+#[test]
+fn test_create_aggregate_with_explicit_keyspace() {
+    let cql = CREATE_AGGREGATE_WITH_EXPLICIT_KEYSPACE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Aggregate(
+            CreateAggregateStatement {
+                if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+                function_name: find_token(cql, "big_data_agg"),
+                function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
+                state_function: find_token(cql, "fn_name"),
+                state_type: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
+                final_function: None,
+                init_condition: None,
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_create_aggregate_with_collection_stype() {
     let cql = CREATE_AGGREGATE_WITH_COLLECTION_STYPE;
@@ -14,14 +34,15 @@ fn test_create_aggregate_with_collection_stype() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: None,
-                init_condition: false,
+                init_condition: None,
             }
         )))
     );
@@ -35,14 +56,16 @@ fn test_create_aggregate_with_udt_stype() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::ValueType(CqlValueType::UserDefinedType(find_token(
-                    cql, "some_udt"
-                ))),
+                state_type: CqlDataType::ValueType(CqlValueType::UserDefinedType(
+                    None,
+                    find_token(cql, "some_udt")
+                )),
                 final_function: None,
-                init_condition: false,
+                init_condition: None,
             }
         )))
     );
@@ -56,14 +79,15 @@ fn test_create_aggregate_or_replace() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Replace,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: None,
-                init_condition: false,
+                init_condition: None,
             }
         )))
     );
@@ -77,14 +101,15 @@ fn test_create_aggregate_if_not_exists() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::DoNotError,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: None,
-                init_condition: false,
+                init_condition: None,
             }
         )))
     );
@@ -98,14 +123,15 @@ fn test_create_aggregate_with_final_function() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: Some(find_token(cql, "ffn_name")),
-                init_condition: false,
+                init_condition: None,
             }
         )))
     );
@@ -119,14 +145,18 @@ fn test_create_aggregate_with_init_condition() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: None,
-                init_condition: true,
+                init_condition: Some(ExpressionTerm::String(find_string_literal(
+                    cql,
+                    "'state value'"
+                ))),
             }
         )))
     );
@@ -140,14 +170,18 @@ fn test_create_aggregate_with_final_function_and_init_condition() {
         vec!(CqlStatement::Create(CreateStatement::Aggregate(
             CreateAggregateStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_agg"),
                 function_arg: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 state_function: find_token(cql, "fn_name"),
-                state_type: CqlDataType::CollectionType(CqlCollectionType::List(
-                    CqlValueType::NativeType(CqlNativeType::Text)
-                )),
+                state_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text))
+                ))),
                 final_function: Some(find_token(cql, "ffn_name")),
-                init_condition: true,
+                init_condition: Some(ExpressionTerm::String(find_string_literal(
+                    cql,
+                    "'state value'"
+                ))),
             }
         )))
     );
@@ -163,6 +197,7 @@ fn test_create_function_called_on_null_as_single_quote_string() {
                 function_body: find_string_literal(cql, "'return fn_arg.toString();'"),
                 returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text)),
                 function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: None,
                 if_exists_behavior: CreateIfExistsBehavior::Error,
                 language: find_token(cql, "java"),
                 on_null_input: OnNullInput::Called,
@@ -188,6 +223,7 @@ fn test_create_function_called_on_null_as_dollar_dollar() {
                 ),
                 returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text)),
                 function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: None,
                 if_exists_behavior: CreateIfExistsBehavior::Error,
                 language: find_token(cql, "java"),
                 on_null_input: OnNullInput::Called,
@@ -200,6 +236,29 @@ fn test_create_function_called_on_null_as_dollar_dollar() {
     );
 }
 
+#[test]
+fn test_create_function_with_explicit_keyspace() {
+    let cql = CREATE_FUNCTION_WITH_EXPLICIT_KEYSPACE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Function(
+            CreateFunctionStatement {
+                function_body: find_string_literal(cql, "'return fn_arg;'"),
+                returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
+                function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+                if_exists_behavior: CreateIfExistsBehavior::Error,
+                language: find_token(cql, "java"),
+                on_null_input: OnNullInput::ReturnsNull,
+                function_args: vec![(
+                    find_token(cql, "fn_arg"),
+                    CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
+                )],
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_create_function_returns_null_on_null() {
     let cql = CREATE_FUNCTION_RETURNS_NULL_ON_NULL_INPUT;
@@ -213,6 +272,7 @@ fn test_create_function_returns_null_on_null() {
                 ),
                 returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text)),
                 function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: None,
                 if_exists_behavior: CreateIfExistsBehavior::Error,
                 language: find_token(cql, "java"),
                 on_null_input: OnNullInput::ReturnsNull,
@@ -238,6 +298,7 @@ fn test_create_function_or_replace() {
                 ),
                 returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text)),
                 function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: None,
                 if_exists_behavior: CreateIfExistsBehavior::Replace,
                 language: find_token(cql, "java"),
                 on_null_input: OnNullInput::Called,
@@ -263,6 +324,7 @@ fn test_create_function_if_not_exists() {
                 ),
                 returns: CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Text)),
                 function_name: find_token(cql, "big_data_fn"),
+                keyspace_name: None,
                 if_exists_behavior: CreateIfExistsBehavior::DoNotError,
                 language: find_token(cql, "java"),
                 on_null_input: OnNullInput::Called,
@@ -283,6 +345,7 @@ fn test_create_function_with_multiple_args() {
         vec!(CqlStatement::Create(CreateStatement::Function(
             CreateFunctionStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_fn"),
                 function_args: vec!(
                     (
@@ -314,11 +377,12 @@ fn test_create_function_with_frozen_udt_arg() {
         vec!(CqlStatement::Create(CreateStatement::Function(
             CreateFunctionStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_fn"),
                 function_args: vec!((
                     find_token(cql, "fn_arg"),
                     CqlDataType::Frozen(Box::new(CqlDataType::ValueType(
-                        CqlValueType::UserDefinedType(find_token(cql, "some_udt"))
+                        CqlValueType::UserDefinedType(None, find_token(cql, "some_udt"))
                     ))),
                 )),
                 on_null_input: OnNullInput::Called,
@@ -341,15 +405,17 @@ fn test_create_function_returns_udt() {
         vec!(CqlStatement::Create(CreateStatement::Function(
             CreateFunctionStatement {
                 if_exists_behavior: CreateIfExistsBehavior::Error,
+                keyspace_name: None,
                 function_name: find_token(cql, "big_data_fn"),
                 function_args: vec!((
                     find_token(cql, "fn_arg"),
                     CqlDataType::ValueType(CqlValueType::NativeType(CqlNativeType::Int)),
                 )),
                 on_null_input: OnNullInput::Called,
-                returns: CqlDataType::ValueType(CqlValueType::UserDefinedType(find_token(
-                    cql, "some_udt"
-                ))),
+                returns: CqlDataType::ValueType(CqlValueType::UserDefinedType(
+                    None,
+                    find_token(cql, "some_udt")
+                )),
                 language: find_token(cql, "java"),
                 function_body: find_string_literal(
                     cql,
@@ -562,6 +628,113 @@ fn test_create_keyspace_with_durable_writes_true() {
     );
 }
 
+#[test]
+fn test_parsing_create_materialized_view() {
+    let cql = CREATE_MATERIALIZED_VIEW;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::MaterializedView(
+            CreateMaterializedViewStatement {
+                if_not_exists: false,
+                keyspace_name: None,
+                view_name: find_token(cql, "big_data_view"),
+                select_clause: SelectClause::All,
+                base_keyspace_name: None,
+                base_table_name: find_token(cql, "big_data_table"),
+                where_clause: WhereClause {
+                    relations: vec!(WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "int_column")),
+                        operator: RelationOperator::GreaterThan,
+                        expr_term: ExpressionTerm::Number(find_token(cql, "4")),
+                    }),
+                },
+                primary_key: PrimaryKeyDefinition::Single(rfind_token(cql, "int_column")),
+                attributes: None,
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_parsing_create_materialized_view_if_not_exists() {
+    let cql = CREATE_MATERIALIZED_VIEW_IF_NOT_EXISTS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Create(CreateStatement::MaterializedView(statement)) => {
+            assert!(statement.if_not_exists);
+            assert_eq!(statement.view_name, find_token(cql, "big_data_view"));
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_parsing_create_materialized_view_with_options() {
+    let cql = CREATE_MATERIALIZED_VIEW_WITH_OPTIONS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Create(CreateStatement::MaterializedView(statement)) => {
+            assert_eq!(
+                statement.attributes,
+                Some(vec!(TableDefinitionAttribute::Comment(
+                    find_string_literal(cql, "'comment ca va'")
+                )))
+            );
+        }
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_parsing_create_materialized_view_with_compound_primary_key() {
+    let cql = CREATE_MATERIALIZED_VIEW_WITH_COMPOUND_PRIMARY_KEY;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::MaterializedView(
+            CreateMaterializedViewStatement {
+                if_not_exists: false,
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+                view_name: find_token(cql, "big_data_view"),
+                select_clause: SelectClause::Selectors(vec!(
+                    SelectorDefinition {
+                        selector: Selector::Term(ExpressionTerm::Column(find_token(
+                            cql,
+                            "text_column"
+                        ))),
+                        alias: None,
+                    },
+                    SelectorDefinition {
+                        selector: Selector::Term(ExpressionTerm::Column(find_token(
+                            cql,
+                            "uuid_column"
+                        ))),
+                        alias: None,
+                    },
+                )),
+                base_keyspace_name: Some(find_nth_token(cql, 1, "big_data_keyspace")),
+                base_table_name: find_token(cql, "big_data_table"),
+                where_clause: WhereClause {
+                    relations: vec!(
+                        WhereClauseRelation {
+                            columns: RelationColumns::Single(find_nth_token(cql, 1, "text_column")),
+                            operator: RelationOperator::IsNot,
+                            expr_term: ExpressionTerm::Null,
+                        },
+                        WhereClauseRelation {
+                            columns: RelationColumns::Single(find_nth_token(cql, 1, "uuid_column")),
+                            operator: RelationOperator::IsNot,
+                            expr_term: ExpressionTerm::Null,
+                        },
+                    ),
+                },
+                primary_key: PrimaryKeyDefinition::Compound {
+                    partition: rfind_token(cql, "text_column"),
+                    clustering: vec!(rfind_token(cql, "uuid_column")),
+                },
+                attributes: None,
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_parsing_create_role() {
     let cql = CREATE_ROLE;
@@ -859,6 +1032,126 @@ fn test_parsing_create_table_with_counter_data_type() {
     );
 }
 
+#[test]
+fn test_parsing_create_table_with_nested_data_types() {
+    let cql = CREATE_TABLE_WITH_NESTED_DATA_TYPES;
+    let native = |native_type| CqlDataType::ValueType(CqlValueType::NativeType(native_type));
+    let int_text_uuid_tuple = || {
+        CqlDataType::Tuple(vec![
+            native(CqlNativeType::Int),
+            native(CqlNativeType::Text),
+            native(CqlNativeType::Uuid),
+        ])
+    };
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Table(
+            CreateTableStatement {
+                if_not_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                column_definitions: vec!(
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "text_column"),
+                        data_type: native(CqlNativeType::Text),
+                        attribute: Some(ColumnDefinitionAttribute::PrimaryKey),
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "list_column"),
+                        data_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                            CqlDataType::Frozen(Box::new(CqlDataType::ValueType(
+                                CqlValueType::UserDefinedType(
+                                    None,
+                                    find_token(cql, "big_data_udt")
+                                )
+                            )))
+                        ))),
+                        attribute: None,
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "set_column"),
+                        data_type: CqlDataType::CollectionType(CqlCollectionType::Set(Box::new(
+                            native(CqlNativeType::Int)
+                        ))),
+                        attribute: None,
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "map_column"),
+                        data_type: CqlDataType::CollectionType(CqlCollectionType::Map(
+                            Box::new(native(CqlNativeType::Text)),
+                            Box::new(CqlDataType::Frozen(Box::new(CqlDataType::CollectionType(
+                                CqlCollectionType::List(Box::new(int_text_uuid_tuple()))
+                            )))),
+                        )),
+                        attribute: None,
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "tuple_column"),
+                        data_type: int_text_uuid_tuple(),
+                        attribute: None,
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "vector_column"),
+                        data_type: CqlDataType::Vector(
+                            Box::new(native(CqlNativeType::Float)),
+                            find_token(cql, "768"),
+                        ),
+                        attribute: None,
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "custom_column"),
+                        data_type: CqlDataType::CustomType(find_string_literal(
+                            cql,
+                            "'org.example.BigDataType'"
+                        )),
+                        attribute: None,
+                    },
+                ),
+                attributes: None,
+                table_alias: None,
+            }
+        )))
+    );
+}
+
+#[test]
+fn test_parsing_create_table_with_explicit_keyspace_udt_collection() {
+    let cql = CREATE_TABLE_WITH_EXPLICIT_KEYSPACE_UDT_COLLECTION;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Table(
+            CreateTableStatement {
+                if_not_exists: false,
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+                table_name: find_token(cql, "big_data_table"),
+                column_definitions: vec!(
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "uuid_column"),
+                        data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                            CqlNativeType::Uuid
+                        )),
+                        attribute: Some(ColumnDefinitionAttribute::PrimaryKey),
+                    },
+                    ColumnDefinition::Column {
+                        column_name: find_token(cql, "list_column"),
+                        data_type: CqlDataType::CollectionType(CqlCollectionType::List(Box::new(
+                            CqlDataType::Frozen(Box::new(CqlDataType::ValueType(
+                                CqlValueType::UserDefinedType(
+                                    Some(rfind_token(cql, "big_data_keyspace")),
+                                    find_token(cql, "big_data_udt")
+                                )
+                            )))
+                        ))),
+                        attribute: None,
+                    },
+                ),
+                attributes: None,
+                table_alias: None,
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_parsing_create_table_if_not_exists() {
     let cql = CREATE_TABLE_IF_NOT_EXISTS;
@@ -1064,6 +1357,33 @@ fn test_parsing_create_table_with_comment() {
     );
 }
 
+#[test]
+fn test_parsing_create_table_with_comment_and_compact_storage() {
+    let cql = CREATE_TABLE_WITH_COMMENT_AND_COMPACT_STORAGE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Table(
+            CreateTableStatement {
+                if_not_exists: false,
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                column_definitions: vec!(ColumnDefinition::Column {
+                    column_name: find_token(cql, "uuid_column"),
+                    data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                        CqlNativeType::Uuid
+                    )),
+                    attribute: Some(ColumnDefinitionAttribute::PrimaryKey),
+                },),
+                attributes: Some(vec!(
+                    TableDefinitionAttribute::Comment(find_string_literal(cql, "'big data!'")),
+                    TableDefinitionAttribute::CompactStorage,
+                )),
+                table_alias: None,
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_parsing_create_table_with_compact_storage() {
     let cql = CREATE_TABLE_WITH_COMPACT_STORAGE;
@@ -1117,6 +1437,32 @@ fn test_parsing_create_table_with_compaction() {
     );
 }
 
+#[test]
+fn test_parsing_create_table_with_table_options() {
+    let cql = CREATE_TABLE_WITH_TABLE_OPTIONS;
+    match parse_cql(cql.to_string()).unwrap().remove(0) {
+        CqlStatement::Create(CreateStatement::Table(statement)) => {
+            assert_eq!(
+                statement.attributes,
+                Some(vec!(
+                    TableDefinitionAttribute::Option(
+                        find_token(cql, "default_time_to_live"),
+                        ExpressionTerm::Number(find_token(cql, "86400")),
+                    ),
+                    TableDefinitionAttribute::Option(
+                        find_token(cql, "caching"),
+                        ExpressionTerm::Map(vec!((
+                            ExpressionTerm::String(find_string_literal(cql, "'keys'")),
+                            ExpressionTerm::String(find_string_literal(cql, "'ALL'")),
+                        ))),
+                    ),
+                ))
+            );
+        }
+        _ => panic!(),
+    }
+}
+
 #[test]
 fn test_parsing_create_table_with_clustering_order() {
     for (cql, clustering_order) in [
@@ -1423,6 +1769,30 @@ fn test_parsing_create_type_with_explicit_keyspace_and_multiple_fields() {
     );
 }
 
+#[test]
+fn test_parsing_create_type_with_explicit_keyspace_udt_field() {
+    let cql = CREATE_EXPLICIT_KEYSPACE_UDT_WITH_EXPLICIT_KEYSPACE_UDT_FIELD;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Create(CreateStatement::Type(
+            CreateTypeStatement {
+                type_name: find_token(cql, "big_data_udt"),
+                if_not_exists: false,
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+                fields: vec![(
+                    find_token(cql, "udt_attr"),
+                    CqlDataType::Frozen(Box::new(CqlDataType::ValueType(
+                        CqlValueType::UserDefinedType(
+                            Some(rfind_token(cql, "big_data_keyspace")),
+                            find_token(cql, "other_udt"),
+                        )
+                    ))),
+                )],
+            }
+        )))
+    );
+}
+
 #[test]
 fn test_parsing_create_user_without_password() {
     let cql = CREATE_USER_WITHOUT_PASSWORD;
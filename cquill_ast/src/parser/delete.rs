@@ -0,0 +1,37 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::ParseResult;
+use crate::parser::expression::*;
+use crate::parser::iter::*;
+
+use std::{iter::Peekable, slice::Iter, sync::Arc};
+
+pub fn parse_delete_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<DeleteStatement> {
+    let mut selections = Vec::new();
+    if !peek_next_match(iter, FromKeyword)? {
+        loop {
+            selections.push(parse_assignment_selection(cql, iter)?);
+            if pop_next_if(iter, Comma).is_none() {
+                break;
+            }
+        }
+    }
+    pop_next_match(iter, FromKeyword)?;
+    let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+    let using = pop_if_update_parameters(cql, iter)?;
+    pop_next_match(iter, WhereKeyword)?;
+    let where_clause = parse_where_clause(cql, iter)?;
+    let if_behavior = parse_if_behavior(cql, iter)?;
+    Ok(DeleteStatement {
+        selections,
+        keyspace_name,
+        table_name,
+        using,
+        where_clause,
+        if_behavior,
+    })
+}
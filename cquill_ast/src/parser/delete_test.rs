@@ -0,0 +1,106 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    ast::*,
+    parse_cql,
+    parser::testing::{find_string_literal, find_token, rfind_token},
+    test_cql::*,
+};
+
+fn where_text_column_equals_big_data(cql: &str) -> WhereClause {
+    WhereClause {
+        relations: vec![WhereClauseRelation {
+            columns: RelationColumns::Single(find_token(cql, "text_column")),
+            operator: RelationOperator::Equal,
+            expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+        }],
+    }
+}
+
+fn delete_uuid_column(cql: &str) -> DeleteStatement {
+    DeleteStatement {
+        selections: vec![AssignmentSelection::Column {
+            column_name: find_token(cql, "uuid_column"),
+        }],
+        keyspace_name: None,
+        table_name: find_token(cql, "big_data_table"),
+        using: vec![],
+        where_clause: where_text_column_equals_big_data(cql),
+        if_behavior: None,
+    }
+}
+
+#[test]
+fn test_delete_single_column() {
+    let cql = DELETE_SINGLE_COLUMN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Delete(delete_uuid_column(cql)))
+    );
+}
+
+#[test]
+fn test_delete_multiple_columns() {
+    let cql = DELETE_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Delete(DeleteStatement {
+            selections: vec![
+                AssignmentSelection::Column {
+                    column_name: find_token(cql, "uuid_column"),
+                },
+                AssignmentSelection::Column {
+                    column_name: find_token(cql, "int_column"),
+                },
+            ],
+            ..delete_uuid_column(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_delete_if_exists() {
+    let cql = DELETE_IF_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Delete(DeleteStatement {
+            if_behavior: Some(UpdateIfBehavior::Exists),
+            ..delete_uuid_column(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_delete_if_condition() {
+    let cql = DELETE_IF_CONDITION;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Delete(DeleteStatement {
+            if_behavior: Some(UpdateIfBehavior::Conditional(vec![UpdateIfCondition {
+                selection: AssignmentSelection::Column {
+                    column_name: rfind_token(cql, "uuid_column"),
+                },
+                operator: RelationOperator::NotEqual,
+                expr_term: ExpressionTerm::Uuid(find_token(
+                    cql,
+                    "89b7aa7a-8776-460b-8e1a-60cb4bcd523c"
+                )),
+            }])),
+            ..delete_uuid_column(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_delete_using_timestamp() {
+    let cql = DELETE_USING_TIMESTAMP;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Delete(DeleteStatement {
+            using: vec![UpdateParameter::Timestamp(ExpressionTerm::String(
+                find_string_literal(cql, "'2023-11-14T04:05+0000'")
+            ))],
+            ..delete_uuid_column(cql)
+        }))
+    );
+}
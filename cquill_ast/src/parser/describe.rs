@@ -0,0 +1,166 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::iter::{pop_identifier, pop_keyspace_object_name, pop_next, pop_next_if};
+use crate::parser::{ParseError, ParseResult};
+use std::iter::Peekable;
+use std::slice::Iter;
+use std::sync::Arc;
+
+pub fn parse_describe_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<DescribeStatement> {
+    let target = match iter.peek() {
+        None => return Err(ParseError::InvalidCql),
+        Some(peeked) => match peeked.name {
+            AggregateKeyword => {
+                _ = iter.next();
+                let (keyspace_name, aggregate_name) = pop_keyspace_object_name(cql, iter)?;
+                DescribeTarget::Aggregate {
+                    keyspace_name,
+                    aggregate_name,
+                }
+            }
+            FullKeyword => {
+                _ = iter.next();
+                match pop_next(iter)?
+                    .to_token_view(cql)
+                    .value()
+                    .to_lowercase()
+                    .as_str()
+                {
+                    "schema" => DescribeTarget::Schema { full: true },
+                    _ => return Err(ParseError::InvalidCql),
+                }
+            }
+            FunctionKeyword => {
+                _ = iter.next();
+                let (keyspace_name, function_name) = pop_keyspace_object_name(cql, iter)?;
+                DescribeTarget::Function {
+                    keyspace_name,
+                    function_name,
+                }
+            }
+            FunctionsKeyword => {
+                _ = iter.next();
+                DescribeTarget::Functions
+            }
+            IndexKeyword => {
+                _ = iter.next();
+                let (keyspace_name, index_name) = pop_keyspace_object_name(cql, iter)?;
+                DescribeTarget::Index {
+                    keyspace_name,
+                    index_name,
+                }
+            }
+            KeyspaceKeyword => {
+                _ = iter.next();
+                parse_describe_keyspace_target(cql, iter, false)?
+            }
+            KeyspacesKeyword => {
+                _ = iter.next();
+                DescribeTarget::Keyspaces
+            }
+            MaterializedKeyword => {
+                _ = iter.next();
+                match pop_next(iter)?.name {
+                    ViewKeyword => {
+                        let (keyspace_name, view_name) = pop_keyspace_object_name(cql, iter)?;
+                        DescribeTarget::MaterializedView {
+                            keyspace_name,
+                            view_name,
+                        }
+                    }
+                    _ => return Err(ParseError::InvalidCql),
+                }
+            }
+            TableKeyword => {
+                _ = iter.next();
+                let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+                DescribeTarget::Table {
+                    keyspace_name,
+                    table_name,
+                }
+            }
+            TablesKeyword => {
+                _ = iter.next();
+                DescribeTarget::Tables
+            }
+            TypeKeyword => {
+                _ = iter.next();
+                let (keyspace_name, type_name) = pop_keyspace_object_name(cql, iter)?;
+                DescribeTarget::Type {
+                    keyspace_name,
+                    type_name,
+                }
+            }
+            Identifier => {
+                // cluster, schema, types, aggregates and only are not reserved keywords and
+                // lex as identifiers
+                let identifier = peeked.to_token_view(cql).value().to_lowercase();
+                match identifier.as_str() {
+                    "aggregates" => {
+                        _ = iter.next();
+                        DescribeTarget::Aggregates
+                    }
+                    "cluster" => {
+                        _ = iter.next();
+                        DescribeTarget::Cluster
+                    }
+                    "only" => {
+                        _ = iter.next();
+                        match pop_next(iter)?.name {
+                            KeyspaceKeyword => parse_describe_keyspace_target(cql, iter, true)?,
+                            _ => return Err(ParseError::InvalidCql),
+                        }
+                    }
+                    "schema" => {
+                        _ = iter.next();
+                        DescribeTarget::Schema { full: false }
+                    }
+                    "types" => {
+                        _ = iter.next();
+                        DescribeTarget::Types
+                    }
+                    _ => {
+                        let (keyspace_name, object_name) = pop_keyspace_object_name(cql, iter)?;
+                        DescribeTarget::Object {
+                            keyspace_name,
+                            object_name,
+                        }
+                    }
+                }
+            }
+            _ => return Err(ParseError::InvalidCql),
+        },
+    };
+    let with_internals = match pop_next_if(iter, WithKeyword) {
+        None => false,
+        Some(_) => match pop_identifier(cql, iter)?.value().to_lowercase().as_str() {
+            "internals" => true,
+            _ => return Err(ParseError::InvalidCql),
+        },
+    };
+    Ok(DescribeStatement {
+        target,
+        with_internals,
+    })
+}
+
+fn parse_describe_keyspace_target(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+    only: bool,
+) -> ParseResult<DescribeTarget> {
+    let keyspace_name = match iter.peek() {
+        Some(Token {
+            name: Identifier, ..
+        }) => Some(pop_identifier(cql, iter)?),
+        _ => None,
+    };
+    Ok(DescribeTarget::Keyspace {
+        only,
+        keyspace_name,
+    })
+}
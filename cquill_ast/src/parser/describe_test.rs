@@ -0,0 +1,196 @@
+use crate::ast::{CqlStatement, DescribeStatement, DescribeTarget};
+use crate::parse_cql;
+use crate::parser::testing::find_token;
+use crate::test_cql::*;
+
+use pretty_assertions::assert_eq;
+
+fn describe(target: DescribeTarget) -> Vec<CqlStatement> {
+    vec![CqlStatement::Describe(DescribeStatement {
+        target,
+        with_internals: false,
+    })]
+}
+
+#[test]
+fn test_parsing_describe_cluster() {
+    assert_eq!(
+        parse_cql(DESCRIBE_CLUSTER.to_string()).unwrap(),
+        describe(DescribeTarget::Cluster)
+    );
+}
+
+#[test]
+fn test_parsing_describe_schema() {
+    assert_eq!(
+        parse_cql(DESCRIBE_SCHEMA.to_string()).unwrap(),
+        describe(DescribeTarget::Schema { full: false })
+    );
+    assert_eq!(
+        parse_cql(DESCRIBE_FULL_SCHEMA.to_string()).unwrap(),
+        describe(DescribeTarget::Schema { full: true })
+    );
+}
+
+#[test]
+fn test_parsing_describe_plural_targets() {
+    for (cql, target) in [
+        (DESCRIBE_KEYSPACES, DescribeTarget::Keyspaces),
+        (DESCRIBE_TABLES, DescribeTarget::Tables),
+        (DESCRIBE_TYPES, DescribeTarget::Types),
+        (DESCRIBE_FUNCTIONS, DescribeTarget::Functions),
+        (DESCRIBE_AGGREGATES, DescribeTarget::Aggregates),
+    ] {
+        assert_eq!(parse_cql(cql.to_string()).unwrap(), describe(target));
+    }
+}
+
+#[test]
+fn test_parsing_describe_keyspace() {
+    assert_eq!(
+        parse_cql(DESCRIBE_KEYSPACE.to_string()).unwrap(),
+        describe(DescribeTarget::Keyspace {
+            only: false,
+            keyspace_name: Some(find_token(DESCRIBE_KEYSPACE, "big_data_keyspace")),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_keyspace_in_use() {
+    assert_eq!(
+        parse_cql(DESCRIBE_KEYSPACE_IN_USE.to_string()).unwrap(),
+        describe(DescribeTarget::Keyspace {
+            only: false,
+            keyspace_name: None,
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_only_keyspace() {
+    assert_eq!(
+        parse_cql(DESCRIBE_ONLY_KEYSPACE.to_string()).unwrap(),
+        describe(DescribeTarget::Keyspace {
+            only: true,
+            keyspace_name: Some(find_token(DESCRIBE_ONLY_KEYSPACE, "big_data_keyspace")),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_keyspace_with_internals() {
+    let cql = DESCRIBE_KEYSPACE_WITH_INTERNALS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Describe(DescribeStatement {
+            target: DescribeTarget::Keyspace {
+                only: false,
+                keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+            },
+            with_internals: true,
+        }))
+    );
+}
+
+#[test]
+fn test_parsing_describe_table() {
+    assert_eq!(
+        parse_cql(DESCRIBE_TABLE.to_string()).unwrap(),
+        describe(DescribeTarget::Table {
+            keyspace_name: None,
+            table_name: find_token(DESCRIBE_TABLE, "big_data_table"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_table_explicit_keyspace() {
+    let cql = DESCRIBE_TABLE_EXPLICIT_KEYSPACE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        describe(DescribeTarget::Table {
+            keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+            table_name: find_token(cql, "big_data_table"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_desc_table() {
+    assert_eq!(
+        parse_cql(DESC_TABLE.to_string()).unwrap(),
+        describe(DescribeTarget::Table {
+            keyspace_name: None,
+            table_name: find_token(DESC_TABLE, "big_data_table"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_index() {
+    assert_eq!(
+        parse_cql(DESCRIBE_INDEX.to_string()).unwrap(),
+        describe(DescribeTarget::Index {
+            keyspace_name: None,
+            index_name: find_token(DESCRIBE_INDEX, "big_data_index"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_materialized_view() {
+    assert_eq!(
+        parse_cql(DESCRIBE_MATERIALIZED_VIEW.to_string()).unwrap(),
+        describe(DescribeTarget::MaterializedView {
+            keyspace_name: None,
+            view_name: find_token(DESCRIBE_MATERIALIZED_VIEW, "big_data_view"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_type() {
+    assert_eq!(
+        parse_cql(DESCRIBE_TYPE.to_string()).unwrap(),
+        describe(DescribeTarget::Type {
+            keyspace_name: None,
+            type_name: find_token(DESCRIBE_TYPE, "big_data_type"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_function() {
+    let cql = DESCRIBE_FUNCTION;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        describe(DescribeTarget::Function {
+            keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+            function_name: find_token(cql, "big_data_function"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_aggregate() {
+    assert_eq!(
+        parse_cql(DESCRIBE_AGGREGATE.to_string()).unwrap(),
+        describe(DescribeTarget::Aggregate {
+            keyspace_name: None,
+            aggregate_name: find_token(DESCRIBE_AGGREGATE, "big_data_agg"),
+        })
+    );
+}
+
+#[test]
+fn test_parsing_describe_object() {
+    let cql = DESCRIBE_OBJECT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        describe(DescribeTarget::Object {
+            keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+            object_name: find_token(cql, "big_data_table"),
+        })
+    );
+}
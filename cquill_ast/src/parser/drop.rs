@@ -1,11 +1,11 @@
 use crate::ast::*;
 use crate::lex::Token;
 use crate::lex::TokenName::*;
-use crate::parser::ParseResult;
 use crate::parser::iter::{
     pop_identifier, pop_if_function_signature, pop_keyspace_object_name, pop_next_match,
     pop_sequence,
 };
+use crate::parser::{ParseError, ParseResult};
 use std::iter::Peekable;
 use std::slice::Iter;
 use std::sync::Arc;
@@ -15,7 +15,7 @@ pub fn parse_drop_statement(
     iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<DropStatement> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(token) => match token.name {
             AggregateKeyword => {
                 parse_drop_aggregate_statement(cql, iter).map(DropStatement::Aggregate)
@@ -37,7 +37,7 @@ pub fn parse_drop_statement(
             TriggerKeyword => parse_drop_trigger_statement(cql, iter).map(DropStatement::Trigger),
             TypeKeyword => parse_drop_type_statement(cql, iter).map(DropStatement::Type),
             UserKeyword => parse_drop_user_statement(cql, iter).map(DropStatement::User),
-            _ => todo!("parse error"),
+            _ => Err(ParseError::InvalidCql),
         },
     }
 }
@@ -0,0 +1,404 @@
+use crate::ast::*;
+use crate::lex::{Token, TokenName};
+use crate::parser::iter::*;
+use crate::parser::{ParseError, ParseResult};
+use TokenName::*;
+
+use std::{iter::Peekable, slice::Iter, sync::Arc};
+
+/// Parses a term including arithmetic operations with `*`, `/` and `%` taking precedence
+/// over `+` and `-`.
+pub fn parse_expression_term(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<ExpressionTerm> {
+    parse_arithmetic_term(cql, iter, 0)
+}
+
+fn parse_arithmetic_term(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+    min_precedence: u8,
+) -> ParseResult<ExpressionTerm> {
+    let mut left = parse_operand_term(cql, iter)?;
+    loop {
+        let operator = match iter.peek().and_then(|peeked| arithmetic_operator(peeked)) {
+            None => break,
+            Some(operator) => operator,
+        };
+        let precedence = operator.precedence();
+        if precedence < min_precedence {
+            break;
+        }
+        _ = iter.next();
+        let right = parse_arithmetic_term(cql, iter, precedence + 1)?;
+        left = ExpressionTerm::Arithmetic {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+        };
+    }
+    Ok(left)
+}
+
+fn arithmetic_operator(token: &Token) -> Option<ArithmeticOperator> {
+    Some(match token.name {
+        Plus => ArithmeticOperator::Add,
+        Minus => ArithmeticOperator::Subtract,
+        Star => ArithmeticOperator::Multiply,
+        Divide => ArithmeticOperator::Divide,
+        Modulus => ArithmeticOperator::Modulo,
+        _ => return None,
+    })
+}
+
+impl ArithmeticOperator {
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            ArithmeticOperator::Add | ArithmeticOperator::Subtract => 1,
+            ArithmeticOperator::Divide
+            | ArithmeticOperator::Modulo
+            | ArithmeticOperator::Multiply => 2,
+        }
+    }
+}
+
+fn parse_operand_term(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<ExpressionTerm> {
+    let popped = match iter.next() {
+        None => return Err(ParseError::InvalidCql),
+        Some(popped) => popped,
+    };
+    Ok(match &popped.name {
+        StringLiteral(style) => ExpressionTerm::String(StringView {
+            cql: cql.clone(),
+            range: popped.range.clone(),
+            style: style.clone(),
+        }),
+        NumberLiteral => ExpressionTerm::Number(popped.to_token_view(cql)),
+        UuidLiteral => ExpressionTerm::Uuid(popped.to_token_view(cql)),
+        BlobLiteral => ExpressionTerm::Blob(popped.to_token_view(cql)),
+        TrueKeyword => ExpressionTerm::Boolean(true),
+        FalseKeyword => ExpressionTerm::Boolean(false),
+        NullKeyword => ExpressionTerm::Null,
+        Minus => ExpressionTerm::Negated(Box::new(parse_operand_term(cql, iter)?)),
+        QuestionMark => ExpressionTerm::BindMarker(BindMarker::Anonymous),
+        Colon => ExpressionTerm::BindMarker(BindMarker::Named(pop_identifier(cql, iter)?)),
+        LeftSquareBracket => {
+            ExpressionTerm::List(pop_expression_terms_until(cql, iter, RightSquareBracket)?)
+        }
+        LeftCurvedBracket => parse_curved_bracket_literal(cql, iter)?,
+        LeftParenthesis => {
+            ExpressionTerm::Tuple(pop_expression_terms_until(cql, iter, RightParenthesis)?)
+        }
+        Identifier => {
+            if peek_next_is(iter, LeftParenthesis) {
+                ExpressionTerm::FunctionCall(parse_function_call(
+                    cql,
+                    iter,
+                    None,
+                    popped.to_token_view(cql),
+                )?)
+            } else if pop_next_if(iter, Dot).is_some() {
+                let function_name = pop_identifier(cql, iter)?;
+                ExpressionTerm::FunctionCall(parse_function_call(
+                    cql,
+                    iter,
+                    Some(popped.to_token_view(cql)),
+                    function_name,
+                )?)
+            } else {
+                let token_view = popped.to_token_view(cql);
+                let value = token_view.value();
+                if is_duration_literal(&value) {
+                    ExpressionTerm::Duration(token_view)
+                } else {
+                    ExpressionTerm::Column(token_view)
+                }
+            }
+        }
+        // keywords that are also names of native functions such as token(), ttl() and uuid()
+        SumKeyword | TokenKeyword | TtlKeyword | UuidKeyword
+            if peek_next_is(iter, LeftParenthesis) =>
+        {
+            ExpressionTerm::FunctionCall(parse_function_call(
+                cql,
+                iter,
+                None,
+                popped.to_token_view(cql),
+            )?)
+        }
+        _ => return Err(ParseError::InvalidCql),
+    })
+}
+
+fn peek_next_is(iter: &mut Peekable<Iter<Token>>, next: TokenName) -> bool {
+    iter.peek().is_some_and(|peeked| peeked.name == next)
+}
+
+fn parse_function_call(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+    keyspace_name: Option<TokenView>,
+    function_name: TokenView,
+) -> ParseResult<FunctionCall> {
+    pop_next_match(iter, LeftParenthesis)?;
+    let args = pop_expression_terms_until(cql, iter, RightParenthesis)?;
+    Ok(FunctionCall {
+        keyspace_name,
+        function_name,
+        args,
+    })
+}
+
+/// Parses a `{}` literal as a map, set or UDT literal. UDT literals are distinguished from maps
+/// by keys that are identifiers and an empty `{}` is parsed as an empty map.
+fn parse_curved_bracket_literal(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<ExpressionTerm> {
+    if pop_next_if(iter, RightCurvedBracket).is_some() {
+        return Ok(ExpressionTerm::Map(Vec::new()));
+    }
+    let mut lookahead = iter.clone();
+    let is_udt_literal = lookahead.next().is_some_and(|t| t.name == Identifier)
+        && lookahead.next().is_some_and(|t| t.name == Colon);
+    if is_udt_literal {
+        let mut fields = Vec::new();
+        loop {
+            let field_name = pop_identifier(cql, iter)?;
+            pop_next_match(iter, Colon)?;
+            fields.push((field_name, parse_expression_term(cql, iter)?));
+            if pop_next_if(iter, Comma).is_none() {
+                break;
+            }
+        }
+        pop_next_match(iter, RightCurvedBracket)?;
+        return Ok(ExpressionTerm::UserDefinedType(fields));
+    }
+    let first = parse_expression_term(cql, iter)?;
+    if pop_next_if(iter, Colon).is_some() {
+        let mut entries = vec![(first, parse_expression_term(cql, iter)?)];
+        while pop_next_if(iter, Comma).is_some() {
+            let key = parse_expression_term(cql, iter)?;
+            pop_next_match(iter, Colon)?;
+            entries.push((key, parse_expression_term(cql, iter)?));
+        }
+        pop_next_match(iter, RightCurvedBracket)?;
+        Ok(ExpressionTerm::Map(entries))
+    } else {
+        let mut elements = vec![first];
+        while pop_next_if(iter, Comma).is_some() {
+            elements.push(parse_expression_term(cql, iter)?);
+        }
+        pop_next_match(iter, RightCurvedBracket)?;
+        Ok(ExpressionTerm::Set(elements))
+    }
+}
+
+/// Pops comma-separated terms until and including the closing token. Opening token of the list
+/// should already have been popped.
+pub fn pop_expression_terms_until(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+    closing: TokenName,
+) -> ParseResult<Vec<ExpressionTerm>> {
+    let mut terms = Vec::new();
+    if iter.next_if(|t| t.name == closing).is_some() {
+        return Ok(terms);
+    }
+    loop {
+        terms.push(parse_expression_term(cql, iter)?);
+        let popped = pop_next(iter)?;
+        if popped.name == Comma {
+            continue;
+        } else if popped.name == closing {
+            break;
+        } else {
+            return Err(ParseError::InvalidCql);
+        }
+    }
+    Ok(terms)
+}
+
+/// Durations are lexed as identifiers and are either a sequence of quantities and units such as
+/// `1h30m` or an ISO 8601 format such as `P1DT2H`.
+fn is_duration_literal(s: &str) -> bool {
+    let lowercase = s.to_lowercase();
+    if lowercase.starts_with(|c: char| c.is_ascii_digit()) {
+        let mut rest = lowercase.as_str();
+        while !rest.is_empty() {
+            let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+            if digits == 0 {
+                return false;
+            }
+            rest = &rest[digits..];
+            let unit = ["mo", "ms", "us", "µs", "ns", "y", "w", "d", "h", "m", "s"]
+                .iter()
+                .find(|unit| rest.starts_with(*unit));
+            match unit {
+                None => return false,
+                Some(unit) => rest = &rest[unit.len()..],
+            }
+        }
+        true
+    } else if let Some(rest) = lowercase.strip_prefix('p') {
+        let mut quantity = false;
+        let mut components = 0;
+        let mut time = false;
+        for c in rest.chars() {
+            if c.is_ascii_digit() {
+                quantity = true;
+            } else if c == 't' && !time && !quantity {
+                time = true;
+            } else if quantity && "ymwdhs".contains(c) {
+                quantity = false;
+                components += 1;
+            } else {
+                return false;
+            }
+        }
+        components > 0 && !quantity
+    } else {
+        false
+    }
+}
+
+pub fn parse_where_clause(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<WhereClause> {
+    let mut relations: Vec<WhereClauseRelation> = Vec::new();
+    loop {
+        let columns = if pop_next_if(iter, LeftParenthesis).is_some() {
+            let column_names = pop_comma_separated_identifiers(cql, iter)?;
+            pop_next_match(iter, RightParenthesis)?;
+            RelationColumns::Tuple(column_names)
+        } else if pop_next_if(iter, TokenKeyword).is_some() {
+            pop_next_match(iter, LeftParenthesis)?;
+            let column_names = pop_comma_separated_identifiers(cql, iter)?;
+            pop_next_match(iter, RightParenthesis)?;
+            RelationColumns::Token(column_names)
+        } else {
+            RelationColumns::Single(pop_identifier(cql, iter)?)
+        };
+        let operator = parse_relation_operator(iter)?;
+        let expr_term = parse_expression_term(cql, iter)?;
+        relations.push(WhereClauseRelation {
+            columns,
+            operator,
+            expr_term,
+        });
+        if pop_next_if(iter, AndKeyword).is_none() {
+            break;
+        }
+    }
+    Ok(WhereClause { relations })
+}
+
+pub fn parse_relation_operator(iter: &mut Peekable<Iter<Token>>) -> ParseResult<RelationOperator> {
+    let token = pop_next(iter)?;
+    Ok(match token.name {
+        Equal => RelationOperator::Equal,
+        NotEqual => RelationOperator::NotEqual,
+        GreaterThan => RelationOperator::GreaterThan,
+        GreaterThanEqual => RelationOperator::GreaterThanEqual,
+        LessThan => RelationOperator::LessThan,
+        LessThanEqual => RelationOperator::LessThanEqual,
+        InKeyword => RelationOperator::In,
+        IsKeyword => {
+            pop_next_match(iter, NotKeyword)?;
+            RelationOperator::IsNot
+        }
+        ContainsKeyword => {
+            if pop_next_if(iter, KeyKeyword).is_some() {
+                RelationOperator::ContainsKey
+            } else {
+                RelationOperator::Contains
+            }
+        }
+        _ => return Err(ParseError::InvalidCql),
+    })
+}
+
+pub fn parse_assignment_selection(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<AssignmentSelection> {
+    let column_name = pop_identifier(cql, iter)?;
+    if pop_next_if(iter, LeftSquareBracket).is_some() {
+        let expr_term = parse_expression_term(cql, iter)?;
+        pop_next_match(iter, RightSquareBracket)?;
+        Ok(AssignmentSelection::ColumnAccess {
+            column_name,
+            expr_term,
+        })
+    } else if pop_next_if(iter, Dot).is_some() {
+        Ok(AssignmentSelection::ColumnField {
+            column_name,
+            field_name: pop_identifier(cql, iter)?,
+        })
+    } else {
+        Ok(AssignmentSelection::Column { column_name })
+    }
+}
+
+/// Parses `IF EXISTS` or `IF condition AND condition` of `UPDATE` and `DELETE` statements.
+pub fn parse_if_behavior(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Option<UpdateIfBehavior>> {
+    match pop_next_if(iter, IfKeyword) {
+        None => Ok(None),
+        Some(_) => match iter.peek() {
+            None => Err(ParseError::InvalidCql),
+            Some(token) => match token.name {
+                ExistsKeyword => {
+                    pop_next(iter)?;
+                    Ok(Some(UpdateIfBehavior::Exists))
+                }
+                Identifier => {
+                    let mut conditions: Vec<UpdateIfCondition> = Vec::new();
+                    loop {
+                        let selection = parse_assignment_selection(cql, iter)?;
+                        let operator = parse_relation_operator(iter)?;
+                        let expr_term = parse_expression_term(cql, iter)?;
+                        conditions.push(UpdateIfCondition {
+                            selection,
+                            operator,
+                            expr_term,
+                        });
+                        if pop_next_if(iter, AndKeyword).is_none() {
+                            break;
+                        }
+                    }
+                    Ok(Some(UpdateIfBehavior::Conditional(conditions)))
+                }
+                _ => Err(ParseError::InvalidCql),
+            },
+        },
+    }
+}
+
+/// Parses `USING TTL term AND TIMESTAMP term` returning an empty vec without a `USING` clause.
+pub fn pop_if_update_parameters(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Vec<UpdateParameter>> {
+    let mut parameters = Vec::new();
+    if pop_next_if(iter, UsingKeyword).is_some() {
+        loop {
+            parameters.push(match pop_next(iter)?.name {
+                TimestampKeyword => UpdateParameter::Timestamp(parse_expression_term(cql, iter)?),
+                TtlKeyword => UpdateParameter::Ttl(parse_expression_term(cql, iter)?),
+                _ => return Err(ParseError::InvalidCql),
+            });
+            if pop_next_if(iter, AndKeyword).is_none() {
+                break;
+            }
+        }
+    }
+    Ok(parameters)
+}
@@ -0,0 +1,296 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    ast::*,
+    parse_cql,
+    parser::testing::{find_nth_token, find_string_literal, find_token, rfind_token},
+    test_cql::*,
+};
+
+#[test]
+fn test_arithmetic_precedence() {
+    let cql = UPDATE_ARITHMETIC;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            using: vec![],
+            assignments: vec![Assignment {
+                selection: AssignmentSelection::Column {
+                    column_name: find_token(cql, "int_column"),
+                },
+                expr_term: ExpressionTerm::Arithmetic {
+                    left: Box::new(ExpressionTerm::Column(find_nth_token(cql, 1, "int_column"))),
+                    operator: ArithmeticOperator::Add,
+                    right: Box::new(ExpressionTerm::Arithmetic {
+                        left: Box::new(ExpressionTerm::Number(find_token(cql, "2"))),
+                        operator: ArithmeticOperator::Multiply,
+                        right: Box::new(ExpressionTerm::Number(find_token(cql, "3"))),
+                    }),
+                },
+            }],
+            where_clause: WhereClause {
+                relations: vec![WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql, "text_column")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                }],
+            },
+            if_behavior: None,
+        })),
+    );
+}
+
+#[test]
+fn test_collection_element_assignment() {
+    let cql = UPDATE_COLLECTION_ELEMENT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            using: vec![],
+            assignments: vec![Assignment {
+                selection: AssignmentSelection::ColumnAccess {
+                    column_name: find_token(cql, "map_column"),
+                    expr_term: ExpressionTerm::String(find_string_literal(cql, "'key'")),
+                },
+                expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+            }],
+            where_clause: WhereClause {
+                relations: vec![WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql, "text_column")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::String(find_string_literal(cql, "'more data!'")),
+                }],
+            },
+            if_behavior: None,
+        })),
+    );
+}
+
+#[test]
+fn test_collection_and_tuple_literals() {
+    let cql = INSERT_COLLECTION_LITERALS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: InsertValues::Values {
+                column_names: vec![
+                    find_token(cql, "list_column"),
+                    find_token(cql, "set_column"),
+                    find_token(cql, "map_column"),
+                    find_token(cql, "tuple_column"),
+                ],
+                values: vec![
+                    ExpressionTerm::List(vec![
+                        ExpressionTerm::Number(find_token(cql, "1")),
+                        ExpressionTerm::Number(find_token(cql, "2")),
+                    ]),
+                    ExpressionTerm::Set(vec![
+                        ExpressionTerm::String(find_string_literal(cql, "'a'")),
+                        ExpressionTerm::String(find_string_literal(cql, "'b'")),
+                    ]),
+                    ExpressionTerm::Map(vec![(
+                        ExpressionTerm::String(find_string_literal(cql, "'k'")),
+                        ExpressionTerm::Negated(Box::new(ExpressionTerm::Number(rfind_token(
+                            cql, "1"
+                        )))),
+                    )]),
+                    ExpressionTerm::Tuple(vec![
+                        ExpressionTerm::Boolean(true),
+                        ExpressionTerm::Null,
+                    ]),
+                ],
+            },
+            if_not_exists: false,
+            using: vec![],
+        })),
+    );
+}
+
+#[test]
+fn test_blob_and_exponent_literals() {
+    let cql = INSERT_BLOB_AND_EXPONENT_LITERALS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: InsertValues::Values {
+                column_names: vec![
+                    find_token(cql, "blob_column"),
+                    find_token(cql, "float_column"),
+                    find_token(cql, "double_column"),
+                ],
+                values: vec![
+                    ExpressionTerm::Blob(find_token(cql, "0xcafe")),
+                    ExpressionTerm::Number(find_token(cql, "1.5e10")),
+                    ExpressionTerm::Negated(Box::new(ExpressionTerm::Number(find_token(
+                        cql, "2E-3"
+                    )))),
+                ],
+            },
+            if_not_exists: false,
+            using: vec![],
+        })),
+    );
+}
+
+#[test]
+fn test_blob_literal_assignment_and_relation() {
+    let cql = UPDATE_BLOB_LITERALS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            using: vec![],
+            assignments: vec![Assignment {
+                selection: AssignmentSelection::Column {
+                    column_name: find_token(cql, "blob_column"),
+                },
+                expr_term: ExpressionTerm::Blob(find_token(cql, "0xcafe")),
+            }],
+            where_clause: WhereClause {
+                relations: vec![WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql, "blob_key")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::Blob(find_token(cql, "0xBEEF")),
+                }],
+            },
+            if_behavior: None,
+        })),
+    );
+}
+
+#[test]
+fn test_udt_literal() {
+    let cql = INSERT_UDT_LITERAL;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: InsertValues::Values {
+                column_names: vec![find_token(cql, "big_data_udt_column")],
+                values: vec![ExpressionTerm::UserDefinedType(vec![
+                    (
+                        find_token(cql, "int_attr"),
+                        ExpressionTerm::Number(find_token(cql, "1")),
+                    ),
+                    (
+                        find_token(cql, "text_attr"),
+                        ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                    ),
+                ])],
+            },
+            if_not_exists: false,
+            using: vec![],
+        })),
+    );
+}
+
+#[test]
+fn test_function_call_and_duration_literals() {
+    let cql = INSERT_FUNCTION_CALL_AND_DURATION;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: InsertValues::Values {
+                column_names: vec![
+                    find_token(cql, "uuid_column"),
+                    find_token(cql, "duration_column"),
+                    find_token(cql, "iso_duration_column"),
+                ],
+                values: vec![
+                    ExpressionTerm::FunctionCall(FunctionCall {
+                        keyspace_name: None,
+                        function_name: rfind_token(cql, "uuid"),
+                        args: vec![],
+                    }),
+                    ExpressionTerm::Duration(find_token(cql, "1h30m")),
+                    ExpressionTerm::Duration(find_token(cql, "P1DT2H")),
+                ],
+            },
+            if_not_exists: false,
+            using: vec![],
+        })),
+    );
+}
+
+#[test]
+fn test_bind_markers() {
+    let cql = SELECT_BIND_MARKERS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            json: false,
+            distinct: false,
+            select_clause: SelectClause::All,
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            where_clause: Some(WhereClause {
+                relations: vec![
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "text_column")),
+                        operator: RelationOperator::Equal,
+                        expr_term: ExpressionTerm::BindMarker(BindMarker::Anonymous),
+                    },
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "int_column")),
+                        operator: RelationOperator::In,
+                        expr_term: ExpressionTerm::BindMarker(BindMarker::Named(find_token(
+                            cql,
+                            "int_values"
+                        ))),
+                    },
+                ],
+            }),
+            group_by: None,
+            order_by: None,
+            per_partition_limit: None,
+            limit: None,
+            allow_filtering: false,
+        })),
+    );
+}
+
+#[test]
+fn test_token_relation() {
+    let cql = SELECT_WHERE_TOKEN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            json: false,
+            distinct: false,
+            select_clause: SelectClause::All,
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            where_clause: Some(WhereClause {
+                relations: vec![WhereClauseRelation {
+                    columns: RelationColumns::Token(vec![find_token(cql, "text_column")]),
+                    operator: RelationOperator::GreaterThan,
+                    expr_term: ExpressionTerm::FunctionCall(FunctionCall {
+                        keyspace_name: None,
+                        function_name: rfind_token(cql, "token"),
+                        args: vec![ExpressionTerm::String(find_string_literal(
+                            cql,
+                            "'big data!'"
+                        ))],
+                    }),
+                }],
+            }),
+            group_by: None,
+            order_by: None,
+            per_partition_limit: None,
+            limit: None,
+            allow_filtering: false,
+        })),
+    );
+}
@@ -0,0 +1,51 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::expression::*;
+use crate::parser::iter::*;
+use crate::parser::{ParseError, ParseResult};
+
+use std::{iter::Peekable, slice::Iter, sync::Arc};
+
+pub fn parse_insert_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<InsertStatement> {
+    pop_next_match(iter, IntoKeyword)?;
+    let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+    let values = if pop_next_if(iter, JsonKeyword).is_some() {
+        let json = parse_expression_term(cql, iter)?;
+        let default = match pop_next_if(iter, DefaultKeyword) {
+            None => None,
+            Some(_) => match pop_next(iter)?.name {
+                NullKeyword => Some(JsonDefault::Null),
+                UnsetKeyword => Some(JsonDefault::Unset),
+                _ => return Err(ParseError::InvalidCql),
+            },
+        };
+        InsertValues::Json { json, default }
+    } else {
+        pop_next_match(iter, LeftParenthesis)?;
+        let column_names = pop_comma_separated_identifiers(cql, iter)?;
+        pop_next_match(iter, RightParenthesis)?;
+        pop_next_match(iter, ValuesKeyword)?;
+        pop_next_match(iter, LeftParenthesis)?;
+        let values = pop_expression_terms_until(cql, iter, RightParenthesis)?;
+        if column_names.len() != values.len() {
+            return Err(ParseError::InvalidCql);
+        }
+        InsertValues::Values {
+            column_names,
+            values,
+        }
+    };
+    let if_not_exists = pop_sequence(iter, &[IfKeyword, NotKeyword, ExistsKeyword])?;
+    let using = pop_if_update_parameters(cql, iter)?;
+    Ok(InsertStatement {
+        keyspace_name,
+        table_name,
+        values,
+        if_not_exists,
+        using,
+    })
+}
@@ -0,0 +1,132 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    ast::*,
+    parse_cql,
+    parser::testing::{find_string_literal, find_token},
+    test_cql::*,
+};
+
+fn insert_text_column_values(cql: &str) -> InsertValues {
+    InsertValues::Values {
+        column_names: vec![find_token(cql, "text_column")],
+        values: vec![ExpressionTerm::String(find_string_literal(
+            cql,
+            "'big data!'",
+        ))],
+    }
+}
+
+#[test]
+fn test_insert_single_value() {
+    let cql = INSERT_SINGLE_VALUE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: insert_text_column_values(cql),
+            if_not_exists: false,
+            using: vec![],
+        }))
+    );
+}
+
+#[test]
+fn test_insert_multiple_values() {
+    let cql = INSERT_MULTIPLE_VALUES;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: InsertValues::Values {
+                column_names: vec![
+                    find_token(cql, "uuid_column"),
+                    find_token(cql, "text_column"),
+                ],
+                values: vec![
+                    ExpressionTerm::Uuid(find_token(cql, "89b7aa7a-8776-460b-8e1a-60cb4bcd523c")),
+                    ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                ],
+            },
+            if_not_exists: false,
+            using: vec![],
+        }))
+    );
+}
+
+#[test]
+fn test_insert_if_not_exists() {
+    let cql = INSERT_IF_NOT_EXISTS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: insert_text_column_values(cql),
+            if_not_exists: true,
+            using: vec![],
+        }))
+    );
+}
+
+#[test]
+fn test_insert_using_ttl() {
+    let cql = INSERT_USING_TTL;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: insert_text_column_values(cql),
+            if_not_exists: false,
+            using: vec![UpdateParameter::Ttl(ExpressionTerm::Number(find_token(
+                cql, "86400"
+            )))],
+        }))
+    );
+}
+
+#[test]
+fn test_insert_using_timestamp() {
+    let cql = INSERT_USING_TIMESTAMP;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Insert(InsertStatement {
+            keyspace_name: None,
+            table_name: find_token(cql, "big_data_table"),
+            values: insert_text_column_values(cql),
+            if_not_exists: false,
+            using: vec![UpdateParameter::Timestamp(ExpressionTerm::String(
+                find_string_literal(cql, "'2023-11-14T04:05+0000'")
+            ))],
+        }))
+    );
+}
+
+#[test]
+fn test_insert_json() {
+    for (cql, default) in [
+        (INSERT_JSON, None),
+        (INSERT_JSON_DEFAULT_NULL, Some(JsonDefault::Null)),
+        (INSERT_JSON_DEFAULT_UNSET, Some(JsonDefault::Unset)),
+    ] {
+        assert_eq!(
+            parse_cql(cql.to_string()).unwrap(),
+            vec!(CqlStatement::Insert(InsertStatement {
+                keyspace_name: None,
+                table_name: find_token(cql, "big_data_table"),
+                values: InsertValues::Json {
+                    json: ExpressionTerm::String(find_string_literal(
+                        cql,
+                        "'{\"text_column\": \"big data!\"}'"
+                    )),
+                    default,
+                },
+                if_not_exists: false,
+                using: vec![],
+            }))
+        );
+    }
+}
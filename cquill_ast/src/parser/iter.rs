@@ -1,39 +1,26 @@
-use crate::ParseResult;
-use crate::ast::CqlCollectionType::List;
+use crate::ast::CqlCollectionType::{List, Map, Set};
 use crate::ast::{
     CqlDataType, CqlDataType::*, CqlNativeType, CqlNativeType::*, CqlValueType::*, StringView,
     TokenView,
 };
 use crate::lex::TokenName::*;
 use crate::lex::{Token, TokenName};
+use crate::{ParseError, ParseResult};
 use std::iter::Peekable;
 use std::slice::Iter;
 use std::sync::Arc;
 
-pub fn advance_until(iter: &mut Peekable<Iter<Token>>, until: TokenName) {
-    loop {
-        match iter.next() {
-            None => break,
-            Some(popped) => {
-                if popped.name == until {
-                    break;
-                }
-            }
-        }
-    }
-}
-
 /// Returns true/false whether peeked token matches and Err if peek returns None.
 pub fn peek_next_match(iter: &mut Peekable<Iter<Token>>, next: TokenName) -> ParseResult<bool> {
     match iter.peek() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(peeked) => Ok(peeked.name == next),
     }
 }
 
 /// Returns next Token or Err if next returns None.
 pub fn pop_next<'a>(iter: &'a mut Peekable<Iter<Token>>) -> ParseResult<&'a Token> {
-    iter.next().ok_or_else(|| todo!("parse error"))
+    iter.next().ok_or(ParseError::InvalidCql)
 }
 
 /// Returns next Some(Token) if it matches TokenName or None if next returns None.
@@ -47,12 +34,12 @@ pub fn pop_next_match<'a>(
     next: TokenName,
 ) -> ParseResult<&'a Token> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(popped) => {
             if popped.name == next {
                 Ok(popped)
             } else {
-                todo!("parse error")
+                Err(ParseError::InvalidCql)
             }
         }
     }
@@ -63,19 +50,25 @@ pub fn pop_next_match<'a>(
 /// this function will return an error.
 ///
 /// Returns true and advances iterator `nexts.len()` number of times if all tokens match.
-/// Returns false if first token peeked does not match `nexts.first()`.
+/// Returns false if first token peeked does not match `nexts.first()` or iter is exhausted.
 /// Returns error if first token peeked matches and subsequent peeks return None or do not match.
 pub fn pop_sequence(iter: &mut Peekable<Iter<Token>>, nexts: &[TokenName]) -> ParseResult<bool> {
     let mut advanced = false;
     for maybe_next in nexts {
         match iter.peek() {
-            None => todo!("parse error"),
+            None => {
+                if advanced {
+                    return Err(ParseError::InvalidCql);
+                } else {
+                    return Ok(false);
+                }
+            }
             Some(next) => {
                 if next.name == *maybe_next {
                     _ = iter.next();
                     advanced = true;
                 } else if advanced {
-                    todo!("parse error")
+                    return Err(ParseError::InvalidCql);
                 } else {
                     return Ok(false);
                 }
@@ -100,60 +93,104 @@ pub fn pop_identifier(
 /// FalseKeyword.
 pub fn pop_boolean_literal(iter: &mut Peekable<Iter<Token>>) -> ParseResult<bool> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(popped) => match &popped.name {
             TrueKeyword => Ok(true),
             FalseKeyword => Ok(false),
-            _ => todo!("parse error"),
+            _ => Err(ParseError::InvalidCql),
         },
     }
 }
 
-pub fn pop_number_literal(
-    cql: &Arc<String>,
-    iter: &mut Peekable<Iter<Token>>,
-) -> ParseResult<TokenView> {
-    match iter.next() {
-        None => todo!("parse error"),
-        Some(popped) => match &popped.name {
-            NumberLiteral => Ok(TokenView::new(cql, popped)),
-            _ => todo!("parse error"),
-        },
-    }
-}
-
-// todo collections, custom types and tuples
+/// Pops a data type including collections, tuples and vectors of any nested data type such as
+/// `map<text, frozen<list<tuple<int, text, uuid>>>>`.
 pub fn pop_cql_data_type(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<CqlDataType> {
     Ok(match iter.next() {
-        None => todo!("parse error"),
+        None => return Err(ParseError::InvalidCql),
         Some(popped) => match maybe_cql_native_type(popped) {
             Some(native_type) => ValueType(NativeType(native_type)),
-            None => match popped.name {
+            None => match &popped.name {
                 ListKeyword => {
-                    pop_next_match(iter, LessThan)?;
-                    let list_of = match maybe_cql_native_type(pop_next(iter)?) {
-                        None => todo!("frozen<> and udt"),
-                        Some(native_type) => NativeType(native_type),
-                    };
-                    pop_next_match(iter, GreaterThan)?;
-                    CollectionType(List(list_of))
+                    let mut generic_types = pop_generic_data_types(cql, iter)?;
+                    if generic_types.len() != 1 {
+                        return Err(ParseError::InvalidCql);
+                    }
+                    CollectionType(List(Box::new(generic_types.remove(0))))
                 }
-                FrozenKeyword => Frozen({
-                    pop_next_match(iter, LessThan)?;
-                    let generic_type = Box::new(pop_cql_data_type(cql, iter)?);
-                    pop_next_match(iter, GreaterThan)?;
-                    generic_type
+                FrozenKeyword => {
+                    let mut generic_types = pop_generic_data_types(cql, iter)?;
+                    if generic_types.len() != 1 {
+                        return Err(ParseError::InvalidCql);
+                    }
+                    Frozen(Box::new(generic_types.remove(0)))
+                }
+                SetKeyword if peek_next_match(iter, LessThan)? => {
+                    let mut generic_types = pop_generic_data_types(cql, iter)?;
+                    if generic_types.len() != 1 {
+                        return Err(ParseError::InvalidCql);
+                    }
+                    CollectionType(Set(Box::new(generic_types.remove(0))))
+                }
+                StringLiteral(style) => CustomType(StringView {
+                    cql: cql.clone(),
+                    range: popped.range.clone(),
+                    style: style.clone(),
                 }),
-                Identifier => ValueType(UserDefinedType(popped.to_token_view(cql))),
-                _ => todo!("parse error"),
+                Identifier if iter.peek().is_some_and(|peeked| peeked.name == LessThan) => {
+                    match popped.to_token_view(cql).value().to_lowercase().as_str() {
+                        "map" => {
+                            let mut generic_types = pop_generic_data_types(cql, iter)?;
+                            if generic_types.len() != 2 {
+                                return Err(ParseError::InvalidCql);
+                            }
+                            let value_type = Box::new(generic_types.remove(1));
+                            CollectionType(Map(Box::new(generic_types.remove(0)), value_type))
+                        }
+                        "tuple" => Tuple(pop_generic_data_types(cql, iter)?),
+                        "vector" => {
+                            pop_next_match(iter, LessThan)?;
+                            let element_type = Box::new(pop_cql_data_type(cql, iter)?);
+                            pop_next_match(iter, Comma)?;
+                            let dimension = pop_next_match(iter, NumberLiteral)?.to_token_view(cql);
+                            pop_next_match(iter, GreaterThan)?;
+                            Vector(element_type, dimension)
+                        }
+                        _ => return Err(ParseError::InvalidCql),
+                    }
+                }
+                Identifier => match pop_next_if(iter, Dot) {
+                    Some(_) => ValueType(UserDefinedType(
+                        Some(popped.to_token_view(cql)),
+                        pop_identifier(cql, iter)?,
+                    )),
+                    None => ValueType(UserDefinedType(None, popped.to_token_view(cql))),
+                },
+                _ => return Err(ParseError::InvalidCql),
             },
         },
     })
 }
 
+/// Pops the `<` and `>` enclosed and comma-separated data types of a generic type.
+fn pop_generic_data_types(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<Vec<CqlDataType>> {
+    pop_next_match(iter, LessThan)?;
+    let mut data_types = Vec::new();
+    loop {
+        data_types.push(pop_cql_data_type(cql, iter)?);
+        if pop_next_if(iter, Comma).is_none() {
+            break;
+        }
+    }
+    pop_next_match(iter, GreaterThan)?;
+    Ok(data_types)
+}
+
 fn maybe_cql_native_type(token: &Token) -> Option<CqlNativeType> {
     Some(match token.name {
         AsciiKeyword => Ascii,
@@ -198,14 +235,14 @@ pub fn pop_string_literal(
     iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<StringView> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(popped) => match &popped.name {
             StringLiteral(style) => Ok(StringView {
                 cql: cql.clone(),
                 range: popped.range.clone(),
                 style: style.clone(),
             }),
-            _ => todo!("parse error"),
+            _ => Err(ParseError::InvalidCql),
         },
     }
 }
@@ -230,7 +267,7 @@ pub fn pop_if_function_signature(
             }
             pop_next_match(iter, RightParenthesis)?;
             if result.is_empty() {
-                todo!("parse error");
+                return Err(ParseError::InvalidCql);
             }
             Some(result)
         }
@@ -1,26 +1,76 @@
+mod alter;
+mod batch;
 mod create;
+mod delete;
+mod describe;
 mod drop;
+mod expression;
+mod insert;
 mod iter;
+mod script;
+mod select;
+mod truncate;
 mod update;
+mod use_keyspace;
+
+#[cfg(test)]
+mod alter_test;
+
+#[cfg(test)]
+mod batch_test;
 
 #[cfg(test)]
 mod create_test;
 
+#[cfg(test)]
+mod delete_test;
+
+#[cfg(test)]
+mod describe_test;
+
 #[cfg(test)]
 mod drop_test;
 
+#[cfg(test)]
+mod expression_test;
+
+#[cfg(test)]
+mod insert_test;
+
+#[cfg(test)]
+mod script_test;
+
+#[cfg(test)]
+mod select_test;
+
 #[cfg(test)]
 mod testing;
 
+#[cfg(test)]
+mod truncate_test;
+
 #[cfg(test)]
 mod update_test;
 
+#[cfg(test)]
+mod use_keyspace_test;
+
 use crate::ast::*;
 use crate::lex::*;
+use crate::parser::alter::parse_alter_statement;
+use crate::parser::batch::parse_batch_statement;
 use crate::parser::create::parse_create_statement;
+use crate::parser::delete::parse_delete_statement;
+use crate::parser::describe::parse_describe_statement;
 use crate::parser::drop::parse_drop_statement;
+use crate::parser::insert::parse_insert_statement;
 use crate::parser::iter::pop_next_if;
+pub(crate) use crate::parser::script::skip_statement;
+pub use crate::parser::script::{ScriptStatement, parse_cql_script};
+use crate::parser::select::parse_select_statement;
+use crate::parser::truncate::parse_truncate_statement;
 use crate::parser::update::parse_update_statement;
+use crate::parser::use_keyspace::parse_use_statement;
 use TokenName::*;
 use std::iter::Peekable;
 use std::slice::Iter;
@@ -35,7 +85,9 @@ pub enum ParseError {
 pub type ParseResult<T> = Result<T, ParseError>;
 
 pub fn parse_cql(cql: String) -> ParseResult<Vec<CqlStatement>> {
-    let tokens = Tokenizer::new(cql.as_str()).tokenize().unwrap();
+    let tokens = Tokenizer::new(cql.as_str())
+        .tokenize()
+        .map_err(|_| ParseError::InvalidCql)?;
     let cql = Arc::new(cql);
     let mut iter = tokens.iter().peekable();
     let mut result = Vec::new();
@@ -44,23 +96,33 @@ pub fn parse_cql(cql: String) -> ParseResult<Vec<CqlStatement>> {
         while pop_next_if(&mut iter, Semicolon).is_some() {}
     }
     if result.is_empty() {
-        todo!("parse error")
+        Err(ParseError::InvalidCql)
     } else {
         Ok(result)
     }
 }
 
-fn parse_statement(
+pub(crate) fn parse_statement(
     cql: &Arc<String>,
     iter: &mut Peekable<Iter<Token>>,
 ) -> ParseResult<CqlStatement> {
     match iter.next() {
-        None => todo!("parse error"),
+        None => Err(ParseError::InvalidCql),
         Some(token) => match token.name {
+            AlterKeyword => parse_alter_statement(cql, iter).map(CqlStatement::Alter),
+            BeginKeyword => parse_batch_statement(cql, iter).map(CqlStatement::Batch),
             CreateKeyword => parse_create_statement(cql, iter).map(CqlStatement::Create),
+            DeleteKeyword => parse_delete_statement(cql, iter).map(CqlStatement::Delete),
+            DescribeKeyword | DescKeyword => {
+                parse_describe_statement(cql, iter).map(CqlStatement::Describe)
+            }
             DropKeyword => parse_drop_statement(cql, iter).map(CqlStatement::Drop),
+            InsertKeyword => parse_insert_statement(cql, iter).map(CqlStatement::Insert),
+            SelectKeyword => parse_select_statement(cql, iter).map(CqlStatement::Select),
+            TruncateKeyword => parse_truncate_statement(cql, iter).map(CqlStatement::Truncate),
             UpdateKeyword => parse_update_statement(cql, iter).map(CqlStatement::Update),
-            _ => todo!("parse error {:?}", token.name),
+            UseKeyword => parse_use_statement(cql, iter).map(CqlStatement::Use),
+            _ => Err(ParseError::InvalidCql),
         },
     }
 }
@@ -0,0 +1,66 @@
+use crate::ast::CqlStatement;
+use crate::lex::TokenName::*;
+use crate::lex::{Token, Tokenizer};
+use crate::parser::{ParseError, ParseResult, parse_statement};
+use std::sync::Arc;
+
+/// A statement of a CQL script with the line its first token is on, counting from 1.
+#[derive(Debug)]
+pub struct ScriptStatement {
+    pub line: usize,
+    pub statement: ParseResult<CqlStatement>,
+}
+
+/// Parses each statement of a CQL script separately, so a statement that cannot be parsed does not
+/// prevent parsing the statements after it.
+pub fn parse_cql_script(cql: &str) -> ParseResult<Vec<ScriptStatement>> {
+    let tokens = Tokenizer::new(cql)
+        .tokenize()
+        .map_err(|_| ParseError::InvalidCql)?;
+    let cql = Arc::new(cql.to_string());
+    let mut result = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if matches!(tokens[i].name, Semicolon) {
+            i += 1;
+            continue;
+        }
+        let mut iter = tokens[i..].iter().peekable();
+        let parsed = parse_statement(&cql, &mut iter);
+        let end = tokens.len() - iter.len();
+        let line = tokens[i].line + 1;
+        match parsed {
+            Ok(statement) if end == tokens.len() || matches!(tokens[end].name, Semicolon) => {
+                result.push(ScriptStatement {
+                    line,
+                    statement: Ok(statement),
+                });
+                i = end + 1;
+            }
+            _ => {
+                result.push(ScriptStatement {
+                    line,
+                    statement: Err(ParseError::InvalidCql),
+                });
+                i = skip_statement(&tokens, i);
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Finds the index of the token after the semicolon ending the statement that begins at `i`,
+/// including the semicolons of the statements within a batch.
+pub(crate) fn skip_statement(tokens: &[Token], i: usize) -> usize {
+    let mut in_batch = matches!(tokens[i].name, BeginKeyword);
+    let mut j = i;
+    while j < tokens.len() {
+        match tokens[j].name {
+            ApplyKeyword => in_batch = false,
+            Semicolon if !in_batch => return j + 1,
+            _ => {}
+        }
+        j += 1;
+    }
+    tokens.len()
+}
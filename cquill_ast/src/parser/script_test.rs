@@ -0,0 +1,45 @@
+use crate::ast::*;
+use crate::parse_cql_script;
+
+#[test]
+fn test_parse_cql_script_lines() {
+    let cql = "use big_data_keyspace;\n\ncreate table big_data_table (\n    uuid_column uuid primary key\n);\n-- dml\ninsert into big_data_table (uuid_column) values (now());";
+    let statements = parse_cql_script(cql).unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(|script_statement| script_statement.line)
+            .collect::<Vec<_>>(),
+        vec![1, 3, 7]
+    );
+    assert!(matches!(statements[0].statement, Ok(CqlStatement::Use(_))));
+    assert!(matches!(
+        statements[1].statement,
+        Ok(CqlStatement::Create(CreateStatement::Table(_)))
+    ));
+    assert!(matches!(
+        statements[2].statement,
+        Ok(CqlStatement::Insert(_))
+    ));
+}
+
+#[test]
+fn test_parse_cql_script_continues_after_unsupported_statement() {
+    let cql = "grant select on keyspace big_data_keyspace to big_data_role;\n/* a\n comment */ drop table big_data_table;";
+    let statements = parse_cql_script(cql).unwrap();
+    assert_eq!(statements.len(), 2);
+    assert_eq!(statements[0].line, 1);
+    assert!(statements[0].statement.is_err());
+    assert_eq!(statements[1].line, 3);
+    assert!(matches!(
+        statements[1].statement,
+        Ok(CqlStatement::Drop(DropStatement::Table(_)))
+    ));
+}
+
+#[test]
+fn test_parse_cql_script_skips_empty_statements() {
+    let statements = parse_cql_script(";\nuse big_data_keyspace;;").unwrap();
+    assert_eq!(statements.len(), 1);
+    assert_eq!(statements[0].line, 2);
+}
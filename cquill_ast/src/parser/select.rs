@@ -0,0 +1,132 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::ParseResult;
+use crate::parser::expression::*;
+use crate::parser::iter::*;
+
+use std::{iter::Peekable, slice::Iter, sync::Arc};
+
+pub fn parse_select_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<SelectStatement> {
+    let json = pop_next_if(iter, JsonKeyword).is_some();
+    let distinct = pop_next_if(iter, DistinctKeyword).is_some();
+    let select_clause = parse_select_clause(cql, iter)?;
+    pop_next_match(iter, FromKeyword)?;
+    let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+    let where_clause = match pop_next_if(iter, WhereKeyword) {
+        None => None,
+        Some(_) => Some(parse_where_clause(cql, iter)?),
+    };
+    let group_by = if pop_sequence(iter, &[GroupKeyword, ByKeyword])? {
+        Some(pop_comma_separated_identifiers(cql, iter)?)
+    } else {
+        None
+    };
+    let order_by = if pop_sequence(iter, &[OrderKeyword, ByKeyword])? {
+        let mut orderings = Vec::new();
+        loop {
+            let column_name = pop_identifier(cql, iter)?;
+            let order = if pop_next_if(iter, AscKeyword).is_some() {
+                Some(ClusteringOrder::Asc)
+            } else if pop_next_if(iter, DescKeyword).is_some() {
+                Some(ClusteringOrder::Desc)
+            } else {
+                None
+            };
+            orderings.push(ClusteringOrderDefinition { column_name, order });
+            if pop_next_if(iter, Comma).is_none() {
+                break;
+            }
+        }
+        Some(orderings)
+    } else {
+        None
+    };
+    let per_partition_limit = if pop_sequence(iter, &[PerKeyword, PartitionKeyword, LimitKeyword])?
+    {
+        Some(parse_expression_term(cql, iter)?)
+    } else {
+        None
+    };
+    let limit = match pop_next_if(iter, LimitKeyword) {
+        None => None,
+        Some(_) => Some(parse_expression_term(cql, iter)?),
+    };
+    let allow_filtering = pop_sequence(iter, &[AllowKeyword, FilteringKeyword])?;
+    Ok(SelectStatement {
+        json,
+        distinct,
+        select_clause,
+        keyspace_name,
+        table_name,
+        where_clause,
+        group_by,
+        order_by,
+        per_partition_limit,
+        limit,
+        allow_filtering,
+    })
+}
+
+/// Parses `*` or the selectors of `SELECT` and `CREATE MATERIALIZED VIEW` statements.
+pub fn parse_select_clause(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<SelectClause> {
+    if pop_next_if(iter, Star).is_some() {
+        return Ok(SelectClause::All);
+    }
+    let mut selectors = Vec::new();
+    loop {
+        let selector = parse_selector(cql, iter)?;
+        let alias = match pop_next_if(iter, AsKeyword) {
+            None => None,
+            Some(_) => Some(pop_identifier(cql, iter)?),
+        };
+        selectors.push(SelectorDefinition { selector, alias });
+        if pop_next_if(iter, Comma).is_none() {
+            break;
+        }
+    }
+    Ok(SelectClause::Selectors(selectors))
+}
+
+fn parse_selector(cql: &Arc<String>, iter: &mut Peekable<Iter<Token>>) -> ParseResult<Selector> {
+    let mut lookahead = iter.clone();
+    if let (Some(function_name), Some(next)) = (lookahead.next(), lookahead.next())
+        && function_name.name == Identifier
+        && next.name == LeftParenthesis
+    {
+        match function_name
+            .to_token_view(cql)
+            .value()
+            .to_lowercase()
+            .as_str()
+        {
+            "cast" => {
+                iter.next();
+                iter.next();
+                let selector = Box::new(parse_selector(cql, iter)?);
+                pop_next_match(iter, AsKeyword)?;
+                let data_type = pop_cql_data_type(cql, iter)?;
+                pop_next_match(iter, RightParenthesis)?;
+                return Ok(Selector::Cast {
+                    selector,
+                    data_type,
+                });
+            }
+            "count" if lookahead.peek().is_some_and(|t| t.name == Star) => {
+                iter.next();
+                iter.next();
+                pop_next_match(iter, Star)?;
+                pop_next_match(iter, RightParenthesis)?;
+                return Ok(Selector::CountAll);
+            }
+            _ => {}
+        }
+    }
+    Ok(Selector::Term(parse_expression_term(cql, iter)?))
+}
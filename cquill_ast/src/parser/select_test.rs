@@ -0,0 +1,406 @@
+use pretty_assertions::assert_eq;
+
+use crate::{
+    ast::*,
+    parse_cql,
+    parser::testing::{find_nth_token, find_string_literal, find_token},
+    test_cql::*,
+};
+
+fn select_all_from_big_data_table(cql: &str) -> SelectStatement {
+    SelectStatement {
+        json: false,
+        distinct: false,
+        select_clause: SelectClause::All,
+        keyspace_name: None,
+        table_name: find_token(cql, "big_data_table"),
+        where_clause: None,
+        group_by: None,
+        order_by: None,
+        per_partition_limit: None,
+        limit: None,
+        allow_filtering: false,
+    }
+}
+
+fn column_selector(cql: &str, column_name: &str) -> SelectorDefinition {
+    SelectorDefinition {
+        selector: Selector::Term(ExpressionTerm::Column(find_token(cql, column_name))),
+        alias: None,
+    }
+}
+
+fn where_single_relation(
+    cql: &str,
+    column_name: &str,
+    operator: RelationOperator,
+    expr_term: ExpressionTerm,
+) -> Option<WhereClause> {
+    Some(WhereClause {
+        relations: vec![WhereClauseRelation {
+            columns: RelationColumns::Single(find_token(cql, column_name)),
+            operator,
+            expr_term,
+        }],
+    })
+}
+
+#[test]
+fn test_select_explicit_columns() {
+    let cql = SELECT_EXPLICIT_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            select_clause: SelectClause::Selectors(vec![
+                column_selector(cql, "text_column"),
+                column_selector(cql, "uuid_column"),
+            ]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_column_as() {
+    let cql = SELECT_COLUMN_AS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            select_clause: SelectClause::Selectors(vec![SelectorDefinition {
+                selector: Selector::Term(ExpressionTerm::Column(find_token(cql, "text_column"))),
+                alias: Some(find_nth_token(cql, 1, "text_col")),
+            }]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_column_cast() {
+    let cql = SELECT_COLUMN_CAST;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            select_clause: SelectClause::Selectors(vec![SelectorDefinition {
+                selector: Selector::Cast {
+                    selector: Box::new(Selector::Term(ExpressionTerm::Column(find_token(
+                        cql,
+                        "uuid_column"
+                    )))),
+                    data_type: CqlDataType::ValueType(CqlValueType::NativeType(
+                        CqlNativeType::Text
+                    )),
+                },
+                alias: None,
+            }]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_count() {
+    let cql = SELECT_COUNT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            select_clause: SelectClause::Selectors(vec![SelectorDefinition {
+                selector: Selector::CountAll,
+                alias: None,
+            }]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_json() {
+    let cql = SELECT_JSON;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            json: true,
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_distinct() {
+    let cql = SELECT_DISTINCT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            distinct: true,
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_where_comparison_operators() {
+    for (cql, operator) in [
+        (SELECT_WHERE_LT, RelationOperator::LessThan),
+        (SELECT_WHERE_GT, RelationOperator::GreaterThan),
+        (SELECT_WHERE_LTE, RelationOperator::LessThanEqual),
+        (SELECT_WHERE_GTE, RelationOperator::GreaterThanEqual),
+    ] {
+        assert_eq!(
+            parse_cql(cql.to_string()).unwrap(),
+            vec!(CqlStatement::Select(SelectStatement {
+                where_clause: where_single_relation(
+                    cql,
+                    "int_column",
+                    operator,
+                    ExpressionTerm::Number(find_token(cql, "3")),
+                ),
+                ..select_all_from_big_data_table(cql)
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_select_where_eq_and_neq() {
+    for (cql, operator) in [
+        (SELECT_WHERE_EQ, RelationOperator::Equal),
+        (SELECT_WHERE_NEQ, RelationOperator::NotEqual),
+    ] {
+        assert_eq!(
+            parse_cql(cql.to_string()).unwrap(),
+            vec!(CqlStatement::Select(SelectStatement {
+                where_clause: where_single_relation(
+                    cql,
+                    "text_column",
+                    operator,
+                    ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                ),
+                ..select_all_from_big_data_table(cql)
+            }))
+        );
+    }
+}
+
+#[test]
+fn test_select_where_in() {
+    let cql = SELECT_WHERE_IN;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            where_clause: Some(WhereClause {
+                relations: vec![
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "partition_col")),
+                        operator: RelationOperator::Equal,
+                        expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                    },
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "clustering_col")),
+                        operator: RelationOperator::In,
+                        expr_term: ExpressionTerm::Tuple(vec![
+                            ExpressionTerm::String(find_string_literal(cql, "'abc'")),
+                            ExpressionTerm::String(find_string_literal(cql, "'def'")),
+                        ]),
+                    },
+                ],
+            }),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_where_in_tuple() {
+    let cql = SELECT_WHERE_IN_TUPLE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            where_clause: Some(WhereClause {
+                relations: vec![
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "partition_col")),
+                        operator: RelationOperator::Equal,
+                        expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                    },
+                    WhereClauseRelation {
+                        columns: RelationColumns::Tuple(vec![
+                            find_token(cql, "clustering_col1"),
+                            find_token(cql, "clustering_col2"),
+                        ]),
+                        operator: RelationOperator::In,
+                        expr_term: ExpressionTerm::Tuple(vec![
+                            ExpressionTerm::Tuple(vec![
+                                ExpressionTerm::String(find_string_literal(cql, "'abc'")),
+                                ExpressionTerm::Number(find_token(cql, "123")),
+                            ]),
+                            ExpressionTerm::Tuple(vec![
+                                ExpressionTerm::String(find_string_literal(cql, "'def'")),
+                                ExpressionTerm::Number(find_token(cql, "456")),
+                            ]),
+                        ]),
+                    },
+                ],
+            }),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_where_contains() {
+    let cql = SELECT_WHERE_CONTAINS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            where_clause: where_single_relation(
+                cql,
+                "list_column",
+                RelationOperator::Contains,
+                ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+            ),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_where_contains_key() {
+    let cql = SELECT_WHERE_CONTAINS_KEY;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            where_clause: where_single_relation(
+                cql,
+                "map_column",
+                RelationOperator::ContainsKey,
+                ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+            ),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_where_and_where() {
+    let cql = SELECT_WHERE_AND_WHERE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            where_clause: Some(WhereClause {
+                relations: vec![
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "partition_col")),
+                        operator: RelationOperator::Equal,
+                        expr_term: ExpressionTerm::String(find_string_literal(cql, "'big data!'")),
+                    },
+                    WhereClauseRelation {
+                        columns: RelationColumns::Single(find_token(cql, "clustering_col")),
+                        operator: RelationOperator::Equal,
+                        expr_term: ExpressionTerm::String(find_string_literal(cql, "'more data!'")),
+                    },
+                ],
+            }),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_group_by() {
+    let cql = SELECT_GROUP_BY_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            group_by: Some(vec![
+                find_token(cql, "text_column"),
+                find_token(cql, "uuid_column"),
+            ]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_order_by() {
+    let cql = SELECT_ORDER_BY_MULTIPLE_COLUMNS;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            order_by: Some(vec![
+                ClusteringOrderDefinition {
+                    column_name: find_token(cql, "text_column"),
+                    order: Some(ClusteringOrder::Asc),
+                },
+                ClusteringOrderDefinition {
+                    column_name: find_token(cql, "uuid_column"),
+                    order: Some(ClusteringOrder::Desc),
+                },
+            ]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_per_partition_limit() {
+    let cql = SELECT_PER_PARTITION_LIMIT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            per_partition_limit: Some(ExpressionTerm::Number(find_token(cql, "1"))),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_limit() {
+    let cql = SELECT_LIMIT;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            limit: Some(ExpressionTerm::Number(find_token(cql, "5"))),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_allow_filtering() {
+    let cql = SELECT_ALLOW_FILTERING;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            allow_filtering: true,
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
+
+#[test]
+fn test_select_without_semicolon() {
+    let cql = "select * from big_data_table";
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(select_all_from_big_data_table(cql)))
+    );
+}
+
+#[test]
+fn test_select_arithmetic_selector() {
+    let cql = "select int_column * 2 from big_data_table;";
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Select(SelectStatement {
+            select_clause: SelectClause::Selectors(vec![SelectorDefinition {
+                selector: Selector::Term(ExpressionTerm::Arithmetic {
+                    left: Box::new(ExpressionTerm::Column(find_token(cql, "int_column"))),
+                    operator: ArithmeticOperator::Multiply,
+                    right: Box::new(ExpressionTerm::Number(find_nth_token(cql, 0, "2"))),
+                }),
+                alias: None,
+            }]),
+            ..select_all_from_big_data_table(cql)
+        }))
+    );
+}
@@ -0,0 +1,20 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::lex::TokenName::*;
+use crate::parser::ParseResult;
+use crate::parser::iter::{pop_keyspace_object_name, pop_next_if};
+use std::iter::Peekable;
+use std::slice::Iter;
+use std::sync::Arc;
+
+pub fn parse_truncate_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<TruncateStatement> {
+    _ = pop_next_if(iter, TableKeyword);
+    let (keyspace_name, table_name) = pop_keyspace_object_name(cql, iter)?;
+    Ok(TruncateStatement {
+        keyspace_name,
+        table_name,
+    })
+}
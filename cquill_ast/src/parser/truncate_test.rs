@@ -0,0 +1,40 @@
+use crate::ast::{CqlStatement, TruncateStatement};
+use crate::parse_cql;
+use crate::parser::testing::find_token;
+use crate::test_cql::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_parsing_truncate() {
+    assert_eq!(
+        parse_cql(TRUNCATE.to_string()).unwrap(),
+        vec!(CqlStatement::Truncate(TruncateStatement {
+            keyspace_name: None,
+            table_name: find_token(TRUNCATE, "big_data_table"),
+        }))
+    );
+}
+
+#[test]
+fn test_parsing_truncate_table() {
+    assert_eq!(
+        parse_cql(TRUNCATE_TABLE.to_string()).unwrap(),
+        vec!(CqlStatement::Truncate(TruncateStatement {
+            keyspace_name: None,
+            table_name: find_token(TRUNCATE_TABLE, "big_data_table"),
+        }))
+    );
+}
+
+#[test]
+fn test_parsing_truncate_explicit_keyspace() {
+    let cql = TRUNCATE_EXPLICIT_KEYSPACE;
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Truncate(TruncateStatement {
+            keyspace_name: Some(find_token(cql, "big_data_keyspace")),
+            table_name: find_token(cql, "big_data_table"),
+        }))
+    );
+}
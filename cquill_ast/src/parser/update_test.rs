@@ -15,7 +15,9 @@ fn test_update_single_column() {
     assert_eq!(
         parse_cql(cql.to_string()).unwrap(),
         vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
             table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![],
             assignments: vec!(Assignment {
                 selection: AssignmentSelection::Column {
                     column_name: find_token(cql.as_str(), "int_column"),
@@ -27,7 +29,8 @@ fn test_update_single_column() {
             }),
             where_clause: WhereClause {
                 relations: vec!(WhereClauseRelation {
-                    column_name: find_token(cql.as_str(), "text_column"),
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
                     expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
                 }),
             },
@@ -42,7 +45,9 @@ fn test_update_multiple_columns() {
     assert_eq!(
         parse_cql(cql.to_string()).unwrap(),
         vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
             table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![],
             assignments: vec!(
                 Assignment {
                     selection: AssignmentSelection::Column {
@@ -65,7 +70,8 @@ fn test_update_multiple_columns() {
             ),
             where_clause: WhereClause {
                 relations: vec!(WhereClauseRelation {
-                    column_name: find_token(cql.as_str(), "text_column"),
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
                     expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
                 }),
             },
@@ -80,7 +86,9 @@ fn test_update_if_exists() {
     assert_eq!(
         parse_cql(cql.to_string()).unwrap(),
         vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
             table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![],
             assignments: vec!(Assignment {
                 selection: AssignmentSelection::Column {
                     column_name: find_token(cql.as_str(), "int_column"),
@@ -92,7 +100,8 @@ fn test_update_if_exists() {
             }),
             where_clause: WhereClause {
                 relations: vec!(WhereClauseRelation {
-                    column_name: find_token(cql.as_str(), "text_column"),
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
                     expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
                 }),
             },
@@ -107,7 +116,9 @@ fn test_update_if_single_condition() {
     assert_eq!(
         parse_cql(cql.to_string()).unwrap(),
         vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
             table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![],
             assignments: vec!(Assignment {
                 selection: AssignmentSelection::Column {
                     column_name: find_token(cql.as_str(), "int_column"),
@@ -119,7 +130,8 @@ fn test_update_if_single_condition() {
             }),
             where_clause: WhereClause {
                 relations: vec!(WhereClauseRelation {
-                    column_name: find_token(cql.as_str(), "text_column"),
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
                     expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
                 }),
             },
@@ -127,8 +139,114 @@ fn test_update_if_single_condition() {
                 selection: AssignmentSelection::Column {
                     column_name: rfind_token(cql.as_str(), "int_column"),
                 },
+                operator: RelationOperator::GreaterThan,
                 expr_term: ExpressionTerm::Number(rfind_token(cql.as_str(), "6")),
             },])),
         })),
     );
 }
+
+#[test]
+fn test_update_if_multiple_conditions() {
+    let cql = Arc::new(String::from(UPDATE_IF_MULTIPLE_CONDITIONS));
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
+            table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![],
+            assignments: vec!(Assignment {
+                selection: AssignmentSelection::Column {
+                    column_name: find_token(cql.as_str(), "int_column"),
+                },
+                expr_term: ExpressionTerm::Number(find_token(cql.as_str(), "1")),
+            }),
+            where_clause: WhereClause {
+                relations: vec!(WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
+                }),
+            },
+            if_behavior: Some(UpdateIfBehavior::Conditional(vec![
+                UpdateIfCondition {
+                    selection: AssignmentSelection::Column {
+                        column_name: rfind_token(cql.as_str(), "int_column"),
+                    },
+                    operator: RelationOperator::GreaterThan,
+                    expr_term: ExpressionTerm::Number(find_token(cql.as_str(), "6")),
+                },
+                UpdateIfCondition {
+                    selection: AssignmentSelection::Column {
+                        column_name: find_token(cql.as_str(), "float_column"),
+                    },
+                    operator: RelationOperator::LessThan,
+                    expr_term: ExpressionTerm::Number(find_token(cql.as_str(), "3.0")),
+                },
+            ])),
+        })),
+    );
+}
+
+#[test]
+fn test_update_using_ttl() {
+    let cql = Arc::new(String::from(UPDATE_USING_TTL));
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: None,
+            table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![UpdateParameter::Ttl(ExpressionTerm::Number(find_token(
+                cql.as_str(),
+                "86400"
+            )))],
+            assignments: vec!(Assignment {
+                selection: AssignmentSelection::Column {
+                    column_name: find_token(cql.as_str(), "int_column"),
+                },
+                expr_term: ExpressionTerm::Number(rfind_token(cql.as_str(), "1")),
+            }),
+            where_clause: WhereClause {
+                relations: vec!(WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
+                }),
+            },
+            if_behavior: None,
+        })),
+    );
+}
+
+#[test]
+fn test_update_explicit_keyspace_using_ttl_and_timestamp() {
+    let cql = Arc::new(String::from(UPDATE_EXPLICIT_KEYSPACE));
+    assert_eq!(
+        parse_cql(cql.to_string()).unwrap(),
+        vec!(CqlStatement::Update(UpdateStatement {
+            keyspace_name: Some(find_token(cql.as_str(), "big_data_keyspace")),
+            table_name: find_token(cql.as_str(), "big_data_table"),
+            using: vec![
+                UpdateParameter::Ttl(ExpressionTerm::Number(find_token(cql.as_str(), "86400"))),
+                UpdateParameter::Timestamp(ExpressionTerm::Number(find_token(
+                    cql.as_str(),
+                    "1698796800"
+                ))),
+            ],
+            assignments: vec!(Assignment {
+                selection: AssignmentSelection::Column {
+                    column_name: find_token(cql.as_str(), "int_column"),
+                },
+                expr_term: ExpressionTerm::Number(rfind_token(cql.as_str(), "1")),
+            }),
+            where_clause: WhereClause {
+                relations: vec!(WhereClauseRelation {
+                    columns: RelationColumns::Single(find_token(cql.as_str(), "text_column")),
+                    operator: RelationOperator::Equal,
+                    expr_term: ExpressionTerm::String(find_string_literal(&cql, "'big data!'")),
+                }),
+            },
+            if_behavior: None,
+        })),
+    );
+}
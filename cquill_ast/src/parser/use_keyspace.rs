@@ -0,0 +1,15 @@
+use crate::ast::*;
+use crate::lex::Token;
+use crate::parser::ParseResult;
+use crate::parser::iter::pop_identifier;
+use std::iter::Peekable;
+use std::slice::Iter;
+use std::sync::Arc;
+
+pub fn parse_use_statement(
+    cql: &Arc<String>,
+    iter: &mut Peekable<Iter<Token>>,
+) -> ParseResult<UseStatement> {
+    let keyspace_name = pop_identifier(cql, iter)?;
+    Ok(UseStatement { keyspace_name })
+}
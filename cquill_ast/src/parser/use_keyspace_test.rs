@@ -0,0 +1,43 @@
+use crate::ast::{CqlStatement, CreateStatement, KeyspaceScope, UseStatement};
+use crate::parse_cql;
+use crate::parser::testing::find_token;
+use crate::test_cql::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn test_parsing_use_keyspace() {
+    assert_eq!(
+        parse_cql(USE_KEYSPACE.to_string()).unwrap(),
+        vec!(CqlStatement::Use(UseStatement {
+            keyspace_name: find_token(USE_KEYSPACE, "big_data_keyspace"),
+        }))
+    );
+}
+
+#[test]
+fn test_keyspace_scope_resolves_unqualified_table_names() {
+    let mut scope = KeyspaceScope::default();
+    let mut resolved = Vec::new();
+    for statement in parse_cql(USE_KEYSPACE_THEN_CREATE_TABLE.to_string()).unwrap() {
+        scope.update(&statement);
+        if let CqlStatement::Create(CreateStatement::Table(create_table)) = statement {
+            resolved.push(scope.resolve(&create_table.keyspace_name));
+        }
+    }
+    assert_eq!(
+        resolved,
+        vec!(
+            None,
+            Some("big_data_keyspace".to_string()),
+            Some("other_keyspace".to_string()),
+        )
+    );
+    assert_eq!(scope.current(), Some(&"big_data_keyspace".to_string()));
+}
+
+#[test]
+fn test_keyspace_scope_starting_keyspace() {
+    let scope = KeyspaceScope::new(Some("big_data_keyspace".to_string()));
+    assert_eq!(scope.resolve(&None), Some("big_data_keyspace".to_string()));
+}
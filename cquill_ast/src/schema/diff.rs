@@ -0,0 +1,527 @@
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::ast::ClusteringOrder;
+use crate::schema::*;
+
+/// A difference between a catalog and the catalog it is diffed with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SchemaDiff {
+    /// An object that only exists in the other catalog.
+    Added {
+        object_type: SchemaObjectType,
+        name: SchemaObjectName,
+    },
+    /// An object that only exists in the diffed catalog.
+    Removed {
+        object_type: SchemaObjectType,
+        name: SchemaObjectName,
+    },
+    /// An object defined differently by the other catalog, described with CQL such as a column's
+    /// data type or a table's primary key.
+    Changed {
+        object_type: SchemaObjectType,
+        name: SchemaObjectName,
+        from: String,
+        to: String,
+    },
+}
+
+/// The name of an object in a [SchemaDiff], qualified with its keyspace and the table, type or view
+/// of a column, field or table option. It displays as CQL such as
+/// `big_data_keyspace.big_data_table.text_column`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaObjectName {
+    /// The keyspace of an object other than a keyspace or role.
+    pub keyspace_name: Option<String>,
+    /// The table, type or view of a column, field or table option.
+    pub parent_name: Option<String>,
+    pub name: String,
+    /// The argument types of a function or aggregate overload.
+    pub argument_types: Option<Vec<String>>,
+}
+
+impl SchemaDiff {
+    pub fn object_type(&self) -> SchemaObjectType {
+        match self {
+            SchemaDiff::Added { object_type, .. }
+            | SchemaDiff::Removed { object_type, .. }
+            | SchemaDiff::Changed { object_type, .. } => *object_type,
+        }
+    }
+
+    pub fn name(&self) -> &SchemaObjectName {
+        match self {
+            SchemaDiff::Added { name, .. }
+            | SchemaDiff::Removed { name, .. }
+            | SchemaDiff::Changed { name, .. } => name,
+        }
+    }
+}
+
+impl Display for SchemaDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaDiff::Added { object_type, name } => write!(f, "+ {object_type} {name}"),
+            SchemaDiff::Removed { object_type, name } => write!(f, "- {object_type} {name}"),
+            SchemaDiff::Changed {
+                object_type,
+                name,
+                from,
+                to,
+            } => write!(f, "~ {object_type} {name}: {from} -> {to}"),
+        }
+    }
+}
+
+impl SchemaObjectName {
+    /// The name of a keyspace or role.
+    pub fn new(name: &str) -> Self {
+        SchemaObjectName {
+            keyspace_name: None,
+            parent_name: None,
+            name: name.to_string(),
+            argument_types: None,
+        }
+    }
+
+    /// The name of an object within this keyspace, or of a column, field or table option within
+    /// this table, type or view.
+    pub fn child(&self, name: &str) -> Self {
+        match &self.keyspace_name {
+            None => SchemaObjectName {
+                keyspace_name: Some(self.name.clone()),
+                ..SchemaObjectName::new(name)
+            },
+            Some(keyspace_name) => SchemaObjectName {
+                keyspace_name: Some(keyspace_name.clone()),
+                parent_name: Some(self.name.clone()),
+                ..SchemaObjectName::new(name)
+            },
+        }
+    }
+
+    /// The name of a function or aggregate overload within this keyspace.
+    pub fn overload(&self, signature: &FunctionSignature) -> Self {
+        SchemaObjectName {
+            argument_types: Some(signature.argument_types.clone()),
+            ..self.child(&signature.name)
+        }
+    }
+
+    /// The signature of a function or aggregate overload.
+    pub fn signature(&self) -> Option<FunctionSignature> {
+        Some(FunctionSignature {
+            name: self.name.clone(),
+            argument_types: self.argument_types.clone()?,
+        })
+    }
+}
+
+impl Display for SchemaObjectName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for qualifier in [&self.keyspace_name, &self.parent_name]
+            .into_iter()
+            .flatten()
+        {
+            write!(f, "{}.", cql_identifier(qualifier))?;
+        }
+        f.write_str(&cql_identifier(&self.name))?;
+        if let Some(argument_types) = &self.argument_types {
+            write!(f, "({})", argument_types.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl SchemaCatalog {
+    /// Finds the changes that turn this catalog's schema into the schema of `other`. Objects
+    /// within an added or removed keyspace, table, type or view are not listed separately.
+    pub fn diff(&self, other: &SchemaCatalog) -> Vec<SchemaDiff> {
+        let mut diffs = Vec::new();
+        diff_named(
+            &mut diffs,
+            SchemaObjectType::Keyspace,
+            &self.keyspaces,
+            &other.keyspaces,
+            |keyspace_name| SchemaObjectName::new(keyspace_name),
+            diff_keyspace,
+        );
+        diff_named(
+            &mut diffs,
+            SchemaObjectType::Role,
+            &self.roles,
+            &other.roles,
+            |role_name| SchemaObjectName::new(role_name),
+            |diffs, role_name, from, to| {
+                diff_definition(diffs, SchemaObjectType::Role, role_name, from, to, |role| {
+                    format!("LOGIN = {} AND SUPERUSER = {}", role.login, role.superuser)
+                })
+            },
+        );
+        diffs
+    }
+}
+
+fn diff_keyspace(
+    diffs: &mut Vec<SchemaDiff>,
+    keyspace_name: &SchemaObjectName,
+    from: &KeyspaceSchema,
+    to: &KeyspaceSchema,
+) {
+    diff_definition(
+        diffs,
+        SchemaObjectType::Keyspace,
+        keyspace_name,
+        from,
+        to,
+        |keyspace| keyspace.replication.to_string(),
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::Type,
+        &from.types,
+        &to.types,
+        |name| keyspace_name.child(name),
+        |diffs, type_name, from, to| {
+            diff_named(
+                diffs,
+                SchemaObjectType::Field,
+                from.fields.iter().map(|field| (&field.name, field)),
+                to.fields.iter().map(|field| (&field.name, field)),
+                |field_name| type_name.child(field_name),
+                |diffs, field_name, from, to| {
+                    diff_definition(
+                        diffs,
+                        SchemaObjectType::Field,
+                        field_name,
+                        from,
+                        to,
+                        |field| normalize_data_type(&field.data_type),
+                    )
+                },
+            )
+        },
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::Table,
+        &from.tables,
+        &to.tables,
+        |name| keyspace_name.child(name),
+        |diffs, table_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::Table,
+                table_name,
+                from,
+                to,
+                |table| primary_key_definition(&table.partition_key, &table.clustering_key),
+            );
+            diff_named(
+                diffs,
+                SchemaObjectType::Column,
+                from.columns.iter().map(|column| (&column.name, column)),
+                to.columns.iter().map(|column| (&column.name, column)),
+                |column_name| table_name.child(column_name),
+                |diffs, column_name, from, to| {
+                    diff_definition(
+                        diffs,
+                        SchemaObjectType::Column,
+                        column_name,
+                        from,
+                        to,
+                        column_definition,
+                    )
+                },
+            );
+            diff_options(diffs, table_name, &from.options, &to.options);
+        },
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::Index,
+        &from.indexes,
+        &to.indexes,
+        |name| keyspace_name.child(name),
+        |diffs, index_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::Index,
+                index_name,
+                from,
+                to,
+                |index| format!("ON {} ({})", index.table_name, index.target.to_lowercase()),
+            )
+        },
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::MaterializedView,
+        &from.views,
+        &to.views,
+        |name| keyspace_name.child(name),
+        |diffs, view_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::MaterializedView,
+                view_name,
+                from,
+                to,
+                view_definition,
+            );
+            diff_options(diffs, view_name, &from.options, &to.options);
+        },
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::Function,
+        &from.functions,
+        &to.functions,
+        |name| keyspace_name.overload(name),
+        |diffs, function_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::Function,
+                function_name,
+                from,
+                to,
+                function_definition,
+            )
+        },
+    );
+    diff_named(
+        diffs,
+        SchemaObjectType::Aggregate,
+        &from.aggregates,
+        &to.aggregates,
+        |name| keyspace_name.overload(name),
+        |diffs, aggregate_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::Aggregate,
+                aggregate_name,
+                from,
+                to,
+                aggregate_definition,
+            )
+        },
+    );
+}
+
+fn diff_options(
+    diffs: &mut Vec<SchemaDiff>,
+    qualified_name: &SchemaObjectName,
+    from: &BTreeMap<String, String>,
+    to: &BTreeMap<String, String>,
+) {
+    diff_named(
+        diffs,
+        SchemaObjectType::TableOption,
+        from,
+        to,
+        |option_name| qualified_name.child(option_name),
+        |diffs, option_name, from, to| {
+            diff_definition(
+                diffs,
+                SchemaObjectType::TableOption,
+                option_name,
+                from,
+                to,
+                |value| value.clone(),
+            )
+        },
+    );
+}
+
+/// Diffs objects by name, calling `changed` with the qualified name of objects in both.
+fn diff_named<'a, K: Ord + 'a, T: 'a>(
+    diffs: &mut Vec<SchemaDiff>,
+    object_type: SchemaObjectType,
+    from: impl IntoIterator<Item = (&'a K, &'a T)>,
+    to: impl IntoIterator<Item = (&'a K, &'a T)>,
+    qualify: impl Fn(&K) -> SchemaObjectName,
+    mut changed: impl FnMut(&mut Vec<SchemaDiff>, &SchemaObjectName, &T, &T),
+) {
+    let from: BTreeMap<&K, &T> = from.into_iter().collect();
+    let to: BTreeMap<&K, &T> = to.into_iter().collect();
+    for (name, from_object) in &from {
+        match to.get(name) {
+            None => diffs.push(SchemaDiff::Removed {
+                object_type,
+                name: qualify(name),
+            }),
+            Some(to_object) => changed(diffs, &qualify(name), from_object, to_object),
+        }
+    }
+    for name in to.keys().filter(|name| !from.contains_key(*name)) {
+        diffs.push(SchemaDiff::Added {
+            object_type,
+            name: qualify(name),
+        });
+    }
+}
+
+/// Diffs an object in both catalogs by the CQL describing its definition.
+fn diff_definition<T>(
+    diffs: &mut Vec<SchemaDiff>,
+    object_type: SchemaObjectType,
+    name: &SchemaObjectName,
+    from: &T,
+    to: &T,
+    definition: impl Fn(&T) -> String,
+) {
+    let (from, to) = (definition(from), definition(to));
+    if from != to {
+        diffs.push(SchemaDiff::Changed {
+            object_type,
+            name: name.clone(),
+            from,
+            to,
+        });
+    }
+}
+
+fn column_definition(column: &ColumnSchema) -> String {
+    let data_type = normalize_data_type(&column.data_type);
+    if column.is_static {
+        format!("{data_type} STATIC")
+    } else {
+        data_type
+    }
+}
+
+fn primary_key_definition(
+    partition_key: &[String],
+    clustering_key: &[ClusteringColumnSchema],
+) -> String {
+    match clustering_order_cql(clustering_key) {
+        None => primary_key_cql(partition_key, clustering_key),
+        Some(clustering_order) => format!(
+            "{} WITH {clustering_order}",
+            primary_key_cql(partition_key, clustering_key)
+        ),
+    }
+}
+
+/// Renders `PRIMARY KEY ((partition_k1, partition_k2), clustering_k1, ...)`.
+pub(super) fn primary_key_cql(
+    partition_key: &[String],
+    clustering_key: &[ClusteringColumnSchema],
+) -> String {
+    let partition_key: Vec<Cow<str>> = partition_key
+        .iter()
+        .map(|column_name| cql_identifier(column_name))
+        .collect();
+    let mut cql = if partition_key.len() == 1 {
+        format!("PRIMARY KEY ({}", partition_key[0])
+    } else {
+        format!("PRIMARY KEY (({})", partition_key.join(", "))
+    };
+    for clustering_column in clustering_key {
+        cql.push_str(", ");
+        cql.push_str(&cql_identifier(&clustering_column.name));
+    }
+    cql.push(')');
+    cql
+}
+
+/// Renders `CLUSTERING ORDER BY (...)` when a clustering column is not in the default ascending
+/// order.
+pub(super) fn clustering_order_cql(clustering_key: &[ClusteringColumnSchema]) -> Option<String> {
+    if clustering_key
+        .iter()
+        .all(|clustering_column| clustering_column.order == ClusteringOrder::Asc)
+    {
+        return None;
+    }
+    Some(format!(
+        "CLUSTERING ORDER BY ({})",
+        clustering_key
+            .iter()
+            .map(|clustering_column| format!(
+                "{} {}",
+                cql_identifier(&clustering_column.name),
+                match clustering_column.order {
+                    ClusteringOrder::Asc => "ASC",
+                    ClusteringOrder::Desc => "DESC",
+                }
+            ))
+            .collect::<Vec<String>>()
+            .join(", ")
+    ))
+}
+
+fn view_definition(view: &ViewSchema) -> String {
+    let mut column_names: Vec<&String> = view.columns.iter().map(|column| &column.name).collect();
+    column_names.sort();
+    format!(
+        "SELECT {} FROM {} WHERE {} {}",
+        column_names
+            .iter()
+            .map(|column_name| column_name.as_str())
+            .collect::<Vec<&str>>()
+            .join(", "),
+        view.base_table_name,
+        view.where_clause,
+        primary_key_definition(&view.partition_key, &view.clustering_key)
+    )
+}
+
+fn function_definition(function: &FunctionSchema) -> String {
+    format!(
+        "({}) {} ON NULL INPUT RETURNS {} LANGUAGE {} AS {}",
+        function
+            .arguments
+            .iter()
+            .map(|argument| format!("{} {}", argument.name, argument.data_type))
+            .collect::<Vec<String>>()
+            .join(", "),
+        if function.called_on_null_input {
+            "CALLED"
+        } else {
+            "RETURNS NULL"
+        },
+        function.returns,
+        function.language,
+        function.body
+    )
+}
+
+fn aggregate_definition(aggregate: &AggregateSchema) -> String {
+    let mut definition = format!(
+        "({}) SFUNC {} STYPE {}",
+        aggregate.argument, aggregate.state_function, aggregate.state_type
+    );
+    if let Some(final_function) = &aggregate.final_function {
+        definition.push_str(" FINALFUNC ");
+        definition.push_str(final_function);
+    }
+    if let Some(init_condition) = &aggregate.init_condition {
+        definition.push_str(" INITCOND ");
+        definition.push_str(init_condition);
+    }
+    definition
+}
+
+/// Replaces `varchar` with `text`, because the database stores its alias as `text`.
+fn normalize_data_type(data_type: &str) -> String {
+    let mut normalized = String::with_capacity(data_type.len());
+    let mut word = String::new();
+    for c in data_type.chars().chain(std::iter::once(' ')) {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            word.push(c);
+            continue;
+        }
+        if word == "varchar" {
+            normalized.push_str("text");
+        } else {
+            normalized.push_str(&word);
+        }
+        word.clear();
+        normalized.push(c);
+    }
+    normalized.pop();
+    normalized
+}
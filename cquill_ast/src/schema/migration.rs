@@ -0,0 +1,402 @@
+use crate::ast::CqlStatement;
+use crate::parse_cql;
+use crate::schema::diff::{clustering_order_cql, primary_key_cql};
+use crate::schema::*;
+
+impl SchemaCatalog {
+    /// Computes the statements that change this catalog's schema into the schema of `desired`.
+    /// Objects are dropped before others are created, and types and tables are created before the
+    /// indexes and views that depend on them. Names are qualified with their keyspace except for
+    /// functions and aggregates, which are created after a `USE` of their keyspace.
+    ///
+    /// Changes that CQL cannot make in place, such as changing a column's data type or a table's
+    /// primary key, error with [SchemaError::UnsupportedChange].
+    pub fn migration_to(&self, desired: &SchemaCatalog) -> Result<Vec<CqlStatement>, SchemaError> {
+        let diffs = self.diff(desired);
+        let mut cql = Vec::new();
+        for diff in diffs.iter().rev() {
+            if let SchemaDiff::Removed { object_type, name } = diff {
+                cql.push(match object_type {
+                    SchemaObjectType::Aggregate => format!("DROP AGGREGATE {name}"),
+                    SchemaObjectType::Column => format!(
+                        "ALTER TABLE {} DROP {}",
+                        qualifier_cql(name),
+                        cql_identifier(&name.name)
+                    ),
+                    SchemaObjectType::Function => format!("DROP FUNCTION {name}"),
+                    SchemaObjectType::Index => format!("DROP INDEX {name}"),
+                    SchemaObjectType::Keyspace => format!("DROP KEYSPACE {name}"),
+                    SchemaObjectType::MaterializedView => {
+                        format!("DROP MATERIALIZED VIEW {name}")
+                    }
+                    SchemaObjectType::Role => format!("DROP ROLE {name}"),
+                    SchemaObjectType::Table => format!("DROP TABLE {name}"),
+                    SchemaObjectType::Type => format!("DROP TYPE {name}"),
+                    SchemaObjectType::Field | SchemaObjectType::TableOption => {
+                        return Err(SchemaError::UnsupportedChange(Box::new(diff.clone())));
+                    }
+                });
+            }
+        }
+        for diff in &diffs {
+            match diff {
+                SchemaDiff::Added { object_type, name } => {
+                    desired.create_cql(&mut cql, *object_type, name)?
+                }
+                SchemaDiff::Changed {
+                    object_type, name, ..
+                } => match object_type {
+                    SchemaObjectType::Aggregate | SchemaObjectType::Function => {
+                        let mut replaced = Vec::new();
+                        desired.create_cql(&mut replaced, *object_type, name)?;
+                        cql.extend(
+                            replaced
+                                .into_iter()
+                                .map(|create| create.replacen("CREATE", "CREATE OR REPLACE", 1)),
+                        );
+                    }
+                    SchemaObjectType::Index => {
+                        cql.push(format!("DROP INDEX {name}"));
+                        desired.create_cql(&mut cql, *object_type, name)?;
+                    }
+                    SchemaObjectType::MaterializedView => {
+                        cql.push(format!("DROP MATERIALIZED VIEW {name}"));
+                        desired.create_cql(&mut cql, *object_type, name)?;
+                    }
+                    SchemaObjectType::Keyspace => {
+                        let keyspace = desired
+                            .keyspaces
+                            .get(&name.name)
+                            .ok_or_else(|| does_not_exist(*object_type, name.to_string()))?;
+                        cql.push(format!(
+                            "ALTER KEYSPACE {name} WITH replication = {}",
+                            keyspace.replication
+                        ));
+                    }
+                    SchemaObjectType::Role => {
+                        let role = desired
+                            .roles
+                            .get(&name.name)
+                            .ok_or_else(|| does_not_exist(*object_type, name.to_string()))?;
+                        cql.push(format!(
+                            "ALTER ROLE {name} WITH LOGIN = {} AND SUPERUSER = {}",
+                            role.login, role.superuser
+                        ));
+                    }
+                    SchemaObjectType::TableOption => {
+                        desired.create_cql(&mut cql, *object_type, name)?
+                    }
+                    SchemaObjectType::Column
+                    | SchemaObjectType::Field
+                    | SchemaObjectType::Table
+                    | SchemaObjectType::Type => {
+                        return Err(SchemaError::UnsupportedChange(Box::new(diff.clone())));
+                    }
+                },
+                SchemaDiff::Removed { .. } => {}
+            }
+        }
+        // functions and aggregates are created in the keyspace in use
+        cql.dedup_by(|cql, previous| cql == previous && cql.starts_with("USE "));
+        let mut statements = Vec::with_capacity(cql.len());
+        for cql in cql {
+            statements.append(&mut parse_cql(cql)?);
+        }
+        Ok(statements)
+    }
+
+    /// Renders the statements creating an object of this catalog by its qualified name, including
+    /// the objects within a keyspace.
+    fn create_cql(
+        &self,
+        cql: &mut Vec<String>,
+        object_type: SchemaObjectType,
+        name: &SchemaObjectName,
+    ) -> Result<(), SchemaError> {
+        let missing = || does_not_exist(object_type, name.to_string());
+        let keyspace_name = name.keyspace_name.as_ref().unwrap_or(&name.name);
+        let keyspace = self.keyspaces.get(keyspace_name);
+        let parent_name = name.parent_name.as_deref().unwrap_or_default();
+        match object_type {
+            SchemaObjectType::Aggregate => {
+                let aggregate = keyspace
+                    .zip(name.signature())
+                    .and_then(|(keyspace, signature)| keyspace.aggregates.get(&signature))
+                    .ok_or_else(missing)?;
+                cql.push(format!("USE {}", cql_identifier(keyspace_name)));
+                let mut create = format!(
+                    "CREATE AGGREGATE {} ({}) SFUNC {} STYPE {}",
+                    cql_identifier(&name.name),
+                    aggregate.argument,
+                    cql_identifier(&aggregate.state_function),
+                    aggregate.state_type
+                );
+                if let Some(final_function) = &aggregate.final_function {
+                    create.push_str(&format!(" FINALFUNC {}", cql_identifier(final_function)));
+                }
+                if let Some(init_condition) = &aggregate.init_condition {
+                    create.push_str(&format!(" INITCOND {init_condition}"));
+                }
+                cql.push(create);
+            }
+            SchemaObjectType::Column => {
+                let column = keyspace
+                    .and_then(|keyspace| keyspace.tables.get(parent_name))
+                    .and_then(|table| table.column(&name.name))
+                    .ok_or_else(missing)?;
+                cql.push(format!(
+                    "ALTER TABLE {} ADD {}",
+                    qualifier_cql(name),
+                    column_cql(column)
+                ));
+            }
+            SchemaObjectType::Field => {
+                let field = keyspace
+                    .and_then(|keyspace| keyspace.types.get(parent_name))
+                    .and_then(|user_defined_type| user_defined_type.field(&name.name))
+                    .ok_or_else(missing)?;
+                cql.push(format!(
+                    "ALTER TYPE {} ADD {} {}",
+                    qualifier_cql(name),
+                    cql_identifier(&field.name),
+                    field.data_type
+                ));
+            }
+            SchemaObjectType::Function => {
+                let function = keyspace
+                    .zip(name.signature())
+                    .and_then(|(keyspace, signature)| keyspace.functions.get(&signature))
+                    .ok_or_else(missing)?;
+                cql.push(format!("USE {}", cql_identifier(keyspace_name)));
+                cql.push(format!(
+                    "CREATE FUNCTION {} ({}) {} ON NULL INPUT RETURNS {} LANGUAGE {} AS {}",
+                    cql_identifier(&name.name),
+                    function
+                        .arguments
+                        .iter()
+                        .map(|argument| format!(
+                            "{} {}",
+                            cql_identifier(&argument.name),
+                            argument.data_type
+                        ))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    if function.called_on_null_input {
+                        "CALLED"
+                    } else {
+                        "RETURNS NULL"
+                    },
+                    function.returns,
+                    function.language,
+                    function.body
+                ));
+            }
+            SchemaObjectType::Index => {
+                let index = keyspace
+                    .and_then(|keyspace| keyspace.indexes.get(&name.name))
+                    .ok_or_else(missing)?;
+                cql.push(format!(
+                    "CREATE INDEX {} ON {}.{} ({})",
+                    cql_identifier(&name.name),
+                    cql_identifier(keyspace_name),
+                    cql_identifier(&index.table_name),
+                    index.target
+                ));
+            }
+            SchemaObjectType::Keyspace => {
+                let keyspace = keyspace.ok_or_else(missing)?;
+                let mut create = format!(
+                    "CREATE KEYSPACE {name} WITH replication = {}",
+                    keyspace.replication
+                );
+                if !keyspace.durable_writes {
+                    create.push_str(" AND durable_writes = false");
+                }
+                cql.push(create);
+                let children: [(SchemaObjectType, Vec<SchemaObjectName>); 6] = [
+                    (
+                        SchemaObjectType::Type,
+                        keyspace
+                            .types
+                            .keys()
+                            .map(|object_name| name.child(object_name))
+                            .collect(),
+                    ),
+                    (
+                        SchemaObjectType::Table,
+                        keyspace
+                            .tables
+                            .keys()
+                            .map(|object_name| name.child(object_name))
+                            .collect(),
+                    ),
+                    (
+                        SchemaObjectType::Index,
+                        keyspace
+                            .indexes
+                            .keys()
+                            .map(|object_name| name.child(object_name))
+                            .collect(),
+                    ),
+                    (
+                        SchemaObjectType::MaterializedView,
+                        keyspace
+                            .views
+                            .keys()
+                            .map(|object_name| name.child(object_name))
+                            .collect(),
+                    ),
+                    (
+                        SchemaObjectType::Function,
+                        keyspace
+                            .functions
+                            .keys()
+                            .map(|signature| name.overload(signature))
+                            .collect(),
+                    ),
+                    (
+                        SchemaObjectType::Aggregate,
+                        keyspace
+                            .aggregates
+                            .keys()
+                            .map(|signature| name.overload(signature))
+                            .collect(),
+                    ),
+                ];
+                for (object_type, object_names) in children {
+                    for object_name in &object_names {
+                        self.create_cql(cql, object_type, object_name)?;
+                    }
+                }
+            }
+            SchemaObjectType::MaterializedView => {
+                let view = keyspace
+                    .and_then(|keyspace| keyspace.views.get(&name.name))
+                    .ok_or_else(missing)?;
+                let mut create = format!(
+                    "CREATE MATERIALIZED VIEW {name} AS SELECT {} FROM {}.{} WHERE {} {}",
+                    view.columns
+                        .iter()
+                        .map(|column| cql_identifier(&column.name))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    cql_identifier(keyspace_name),
+                    cql_identifier(&view.base_table_name),
+                    view.where_clause,
+                    primary_key_cql(&view.partition_key, &view.clustering_key)
+                );
+                push_table_options(
+                    &mut create,
+                    clustering_order_cql(&view.clustering_key),
+                    &view.options,
+                );
+                cql.push(create);
+            }
+            SchemaObjectType::Role => {
+                let role = self.roles.get(&name.name).ok_or_else(missing)?;
+                cql.push(format!(
+                    "CREATE ROLE {name} WITH LOGIN = {} AND SUPERUSER = {}",
+                    role.login, role.superuser
+                ));
+            }
+            SchemaObjectType::Table => {
+                let table = keyspace
+                    .and_then(|keyspace| keyspace.tables.get(&name.name))
+                    .ok_or_else(missing)?;
+                let mut create = format!(
+                    "CREATE TABLE {name} ({}, {})",
+                    table
+                        .columns
+                        .iter()
+                        .map(column_cql)
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    primary_key_cql(&table.partition_key, &table.clustering_key)
+                );
+                let mut clustering_order = clustering_order_cql(&table.clustering_key);
+                if table.compact_storage {
+                    clustering_order = Some(match clustering_order {
+                        None => "COMPACT STORAGE".to_string(),
+                        Some(clustering_order) => format!("COMPACT STORAGE AND {clustering_order}"),
+                    });
+                }
+                push_table_options(&mut create, clustering_order, &table.options);
+                cql.push(create);
+            }
+            SchemaObjectType::TableOption => {
+                let keyspace = keyspace.ok_or_else(missing)?;
+                let (alter, options) = match keyspace.tables.get(parent_name) {
+                    Some(table) => ("TABLE", &table.options),
+                    None => (
+                        "MATERIALIZED VIEW",
+                        &keyspace.views.get(parent_name).ok_or_else(missing)?.options,
+                    ),
+                };
+                cql.push(format!(
+                    "ALTER {alter} {} WITH {} = {}",
+                    qualifier_cql(name),
+                    name.name,
+                    options.get(&name.name).ok_or_else(missing)?
+                ));
+            }
+            SchemaObjectType::Type => {
+                let user_defined_type = keyspace
+                    .and_then(|keyspace| keyspace.types.get(&name.name))
+                    .ok_or_else(missing)?;
+                cql.push(format!(
+                    "CREATE TYPE {name} ({})",
+                    user_defined_type
+                        .fields
+                        .iter()
+                        .map(|field| format!("{} {}", cql_identifier(&field.name), field.data_type))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders the keyspace and the table, type or view qualifying a name, such as
+/// `big_data_keyspace.big_data_table` for a column.
+fn qualifier_cql(name: &SchemaObjectName) -> String {
+    [&name.keyspace_name, &name.parent_name]
+        .into_iter()
+        .flatten()
+        .map(|qualifier| cql_identifier(qualifier))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn column_cql(column: &ColumnSchema) -> String {
+    if column.is_static {
+        format!(
+            "{} {} STATIC",
+            cql_identifier(&column.name),
+            column.data_type
+        )
+    } else {
+        format!("{} {}", cql_identifier(&column.name), column.data_type)
+    }
+}
+
+/// Appends `WITH` and the `AND` separated clustering order and options of a table or view.
+fn push_table_options(
+    cql: &mut String,
+    clustering_order: Option<String>,
+    options: &BTreeMap<String, String>,
+) {
+    let attributes: Vec<String> = clustering_order
+        .into_iter()
+        .chain(
+            options
+                .iter()
+                .map(|(option_name, value)| format!("{option_name} = {value}")),
+        )
+        .collect();
+    if !attributes.is_empty() {
+        cql.push_str(" WITH ");
+        cql.push_str(&attributes.join(" AND "));
+    }
+}
@@ -0,0 +1,1373 @@
+//! A catalog of the schema created by CQL statements, built by applying parsed statements in the
+//! order they are migrated without connecting to a cluster.
+//!
+//! Names are stored lowercase unless double-quoted, and data types, option values and where clauses are stored as the
+//! canonical CQL rendered by the AST's `Display` implementations. Triggers and statements that do
+//! not change the schema, such as `INSERT` or `SELECT`, are applied without effect.
+//!
+//! Applying a statement validates it against the schema and errors for changes the database would
+//! reject, such as altering a table that does not exist, declaring a column twice or referencing a
+//! user defined type that has not been created.
+
+mod diff;
+mod migration;
+
+pub use diff::{SchemaDiff, SchemaObjectName};
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+use crate::ast::*;
+use crate::lex::{TokenName, Tokenizer};
+use crate::visit::{Visit, walk_data_type};
+use crate::{ParseError, parse_cql};
+
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("{object_type} {name} already exists")]
+    AlreadyExists {
+        object_type: SchemaObjectType,
+        name: String,
+    },
+    #[error("clustering order of {name} is not for a clustering column")]
+    ClusteringOrderOnNonClusteringColumn { name: String },
+    #[error("table {name} mixes counter and non-counter columns")]
+    CounterColumnMix { name: String },
+    #[error("primary key column {name} cannot be dropped")]
+    DropPrimaryKeyColumn { name: String },
+    #[error("{object_type} {name} does not exist")]
+    DoesNotExist {
+        object_type: SchemaObjectType,
+        name: String,
+    },
+    #[error("{object_type} {name} has overloads and needs argument types")]
+    AmbiguousOverload {
+        object_type: SchemaObjectType,
+        name: String,
+    },
+    #[error("table {name} does not declare a primary key")]
+    MissingPrimaryKey { name: String },
+    #[error("{name} is not qualified with a keyspace and no keyspace is in use")]
+    NoKeyspace { name: String },
+    #[error("primary key column {name} is not declared")]
+    UndeclaredPrimaryKeyColumn { name: String },
+    #[error("cql cannot make schema change {0}")]
+    UnsupportedChange(Box<SchemaDiff>),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SchemaObjectType {
+    Aggregate,
+    Column,
+    Field,
+    Function,
+    Index,
+    Keyspace,
+    MaterializedView,
+    Role,
+    Table,
+    /// A `WITH` option of a table or materialized view such as `default_time_to_live`.
+    TableOption,
+    Type,
+}
+
+impl Display for SchemaObjectType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SchemaObjectType::Aggregate => "aggregate",
+            SchemaObjectType::Column => "column",
+            SchemaObjectType::Field => "field",
+            SchemaObjectType::Function => "function",
+            SchemaObjectType::Index => "index",
+            SchemaObjectType::Keyspace => "keyspace",
+            SchemaObjectType::MaterializedView => "materialized view",
+            SchemaObjectType::Role => "role",
+            SchemaObjectType::Table => "table",
+            SchemaObjectType::TableOption => "table option",
+            SchemaObjectType::Type => "type",
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SchemaCatalog {
+    pub keyspaces: BTreeMap<String, KeyspaceSchema>,
+    pub roles: BTreeMap<String, RoleSchema>,
+    scope: KeyspaceScope,
+}
+
+/// Catalogs are equal when their keyspaces and roles are equal regardless of the keyspace in use.
+impl PartialEq for SchemaCatalog {
+    fn eq(&self, other: &Self) -> bool {
+        self.keyspaces == other.keyspaces && self.roles == other.roles
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeyspaceSchema {
+    pub replication: KeyspaceReplication,
+    pub durable_writes: bool,
+    pub tables: BTreeMap<String, TableSchema>,
+    pub types: BTreeMap<String, TypeSchema>,
+    pub indexes: BTreeMap<String, IndexSchema>,
+    pub views: BTreeMap<String, ViewSchema>,
+    pub functions: BTreeMap<FunctionSignature, FunctionSchema>,
+    pub aggregates: BTreeMap<FunctionSignature, AggregateSchema>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableSchema {
+    /// Columns in the order they were declared and added.
+    pub columns: Vec<ColumnSchema>,
+    pub partition_key: Vec<String>,
+    pub clustering_key: Vec<ClusteringColumnSchema>,
+    pub compact_storage: bool,
+    /// Table options such as `comment` or `default_time_to_live` with their CQL values.
+    pub options: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnSchema {
+    pub name: String,
+    pub data_type: String,
+    pub is_static: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClusteringColumnSchema {
+    pub name: String,
+    pub order: ClusteringOrder,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeSchema {
+    pub fields: Vec<FieldSchema>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldSchema {
+    pub name: String,
+    pub data_type: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndexSchema {
+    pub table_name: String,
+    /// The indexed column such as `text_column` or `KEYS(map_column)`.
+    pub target: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewSchema {
+    pub base_table_name: String,
+    pub columns: Vec<ColumnSchema>,
+    /// The relations of the view's `WHERE` clause.
+    pub where_clause: String,
+    pub partition_key: Vec<String>,
+    pub clustering_key: Vec<ClusteringColumnSchema>,
+    pub options: BTreeMap<String, String>,
+}
+
+/// The name and argument types of a function or aggregate, which overloads of a name differ by.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FunctionSignature {
+    pub name: String,
+    pub argument_types: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FunctionSchema {
+    pub arguments: Vec<FieldSchema>,
+    pub called_on_null_input: bool,
+    pub returns: String,
+    pub language: String,
+    pub body: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregateSchema {
+    pub argument: String,
+    pub state_function: String,
+    pub state_type: String,
+    pub final_function: Option<String>,
+    pub init_condition: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleSchema {
+    pub login: bool,
+    pub superuser: bool,
+}
+
+impl SchemaCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The keyspace selected by the most recently applied `USE` statement.
+    pub fn current_keyspace(&self) -> Option<&String> {
+        self.scope.current()
+    }
+
+    pub fn keyspace(&self, keyspace_name: &str) -> Option<&KeyspaceSchema> {
+        self.keyspaces.get(&identifier_name(keyspace_name))
+    }
+
+    pub fn table(&self, keyspace_name: &str, table_name: &str) -> Option<&TableSchema> {
+        self.keyspace(keyspace_name)?
+            .tables
+            .get(&identifier_name(table_name))
+    }
+
+    /// Parses and applies each statement of a CQL script.
+    pub fn apply_cql(&mut self, cql: &str) -> Result<(), SchemaError> {
+        for statement in parse_cql(cql.to_string())? {
+            self.apply(&statement)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a statement's changes to the schema, returning an error for changes the database
+    /// would reject because an object already exists or does not exist.
+    pub fn apply(&mut self, statement: &CqlStatement) -> Result<(), SchemaError> {
+        match statement {
+            CqlStatement::Alter(statement) => self.apply_alter(statement),
+            CqlStatement::Create(statement) => self.apply_create(statement),
+            CqlStatement::Drop(statement) => self.apply_drop(statement),
+            CqlStatement::Use(use_statement) => {
+                let keyspace_name = name(&use_statement.keyspace_name);
+                if !self.keyspaces.contains_key(&keyspace_name) {
+                    return Err(does_not_exist(SchemaObjectType::Keyspace, keyspace_name));
+                }
+                self.scope.update(statement);
+                Ok(())
+            }
+            CqlStatement::Batch(_)
+            | CqlStatement::Delete(_)
+            | CqlStatement::Describe(_)
+            | CqlStatement::Insert(_)
+            | CqlStatement::Select(_)
+            | CqlStatement::Truncate(_)
+            | CqlStatement::Update(_) => Ok(()),
+        }
+    }
+
+    fn apply_alter(&mut self, statement: &AlterStatement) -> Result<(), SchemaError> {
+        match statement {
+            AlterStatement::Keyspace(statement) => {
+                let keyspace_name = name(&statement.keyspace_name);
+                let Some(keyspace) = self.keyspaces.get_mut(&keyspace_name) else {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::Keyspace,
+                        keyspace_name,
+                    );
+                };
+                if let Some(replication) = &statement.replication {
+                    keyspace.replication = replication.clone();
+                }
+                if let Some(durable_writes) = statement.durable_writes {
+                    keyspace.durable_writes = durable_writes;
+                }
+                Ok(())
+            }
+            AlterStatement::MaterializedView(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.view_name)?;
+                let view_name = name(&statement.view_name);
+                let Some(view) = keyspace.views.get_mut(&view_name) else {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::MaterializedView,
+                        format!("{keyspace_name}.{view_name}"),
+                    );
+                };
+                apply_table_options(&mut view.options, &statement.attributes);
+                Ok(())
+            }
+            AlterStatement::Role(statement) => {
+                let role_name = name(&statement.role_name);
+                let Some(role) = self.roles.get_mut(&role_name) else {
+                    return if_exists_or(statement.if_exists, SchemaObjectType::Role, role_name);
+                };
+                apply_role_attributes(role, &statement.attributes);
+                Ok(())
+            }
+            AlterStatement::Table(statement) => self.apply_alter_table(statement),
+            AlterStatement::Type(statement) => self.apply_alter_type(statement),
+            AlterStatement::User(statement) => {
+                let user_name = name(&statement.user_name);
+                let Some(role) = self.roles.get_mut(&user_name) else {
+                    return if_exists_or(statement.if_exists, SchemaObjectType::Role, user_name);
+                };
+                if let Some(user_status) = &statement.user_status {
+                    role.superuser = matches!(user_status, CreateUserStatus::Superuser);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn apply_alter_table(&mut self, statement: &AlterTableStatement) -> Result<(), SchemaError> {
+        let (keyspace_name, keyspace) =
+            self.resolve_keyspace(&statement.keyspace_name, &statement.table_name)?;
+        let table_name = name(&statement.table_name);
+        let qualified_table_name = format!("{keyspace_name}.{table_name}");
+        let Some(table) = keyspace.tables.get_mut(&table_name) else {
+            return if_exists_or(
+                statement.if_exists,
+                SchemaObjectType::Table,
+                qualified_table_name,
+            );
+        };
+        match &statement.instruction {
+            AlterTableInstruction::AddColumns {
+                if_not_exists,
+                columns,
+            } => {
+                for column in columns {
+                    let column_name = name(&column.column_name);
+                    if table.column(&column_name).is_some() {
+                        if *if_not_exists {
+                            continue;
+                        }
+                        return Err(already_exists(
+                            SchemaObjectType::Column,
+                            format!("{qualified_table_name}.{column_name}"),
+                        ));
+                    }
+                    check_user_defined_types(&keyspace_name, &keyspace.types, &column.data_type)?;
+                    table.columns.push(ColumnSchema {
+                        name: column_name,
+                        data_type: data_type_cql(&column.data_type),
+                        is_static: column.is_static,
+                    });
+                }
+                table.check_counter_columns(&qualified_table_name)?;
+            }
+            AlterTableInstruction::DropColumns {
+                if_exists,
+                column_names,
+            } => {
+                for column_name in column_names {
+                    let column_name = name(column_name);
+                    let qualified_column_name = format!("{qualified_table_name}.{column_name}");
+                    if table.column(&column_name).is_none() {
+                        if *if_exists {
+                            continue;
+                        }
+                        return Err(does_not_exist(
+                            SchemaObjectType::Column,
+                            qualified_column_name,
+                        ));
+                    }
+                    if table.is_primary_key_column(&column_name) {
+                        return Err(SchemaError::DropPrimaryKeyColumn {
+                            name: qualified_column_name,
+                        });
+                    }
+                    table.columns.retain(|column| column.name != column_name);
+                }
+            }
+            AlterTableInstruction::RenameColumns { if_exists, renames } => {
+                for (from, to) in renames {
+                    let (from, to) = (name(from), name(to));
+                    if table.column(&from).is_none() {
+                        if *if_exists {
+                            continue;
+                        }
+                        return Err(does_not_exist(
+                            SchemaObjectType::Column,
+                            format!("{qualified_table_name}.{from}"),
+                        ));
+                    }
+                    if table.column(&to).is_some() {
+                        return Err(already_exists(
+                            SchemaObjectType::Column,
+                            format!("{qualified_table_name}.{to}"),
+                        ));
+                    }
+                    table.rename_column(&from, &to);
+                }
+            }
+            AlterTableInstruction::With(attributes) => {
+                apply_table_options(&mut table.options, attributes);
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_alter_type(&mut self, statement: &AlterTypeStatement) -> Result<(), SchemaError> {
+        let (keyspace_name, keyspace) =
+            self.resolve_keyspace(&statement.keyspace_name, &statement.type_name)?;
+        let type_name = name(&statement.type_name);
+        let qualified_type_name = format!("{keyspace_name}.{type_name}");
+        if !keyspace.types.contains_key(&type_name) {
+            return if_exists_or(
+                statement.if_exists,
+                SchemaObjectType::Type,
+                qualified_type_name,
+            );
+        }
+        if let AlterTypeInstruction::AddField { data_type, .. } = &statement.instruction {
+            check_user_defined_types(&keyspace_name, &keyspace.types, data_type)?;
+        }
+        let udt = keyspace.types.get_mut(&type_name).unwrap();
+        match &statement.instruction {
+            AlterTypeInstruction::AddField {
+                if_not_exists,
+                field_name,
+                data_type,
+            } => {
+                let field_name = name(field_name);
+                if udt.field(&field_name).is_some() {
+                    return if *if_not_exists {
+                        Ok(())
+                    } else {
+                        Err(already_exists(
+                            SchemaObjectType::Field,
+                            format!("{qualified_type_name}.{field_name}"),
+                        ))
+                    };
+                }
+                udt.fields.push(FieldSchema {
+                    name: field_name,
+                    data_type: data_type_cql(data_type),
+                });
+            }
+            AlterTypeInstruction::RenameFields { if_exists, renames } => {
+                for (from, to) in renames {
+                    let (from, to) = (name(from), name(to));
+                    if udt.field(&to).is_some() {
+                        return Err(already_exists(
+                            SchemaObjectType::Field,
+                            format!("{qualified_type_name}.{to}"),
+                        ));
+                    }
+                    match udt.fields.iter_mut().find(|field| field.name == from) {
+                        Some(field) => field.name = to,
+                        None if *if_exists => {}
+                        None => {
+                            return Err(does_not_exist(
+                                SchemaObjectType::Field,
+                                format!("{qualified_type_name}.{from}"),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_create(&mut self, statement: &CreateStatement) -> Result<(), SchemaError> {
+        match statement {
+            CreateStatement::Aggregate(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.function_name)?;
+                let aggregate_name = FunctionSignature::new(
+                    name(&statement.function_name),
+                    std::iter::once(&statement.function_arg),
+                );
+                if keyspace.aggregates.contains_key(&aggregate_name) {
+                    match statement.if_exists_behavior {
+                        CreateIfExistsBehavior::DoNotError => return Ok(()),
+                        CreateIfExistsBehavior::Error => {
+                            return Err(already_exists(
+                                SchemaObjectType::Aggregate,
+                                format!("{keyspace_name}.{aggregate_name}"),
+                            ));
+                        }
+                        CreateIfExistsBehavior::Replace => {}
+                    }
+                }
+                keyspace.aggregates.insert(
+                    aggregate_name,
+                    AggregateSchema {
+                        argument: data_type_cql(&statement.function_arg),
+                        state_function: name(&statement.state_function),
+                        state_type: data_type_cql(&statement.state_type),
+                        final_function: statement.final_function.as_ref().map(name),
+                        init_condition: statement
+                            .init_condition
+                            .as_ref()
+                            .map(|init_condition| init_condition.to_string()),
+                    },
+                );
+                Ok(())
+            }
+            CreateStatement::Function(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.function_name)?;
+                let function_name = FunctionSignature::new(
+                    name(&statement.function_name),
+                    statement
+                        .function_args
+                        .iter()
+                        .map(|(_, data_type)| data_type),
+                );
+                if keyspace.functions.contains_key(&function_name) {
+                    match statement.if_exists_behavior {
+                        CreateIfExistsBehavior::DoNotError => return Ok(()),
+                        CreateIfExistsBehavior::Error => {
+                            return Err(already_exists(
+                                SchemaObjectType::Function,
+                                format!("{keyspace_name}.{function_name}"),
+                            ));
+                        }
+                        CreateIfExistsBehavior::Replace => {}
+                    }
+                }
+                keyspace.functions.insert(
+                    function_name,
+                    FunctionSchema {
+                        arguments: statement
+                            .function_args
+                            .iter()
+                            .map(|(arg_name, data_type)| FieldSchema {
+                                name: name(arg_name),
+                                data_type: data_type_cql(data_type),
+                            })
+                            .collect(),
+                        called_on_null_input: matches!(
+                            statement.on_null_input,
+                            OnNullInput::Called
+                        ),
+                        returns: data_type_cql(&statement.returns),
+                        language: name(&statement.language),
+                        body: statement.function_body.to_string(),
+                    },
+                );
+                Ok(())
+            }
+            CreateStatement::Index(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.table_name)?;
+                let table_name = name(&statement.table_name);
+                if !keyspace.tables.contains_key(&table_name) {
+                    return Err(does_not_exist(
+                        SchemaObjectType::Table,
+                        format!("{keyspace_name}.{table_name}"),
+                    ));
+                }
+                let index_name = match &statement.index_name {
+                    Some(index_name) => name(index_name),
+                    None => format!(
+                        "{table_name}_{}_idx",
+                        match &statement.on_column {
+                            CreateIndexColumn::Column(column_name)
+                            | CreateIndexColumn::FullCollection(column_name)
+                            | CreateIndexColumn::MapEntries(column_name)
+                            | CreateIndexColumn::MapValues(column_name)
+                            | CreateIndexColumn::MapKeys(column_name) => name(column_name),
+                        }
+                    ),
+                };
+                if keyspace.indexes.contains_key(&index_name) {
+                    return if_not_exists_or(
+                        statement.if_not_exists,
+                        SchemaObjectType::Index,
+                        format!("{keyspace_name}.{index_name}"),
+                    );
+                }
+                keyspace.indexes.insert(
+                    index_name,
+                    IndexSchema {
+                        table_name,
+                        target: statement.on_column.to_string(),
+                    },
+                );
+                Ok(())
+            }
+            CreateStatement::Keyspace(statement) => {
+                let keyspace_name = name(&statement.keyspace_name);
+                if self.keyspaces.contains_key(&keyspace_name) {
+                    return if_not_exists_or(
+                        statement.if_not_exists,
+                        SchemaObjectType::Keyspace,
+                        keyspace_name,
+                    );
+                }
+                self.keyspaces.insert(
+                    keyspace_name,
+                    KeyspaceSchema {
+                        replication: statement.replication.clone(),
+                        durable_writes: statement.durable_writes.unwrap_or(true),
+                        tables: BTreeMap::new(),
+                        types: BTreeMap::new(),
+                        indexes: BTreeMap::new(),
+                        views: BTreeMap::new(),
+                        functions: BTreeMap::new(),
+                        aggregates: BTreeMap::new(),
+                    },
+                );
+                Ok(())
+            }
+            CreateStatement::MaterializedView(statement) => {
+                self.apply_create_materialized_view(statement)
+            }
+            CreateStatement::Role(statement) => {
+                let role_name = name(&statement.role_name);
+                if self.roles.contains_key(&role_name) {
+                    return if_not_exists_or(
+                        statement.if_not_exists,
+                        SchemaObjectType::Role,
+                        role_name,
+                    );
+                }
+                let mut role = RoleSchema {
+                    login: false,
+                    superuser: false,
+                };
+                if let Some(attributes) = &statement.attributes {
+                    apply_role_attributes(&mut role, attributes);
+                }
+                self.roles.insert(role_name, role);
+                Ok(())
+            }
+            CreateStatement::Table(statement) => self.apply_create_table(statement),
+            CreateStatement::Trigger(_) => Ok(()),
+            CreateStatement::Type(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.type_name)?;
+                let type_name = name(&statement.type_name);
+                if keyspace.types.contains_key(&type_name) {
+                    return if_not_exists_or(
+                        statement.if_not_exists,
+                        SchemaObjectType::Type,
+                        format!("{keyspace_name}.{type_name}"),
+                    );
+                }
+                for (_, data_type) in &statement.fields {
+                    check_user_defined_types(&keyspace_name, &keyspace.types, data_type)?;
+                }
+                keyspace.types.insert(
+                    type_name,
+                    TypeSchema {
+                        fields: statement
+                            .fields
+                            .iter()
+                            .map(|(field_name, data_type)| FieldSchema {
+                                name: name(field_name),
+                                data_type: data_type_cql(data_type),
+                            })
+                            .collect(),
+                    },
+                );
+                Ok(())
+            }
+            CreateStatement::User(statement) => {
+                let user_name = name(&statement.user_name);
+                if self.roles.contains_key(&user_name) {
+                    return if_not_exists_or(
+                        statement.if_not_exists,
+                        SchemaObjectType::Role,
+                        user_name,
+                    );
+                }
+                self.roles.insert(
+                    user_name,
+                    RoleSchema {
+                        login: true,
+                        superuser: matches!(
+                            statement.user_status,
+                            Some(CreateUserStatus::Superuser)
+                        ),
+                    },
+                );
+                Ok(())
+            }
+        }
+    }
+
+    fn apply_create_materialized_view(
+        &mut self,
+        statement: &CreateMaterializedViewStatement,
+    ) -> Result<(), SchemaError> {
+        let (keyspace_name, keyspace) =
+            self.resolve_keyspace(&statement.keyspace_name, &statement.view_name)?;
+        let view_name = name(&statement.view_name);
+        if keyspace.views.contains_key(&view_name) || keyspace.tables.contains_key(&view_name) {
+            return if_not_exists_or(
+                statement.if_not_exists,
+                SchemaObjectType::MaterializedView,
+                format!("{keyspace_name}.{view_name}"),
+            );
+        }
+        let base_table_name = name(&statement.base_table_name);
+        let Some(base_table) = keyspace.tables.get(&base_table_name) else {
+            return Err(does_not_exist(
+                SchemaObjectType::Table,
+                format!("{keyspace_name}.{base_table_name}"),
+            ));
+        };
+        let columns = match &statement.select_clause {
+            SelectClause::All => base_table.columns.clone(),
+            SelectClause::Selectors(selectors) => {
+                let mut columns = Vec::new();
+                for selector_definition in selectors {
+                    if let Selector::Term(ExpressionTerm::Column(column_name)) =
+                        &selector_definition.selector
+                    {
+                        let column_name = name(column_name);
+                        match base_table.column(&column_name) {
+                            None => {
+                                return Err(does_not_exist(
+                                    SchemaObjectType::Column,
+                                    format!("{keyspace_name}.{base_table_name}.{column_name}"),
+                                ));
+                            }
+                            Some(column) => columns.push(column.clone()),
+                        }
+                    }
+                }
+                columns
+            }
+        };
+        let attributes = statement.attributes.as_deref().unwrap_or_default();
+        let qualified_view_name = format!("{keyspace_name}.{view_name}");
+        let (partition_key, clustering_key) =
+            primary_key_columns(&qualified_view_name, &statement.primary_key, attributes)?;
+        check_primary_key_declared(
+            &qualified_view_name,
+            &columns,
+            &partition_key,
+            &clustering_key,
+        )?;
+        let mut options = BTreeMap::new();
+        apply_table_options(&mut options, attributes);
+        keyspace.views.insert(
+            view_name,
+            ViewSchema {
+                base_table_name,
+                columns,
+                where_clause: statement
+                    .where_clause
+                    .to_string()
+                    .trim_start_matches("WHERE ")
+                    .to_string(),
+                partition_key,
+                clustering_key,
+                options,
+            },
+        );
+        Ok(())
+    }
+
+    fn apply_create_table(&mut self, statement: &CreateTableStatement) -> Result<(), SchemaError> {
+        let (keyspace_name, keyspace) =
+            self.resolve_keyspace(&statement.keyspace_name, &statement.table_name)?;
+        let table_name = name(&statement.table_name);
+        let qualified_table_name = format!("{keyspace_name}.{table_name}");
+        if keyspace.tables.contains_key(&table_name) || keyspace.views.contains_key(&table_name) {
+            return if_not_exists_or(
+                statement.if_not_exists,
+                SchemaObjectType::Table,
+                qualified_table_name,
+            );
+        }
+        let attributes = statement.attributes.as_deref().unwrap_or_default();
+        let mut columns: Vec<ColumnSchema> = Vec::new();
+        let mut primary_key = None;
+        for column_definition in &statement.column_definitions {
+            match column_definition {
+                ColumnDefinition::Column {
+                    column_name,
+                    data_type,
+                    attribute,
+                } => {
+                    let column_name = name(column_name);
+                    if columns.iter().any(|column| column.name == column_name) {
+                        return Err(already_exists(
+                            SchemaObjectType::Column,
+                            format!("{qualified_table_name}.{column_name}"),
+                        ));
+                    }
+                    check_user_defined_types(&keyspace_name, &keyspace.types, data_type)?;
+                    if let Some(ColumnDefinitionAttribute::PrimaryKey) = attribute {
+                        primary_key = Some(clustering_ordered_key(
+                            &qualified_table_name,
+                            vec![column_name.clone()],
+                            Vec::new(),
+                            attributes,
+                        )?);
+                    }
+                    columns.push(ColumnSchema {
+                        name: column_name,
+                        data_type: data_type_cql(data_type),
+                        is_static: matches!(attribute, Some(ColumnDefinitionAttribute::Static)),
+                    });
+                }
+                ColumnDefinition::PrimaryKey(primary_key_definition) => {
+                    primary_key = Some(primary_key_columns(
+                        &qualified_table_name,
+                        primary_key_definition,
+                        attributes,
+                    )?);
+                }
+            }
+        }
+        let Some((partition_key, clustering_key)) = primary_key else {
+            return Err(SchemaError::MissingPrimaryKey {
+                name: qualified_table_name,
+            });
+        };
+        check_primary_key_declared(
+            &qualified_table_name,
+            &columns,
+            &partition_key,
+            &clustering_key,
+        )?;
+        let mut options = BTreeMap::new();
+        apply_table_options(&mut options, attributes);
+        let table = TableSchema {
+            columns,
+            partition_key,
+            clustering_key,
+            compact_storage: attributes
+                .iter()
+                .any(|attribute| matches!(attribute, TableDefinitionAttribute::CompactStorage)),
+            options,
+        };
+        table.check_counter_columns(&qualified_table_name)?;
+        keyspace.tables.insert(table_name, table);
+        Ok(())
+    }
+
+    fn apply_drop(&mut self, statement: &DropStatement) -> Result<(), SchemaError> {
+        match statement {
+            DropStatement::Aggregate(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.aggregate_name)?;
+                drop_overload(
+                    &mut keyspace.aggregates,
+                    SchemaObjectType::Aggregate,
+                    &keyspace_name,
+                    name(&statement.aggregate_name),
+                    &statement.signature,
+                    statement.if_exists,
+                )
+            }
+            DropStatement::Function(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.function_name)?;
+                drop_overload(
+                    &mut keyspace.functions,
+                    SchemaObjectType::Function,
+                    &keyspace_name,
+                    name(&statement.function_name),
+                    &statement.signature,
+                    statement.if_exists,
+                )
+            }
+            DropStatement::Index(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.index_name)?;
+                let index_name = name(&statement.index_name);
+                if keyspace.indexes.remove(&index_name).is_none() {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::Index,
+                        format!("{keyspace_name}.{index_name}"),
+                    );
+                }
+                Ok(())
+            }
+            DropStatement::Keyspace(statement) => {
+                let keyspace_name = name(&statement.keyspace_name);
+                if self.keyspaces.remove(&keyspace_name).is_none() {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::Keyspace,
+                        keyspace_name,
+                    );
+                }
+                Ok(())
+            }
+            DropStatement::MaterializedView(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.view_name)?;
+                let view_name = name(&statement.view_name);
+                if keyspace.views.remove(&view_name).is_none() {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::MaterializedView,
+                        format!("{keyspace_name}.{view_name}"),
+                    );
+                }
+                Ok(())
+            }
+            DropStatement::Role(statement) => {
+                let role_name = name(&statement.role_name);
+                if self.roles.remove(&role_name).is_none() {
+                    return if_exists_or(statement.if_exists, SchemaObjectType::Role, role_name);
+                }
+                Ok(())
+            }
+            DropStatement::Table(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.table_name)?;
+                let table_name = name(&statement.table_name);
+                if keyspace.tables.remove(&table_name).is_none() {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::Table,
+                        format!("{keyspace_name}.{table_name}"),
+                    );
+                }
+                keyspace
+                    .indexes
+                    .retain(|_, index| index.table_name != table_name);
+                Ok(())
+            }
+            DropStatement::Trigger(_) => Ok(()),
+            DropStatement::Type(statement) => {
+                let (keyspace_name, keyspace) =
+                    self.resolve_keyspace(&statement.keyspace_name, &statement.type_name)?;
+                let type_name = name(&statement.type_name);
+                if keyspace.types.remove(&type_name).is_none() {
+                    return if_exists_or(
+                        statement.if_exists,
+                        SchemaObjectType::Type,
+                        format!("{keyspace_name}.{type_name}"),
+                    );
+                }
+                Ok(())
+            }
+            DropStatement::User(statement) => {
+                let user_name = name(&statement.user_name);
+                if self.roles.remove(&user_name).is_none() {
+                    return if_exists_or(statement.if_exists, SchemaObjectType::Role, user_name);
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Resolves the keyspace of an object from its qualified name or the keyspace in use.
+    fn resolve_keyspace(
+        &mut self,
+        keyspace_name: &Option<TokenView>,
+        object_name: &TokenView,
+    ) -> Result<(String, &mut KeyspaceSchema), SchemaError> {
+        let Some(keyspace_name) = self.scope.resolve(keyspace_name) else {
+            return Err(SchemaError::NoKeyspace {
+                name: name(object_name),
+            });
+        };
+        let keyspace_name = identifier_name(&keyspace_name);
+        match self.keyspaces.get_mut(&keyspace_name) {
+            None => Err(does_not_exist(SchemaObjectType::Keyspace, keyspace_name)),
+            Some(keyspace) => Ok((keyspace_name, keyspace)),
+        }
+    }
+}
+
+impl TableSchema {
+    pub fn column(&self, column_name: &str) -> Option<&ColumnSchema> {
+        self.columns
+            .iter()
+            .find(|column| column.name == column_name)
+    }
+
+    pub fn is_primary_key_column(&self, column_name: &str) -> bool {
+        self.partition_key.iter().any(|name| name == column_name)
+            || self
+                .clustering_key
+                .iter()
+                .any(|clustering_column| clustering_column.name == column_name)
+    }
+
+    /// Checks that a table with counter columns has only counter columns outside its primary key.
+    fn check_counter_columns(&self, qualified_table_name: &str) -> Result<(), SchemaError> {
+        let (counters, non_counters): (Vec<&ColumnSchema>, Vec<&ColumnSchema>) = self
+            .columns
+            .iter()
+            .filter(|column| !self.is_primary_key_column(&column.name))
+            .partition(|column| column.data_type == "counter");
+        if counters.is_empty() || non_counters.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaError::CounterColumnMix {
+                name: qualified_table_name.to_string(),
+            })
+        }
+    }
+
+    fn rename_column(&mut self, from: &str, to: &str) {
+        for column in self.columns.iter_mut().filter(|column| column.name == from) {
+            column.name = to.to_string();
+        }
+        for name in self.partition_key.iter_mut().filter(|name| *name == from) {
+            *name = to.to_string();
+        }
+        for clustering_column in self
+            .clustering_key
+            .iter_mut()
+            .filter(|clustering_column| clustering_column.name == from)
+        {
+            clustering_column.name = to.to_string();
+        }
+    }
+}
+
+impl TypeSchema {
+    pub fn field(&self, field_name: &str) -> Option<&FieldSchema> {
+        self.fields.iter().find(|field| field.name == field_name)
+    }
+}
+
+fn name(token_view: &TokenView) -> String {
+    identifier_name(&token_view.value())
+}
+
+/// The name of a CQL identifier, which is case-sensitive when enclosed in double quotes and
+/// lowercased otherwise.
+fn identifier_name(identifier: &str) -> String {
+    match identifier
+        .strip_prefix('"')
+        .and_then(|identifier| identifier.strip_suffix('"'))
+    {
+        Some(quoted) => quoted.replace("\"\"", "\""),
+        None => identifier.to_lowercase(),
+    }
+}
+
+/// Renders a name as a CQL identifier, double-quoting names that would otherwise be lowercased
+/// or read as a keyword.
+pub(crate) fn cql_identifier(name: &str) -> Cow<'_, str> {
+    let is_unquoted_identifier =
+        Tokenizer::new(name)
+            .tokenize()
+            .is_ok_and(|tokens| match tokens.as_slice() {
+                [token] => {
+                    token.name == TokenName::Identifier
+                        && token.range.begin() == 0
+                        && token.range.end() + 1 == name.len()
+                }
+                _ => false,
+            });
+    if is_unquoted_identifier && !name.starts_with('"') && name.to_lowercase() == name {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Owned(format!("\"{}\"", name.replace('"', "\"\"")))
+    }
+}
+
+impl FunctionSignature {
+    fn new<'a>(name: String, argument_types: impl Iterator<Item = &'a CqlDataType>) -> Self {
+        FunctionSignature {
+            name,
+            argument_types: argument_types.map(data_type_cql).collect(),
+        }
+    }
+}
+
+/// Renders the signature as CQL such as `big_data_fn(int, text)`.
+impl Display for FunctionSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}({})",
+            cql_identifier(&self.name),
+            self.argument_types.join(", ")
+        )
+    }
+}
+
+/// Drops the overload of a function or aggregate selected by the argument types of a `DROP`
+/// statement, or its only overload when the statement does not specify argument types.
+fn drop_overload<T>(
+    overloads: &mut BTreeMap<FunctionSignature, T>,
+    object_type: SchemaObjectType,
+    keyspace_name: &str,
+    function_name: String,
+    signature: &Option<Vec<CqlDataType>>,
+    if_exists: bool,
+) -> Result<(), SchemaError> {
+    let (key, name) = match signature {
+        Some(argument_types) => {
+            let signature = FunctionSignature::new(function_name, argument_types.iter());
+            let name = signature.to_string();
+            (
+                overloads.contains_key(&signature).then_some(signature),
+                name,
+            )
+        }
+        None => {
+            let mut keys = overloads
+                .keys()
+                .filter(|signature| signature.name == function_name);
+            match (keys.next(), keys.next()) {
+                (Some(_), Some(_)) => {
+                    return Err(SchemaError::AmbiguousOverload {
+                        object_type,
+                        name: format!("{keyspace_name}.{}", cql_identifier(&function_name)),
+                    });
+                }
+                (key, _) => (key.cloned(), cql_identifier(&function_name).into_owned()),
+            }
+        }
+    };
+    match key {
+        Some(key) => {
+            overloads.remove(&key);
+            Ok(())
+        }
+        None => if_exists_or(if_exists, object_type, format!("{keyspace_name}.{name}")),
+    }
+}
+
+fn already_exists(object_type: SchemaObjectType, name: String) -> SchemaError {
+    SchemaError::AlreadyExists { object_type, name }
+}
+
+fn does_not_exist(object_type: SchemaObjectType, name: String) -> SchemaError {
+    SchemaError::DoesNotExist { object_type, name }
+}
+
+/// Returns Ok for a statement specifying `IF EXISTS` when the object does not exist.
+fn if_exists_or(
+    if_exists: bool,
+    object_type: SchemaObjectType,
+    name: String,
+) -> Result<(), SchemaError> {
+    if if_exists {
+        Ok(())
+    } else {
+        Err(does_not_exist(object_type, name))
+    }
+}
+
+/// Returns Ok for a statement specifying `IF NOT EXISTS` when the object already exists.
+fn if_not_exists_or(
+    if_not_exists: bool,
+    object_type: SchemaObjectType,
+    name: String,
+) -> Result<(), SchemaError> {
+    if if_not_exists {
+        Ok(())
+    } else {
+        Err(already_exists(object_type, name))
+    }
+}
+
+/// Splits a primary key into its partition and clustering columns with each clustering column's
+/// order from `WITH CLUSTERING ORDER BY`, which defaults to ascending.
+fn primary_key_columns(
+    qualified_name: &str,
+    primary_key: &PrimaryKeyDefinition,
+    attributes: &[TableDefinitionAttribute],
+) -> Result<(Vec<String>, Vec<ClusteringColumnSchema>), SchemaError> {
+    let (partition_key, clustering) = match primary_key {
+        PrimaryKeyDefinition::Single(partition) => (vec![name(partition)], Vec::new()),
+        PrimaryKeyDefinition::Compound {
+            partition,
+            clustering,
+        } => (vec![name(partition)], clustering.iter().map(name).collect()),
+        PrimaryKeyDefinition::CompositePartition {
+            partition,
+            clustering,
+        } => (
+            partition.iter().map(name).collect(),
+            clustering.iter().map(name).collect(),
+        ),
+    };
+    clustering_ordered_key(qualified_name, partition_key, clustering, attributes)
+}
+
+/// Orders the clustering columns of a primary key, erroring on a clustering order of a column that
+/// is not a clustering column, including of a primary key declared inline with its column.
+fn clustering_ordered_key(
+    qualified_name: &str,
+    partition_key: Vec<String>,
+    clustering: Vec<String>,
+    attributes: &[TableDefinitionAttribute],
+) -> Result<(Vec<String>, Vec<ClusteringColumnSchema>), SchemaError> {
+    let clustering_orders: Vec<&ClusteringOrderDefinition> = attributes
+        .iter()
+        .filter_map(|attribute| match attribute {
+            TableDefinitionAttribute::ClusteringOrderBy(clustering_orders) => {
+                Some(clustering_orders)
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect();
+    for clustering_order in &clustering_orders {
+        let column_name = name(&clustering_order.column_name);
+        if !clustering.contains(&column_name) {
+            return Err(SchemaError::ClusteringOrderOnNonClusteringColumn {
+                name: format!("{qualified_name}.{column_name}"),
+            });
+        }
+    }
+    let clustering_key = clustering
+        .into_iter()
+        .map(|column_name| ClusteringColumnSchema {
+            order: clustering_orders
+                .iter()
+                .find(|clustering_order| name(&clustering_order.column_name) == column_name)
+                .and_then(|clustering_order| clustering_order.order)
+                .unwrap_or(ClusteringOrder::Asc),
+            name: column_name,
+        })
+        .collect();
+    Ok((partition_key, clustering_key))
+}
+
+fn check_primary_key_declared(
+    qualified_name: &str,
+    columns: &[ColumnSchema],
+    partition_key: &[String],
+    clustering_key: &[ClusteringColumnSchema],
+) -> Result<(), SchemaError> {
+    let primary_key_column_names = partition_key.iter().chain(
+        clustering_key
+            .iter()
+            .map(|clustering_column| &clustering_column.name),
+    );
+    for column_name in primary_key_column_names {
+        if !columns.iter().any(|column| column.name == *column_name) {
+            return Err(SchemaError::UndeclaredPrimaryKeyColumn {
+                name: format!("{qualified_name}.{column_name}"),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the user defined types referenced by a data type exist in the keyspace, which is
+/// the only keyspace a keyspace-qualified user defined type may refer to.
+fn check_user_defined_types(
+    keyspace_name: &str,
+    types: &BTreeMap<String, TypeSchema>,
+    data_type: &CqlDataType,
+) -> Result<(), SchemaError> {
+    let mut type_names = UserDefinedTypeNames(Vec::new());
+    type_names.visit_data_type(data_type);
+    match type_names
+        .0
+        .into_iter()
+        .find(|(type_keyspace_name, type_name)| {
+            type_keyspace_name
+                .as_ref()
+                .is_some_and(|type_keyspace_name| type_keyspace_name != keyspace_name)
+                || !types.contains_key(type_name)
+        }) {
+        None => Ok(()),
+        Some((type_keyspace_name, type_name)) => Err(does_not_exist(
+            SchemaObjectType::Type,
+            format!(
+                "{}.{type_name}",
+                type_keyspace_name.as_deref().unwrap_or(keyspace_name)
+            ),
+        )),
+    }
+}
+
+/// The cql of a data type without the keyspace qualifiers of user defined types, which may only
+/// refer to types of the keyspace of the statement and are not qualified in `system_schema`.
+fn data_type_cql(data_type: &CqlDataType) -> String {
+    UnqualifiedDataType(data_type).to_string()
+}
+
+struct UnqualifiedDataType<'a>(&'a CqlDataType);
+
+impl Display for UnqualifiedDataType<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            CqlDataType::CollectionType(CqlCollectionType::List(data_type)) => {
+                write!(f, "list<{}>", UnqualifiedDataType(data_type))
+            }
+            CqlDataType::CollectionType(CqlCollectionType::Map(key_type, value_type)) => write!(
+                f,
+                "map<{}, {}>",
+                UnqualifiedDataType(key_type),
+                UnqualifiedDataType(value_type)
+            ),
+            CqlDataType::CollectionType(CqlCollectionType::Set(data_type)) => {
+                write!(f, "set<{}>", UnqualifiedDataType(data_type))
+            }
+            CqlDataType::Frozen(data_type) => {
+                write!(f, "frozen<{}>", UnqualifiedDataType(data_type))
+            }
+            CqlDataType::Tuple(data_types) => {
+                f.write_str("tuple<")?;
+                for (i, data_type) in data_types.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    UnqualifiedDataType(data_type).fmt(f)?;
+                }
+                f.write_str(">")
+            }
+            CqlDataType::ValueType(CqlValueType::UserDefinedType(_, type_name)) => type_name.fmt(f),
+            CqlDataType::Vector(data_type, dimension) => {
+                write!(f, "vector<{}, {dimension}>", UnqualifiedDataType(data_type))
+            }
+            CqlDataType::CustomType(_) | CqlDataType::ValueType(CqlValueType::NativeType(_)) => {
+                self.0.fmt(f)
+            }
+        }
+    }
+}
+
+/// Collects the keyspace qualifiers and names of the user defined types referenced by a data
+/// type.
+struct UserDefinedTypeNames(Vec<(Option<String>, String)>);
+
+impl Visit for UserDefinedTypeNames {
+    fn visit_data_type(&mut self, node: &CqlDataType) {
+        if let CqlDataType::ValueType(CqlValueType::UserDefinedType(keyspace_name, type_name)) =
+            node
+        {
+            self.0
+                .push((keyspace_name.as_ref().map(name), name(type_name)));
+        }
+        walk_data_type(self, node);
+    }
+}
+
+/// Sets the table options of `WITH` attributes other than clustering order and compact storage.
+fn apply_table_options(
+    options: &mut BTreeMap<String, String>,
+    attributes: &[TableDefinitionAttribute],
+) {
+    for attribute in attributes {
+        let (option_name, value) = match attribute {
+            TableDefinitionAttribute::ClusteringOrderBy(_)
+            | TableDefinitionAttribute::CompactStorage => continue,
+            TableDefinitionAttribute::Comment(comment) => {
+                ("comment".to_string(), comment.to_string())
+            }
+            TableDefinitionAttribute::Compaction(_) => (
+                "compaction".to_string(),
+                attribute
+                    .to_string()
+                    .trim_start_matches("compaction = ")
+                    .to_string(),
+            ),
+            TableDefinitionAttribute::Id(id) => ("id".to_string(), format!("'{id}'")),
+            TableDefinitionAttribute::Option(option_name, value) => {
+                (name(option_name), value.to_string())
+            }
+        };
+        options.insert(option_name, value);
+    }
+}
+
+fn apply_role_attributes(role: &mut RoleSchema, attributes: &[RoleConfigAttribute]) {
+    for attribute in attributes {
+        match attribute {
+            RoleConfigAttribute::Login(login) => role.login = *login,
+            RoleConfigAttribute::Superuser(superuser) => role.superuser = *superuser,
+            RoleConfigAttribute::Access(_)
+            | RoleConfigAttribute::Options(_)
+            | RoleConfigAttribute::Password(_) => {}
+        }
+    }
+}
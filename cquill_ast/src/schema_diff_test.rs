@@ -0,0 +1,177 @@
+use crate::schema::*;
+use crate::test_cql::*;
+
+fn catalog_using_keyspace(cql: &[&str]) -> SchemaCatalog {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION)
+        .unwrap();
+    catalog.apply_cql(USE_KEYSPACE).unwrap();
+    for cql in cql {
+        catalog.apply_cql(cql).unwrap();
+    }
+    catalog
+}
+
+fn diff_strings(from: &SchemaCatalog, to: &SchemaCatalog) -> Vec<String> {
+    from.diff(to).iter().map(|diff| diff.to_string()).collect()
+}
+
+#[test]
+fn test_diff_equal_catalogs() {
+    let catalog =
+        catalog_using_keyspace(&["create table big_data_table (id uuid primary key, name text);"]);
+    assert!(catalog.diff(&catalog.clone()).is_empty());
+}
+
+#[test]
+fn test_diff_added_and_removed_keyspaces() {
+    let from = catalog_using_keyspace(&[]);
+    let to = SchemaCatalog::new();
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec!["- keyspace big_data_keyspace"]
+    );
+    assert_eq!(
+        diff_strings(&to, &from),
+        vec!["+ keyspace big_data_keyspace"]
+    );
+}
+
+#[test]
+fn test_diff_changed_keyspace_replication() {
+    let from = catalog_using_keyspace(&[]);
+    let mut to = SchemaCatalog::new();
+    to.apply_cql("create keyspace big_data_keyspace with replication = {'class': 'SimpleStrategy', 'replication_factor': 3};")
+        .unwrap();
+    let diffs = from.diff(&to);
+    assert_eq!(diffs.len(), 1);
+    assert_eq!(diffs[0].object_type(), SchemaObjectType::Keyspace);
+    assert_eq!(diffs[0].name(), &SchemaObjectName::new("big_data_keyspace"));
+}
+
+#[test]
+fn test_diff_added_table_does_not_list_columns() {
+    let from = catalog_using_keyspace(&[]);
+    let to =
+        catalog_using_keyspace(&["create table big_data_table (id uuid primary key, name text);"]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec!["+ table big_data_keyspace.big_data_table"]
+    );
+}
+
+#[test]
+fn test_diff_columns() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, name varchar, age int, email text);",
+    ]);
+    let to = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, name text, age bigint, address text);",
+    ]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec![
+            "~ column big_data_keyspace.big_data_table.age: int -> bigint",
+            "- column big_data_keyspace.big_data_table.email",
+            "+ column big_data_keyspace.big_data_table.address",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_collection_columns_normalize_varchar() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, tags map<varchar, frozen<list<varchar>>>);",
+    ]);
+    let to = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, tags map<text, frozen<list<text>>>);",
+    ]);
+    assert!(from.diff(&to).is_empty());
+}
+
+#[test]
+fn test_diff_primary_key() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid, created timestamp, primary key (id, created));",
+    ]);
+    let to = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid, created timestamp, primary key (id, created)) with clustering order by (created desc);",
+    ]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec![
+            "~ table big_data_keyspace.big_data_table: PRIMARY KEY (id, created) -> PRIMARY KEY (id, created) WITH CLUSTERING ORDER BY (created DESC)"
+        ]
+    );
+}
+
+#[test]
+fn test_diff_table_options() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key) with comment = 'big data!';",
+    ]);
+    let to = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key) with comment = 'bigger data!';",
+    ]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec![
+            "~ table option big_data_keyspace.big_data_table.comment: 'big data!' -> 'bigger data!'"
+        ]
+    );
+}
+
+#[test]
+fn test_diff_indexes() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, name text, email text);",
+        "create index name_index on big_data_table (name);",
+    ]);
+    let to = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, name text, email text);",
+        "create index email_index on big_data_table (email);",
+    ]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec![
+            "- index big_data_keyspace.name_index",
+            "+ index big_data_keyspace.email_index",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_type_fields() {
+    let from = catalog_using_keyspace(&["create type address (street text, zip int);"]);
+    let to = catalog_using_keyspace(&["create type address (street text, zip text, city text);"]);
+    assert_eq!(
+        diff_strings(&from, &to),
+        vec![
+            "~ field big_data_keyspace.address.zip: int -> text",
+            "+ field big_data_keyspace.address.city",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_names_are_structured() {
+    let from = catalog_using_keyspace(&["create table \"big.data\" (id uuid primary key);"]);
+    let to = catalog_using_keyspace(&[
+        "create table \"big.data\" (id uuid primary key, \"Name\" text);",
+    ]);
+    let diffs = from.diff(&to);
+    assert_eq!(
+        diffs,
+        vec![SchemaDiff::Added {
+            object_type: SchemaObjectType::Column,
+            name: SchemaObjectName::new("big_data_keyspace")
+                .child("big.data")
+                .child("Name"),
+        }]
+    );
+    assert_eq!(
+        diffs[0].to_string(),
+        "+ column big_data_keyspace.\"big.data\".\"Name\""
+    );
+}
@@ -0,0 +1,174 @@
+use crate::schema::*;
+use crate::test_cql::*;
+
+fn catalog_using_keyspace(cql: &[&str]) -> SchemaCatalog {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION)
+        .unwrap();
+    catalog.apply_cql(USE_KEYSPACE).unwrap();
+    for cql in cql {
+        catalog.apply_cql(cql).unwrap();
+    }
+    catalog
+}
+
+/// Asserts the migration's rendered statements and that applying them arrives at `desired`.
+fn assert_migration(from: &SchemaCatalog, desired: &SchemaCatalog, expected: &[&str]) {
+    let statements = from.migration_to(desired).unwrap();
+    assert_eq!(
+        statements
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<String>>(),
+        expected
+    );
+    let mut migrated = from.clone();
+    for statement in &statements {
+        migrated.apply(statement).unwrap();
+    }
+    assert!(migrated.diff(desired).is_empty());
+}
+
+#[test]
+fn test_migration_to_equal_catalog() {
+    let catalog =
+        catalog_using_keyspace(&["create table big_data_table (id uuid primary key, name text);"]);
+    assert!(catalog.migration_to(&catalog.clone()).unwrap().is_empty());
+}
+
+#[test]
+fn test_migration_creates_keyspace_with_its_objects() {
+    let desired = catalog_using_keyspace(&[
+        "create type big_data_udt (street text);",
+        "create table big_data_table (id uuid, created timestamp, address frozen<big_data_udt>, primary key (id, created)) with clustering order by (created desc) and comment = 'big data!';",
+        "create index created_index on big_data_table (address);",
+    ]);
+    assert_migration(
+        &SchemaCatalog::new(),
+        &desired,
+        &[
+            "CREATE KEYSPACE big_data_keyspace WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1}",
+            "CREATE TYPE big_data_keyspace.big_data_udt (\n    street text\n)",
+            "CREATE TABLE big_data_keyspace.big_data_table (\n    id uuid,\n    created timestamp,\n    address frozen<big_data_udt>,\n    PRIMARY KEY (id, created)\n) WITH CLUSTERING ORDER BY (created DESC)\n    AND comment = 'big data!'",
+            "CREATE INDEX created_index ON big_data_keyspace.big_data_table (address)",
+        ],
+    );
+}
+
+#[test]
+fn test_migration_alters_tables_and_types() {
+    let from = catalog_using_keyspace(&[
+        "create type big_data_udt (street text);",
+        "create table big_data_table (id uuid primary key, name text, email text);",
+    ]);
+    let desired = catalog_using_keyspace(&[
+        "create type big_data_udt (street text, zip int);",
+        "create table big_data_table (id uuid primary key, name text, phone text) with default_time_to_live = 60;",
+    ]);
+    assert_migration(
+        &from,
+        &desired,
+        &[
+            "ALTER TABLE big_data_keyspace.big_data_table DROP email",
+            "ALTER TYPE big_data_keyspace.big_data_udt ADD zip int",
+            "ALTER TABLE big_data_keyspace.big_data_table ADD phone text",
+            "ALTER TABLE big_data_keyspace.big_data_table WITH default_time_to_live = 60",
+        ],
+    );
+}
+
+#[test]
+fn test_migration_drops_indexes_before_tables() {
+    let from = catalog_using_keyspace(&[
+        "create table big_data_table (id uuid primary key, name text);",
+        "create index name_index on big_data_table (name);",
+    ]);
+    let desired = catalog_using_keyspace(&[]);
+    assert_migration(
+        &from,
+        &desired,
+        &[
+            "DROP INDEX big_data_keyspace.name_index",
+            "DROP TABLE big_data_keyspace.big_data_table",
+        ],
+    );
+}
+
+#[test]
+fn test_migration_unsupported_change() {
+    let from =
+        catalog_using_keyspace(&["create table big_data_table (id uuid primary key, age int);"]);
+    let desired =
+        catalog_using_keyspace(&["create table big_data_table (id uuid primary key, age bigint);"]);
+    match from.migration_to(&desired) {
+        Err(err @ SchemaError::UnsupportedChange(_)) => assert_eq!(
+            err.to_string(),
+            "cql cannot make schema change ~ column big_data_keyspace.big_data_table.age: int -> bigint"
+        ),
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_migration_creates_views_and_replaces_functions() {
+    let table = "create table big_data_table (id uuid primary key, name text);";
+    let from = catalog_using_keyspace(&[
+        table,
+        "create function big_data_function (a int) called on null input returns int language java as 'return a;';",
+    ]);
+    let desired = catalog_using_keyspace(&[
+        table,
+        "create function big_data_function (a int) returns null on null input returns int language java as 'return a;';",
+        "create materialized view big_data_view as select id, name from big_data_table where name is not null and id is not null primary key (name, id);",
+    ]);
+    let statements = from.migration_to(&desired).unwrap();
+    let mut migrated = from.clone();
+    for statement in &statements {
+        migrated.apply(statement).unwrap();
+    }
+    assert_eq!(
+        statements
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<String>>()[1..],
+        [
+            "USE big_data_keyspace",
+            "CREATE OR REPLACE FUNCTION big_data_function(a int)\n    RETURNS NULL ON NULL INPUT\n    RETURNS int\n    LANGUAGE java\n    AS 'return a;'",
+        ]
+    );
+    assert!(migrated.diff(&desired).is_empty());
+}
+
+#[test]
+fn test_migration_drops_function_overload() {
+    let text_function = "create function big_data_function (a text) called on null input returns text language java as 'return a;';";
+    let from = catalog_using_keyspace(&[
+        "create function big_data_function (a int) called on null input returns int language java as 'return a;';",
+        text_function,
+    ]);
+    let desired = catalog_using_keyspace(&[text_function]);
+    assert_migration(
+        &from,
+        &desired,
+        &["DROP FUNCTION big_data_keyspace.big_data_function(int)"],
+    );
+}
+
+#[test]
+fn test_migration_quotes_names_containing_dots() {
+    let from = catalog_using_keyspace(&[
+        "create table \"big.data\" (id uuid primary key, \"Name\" text);",
+    ]);
+    let desired = catalog_using_keyspace(&[
+        "create table \"big.data\" (id uuid primary key, \"email.address\" text);",
+    ]);
+    assert_migration(
+        &from,
+        &desired,
+        &[
+            "ALTER TABLE big_data_keyspace.\"big.data\" DROP \"Name\"",
+            "ALTER TABLE big_data_keyspace.\"big.data\" ADD \"email.address\" text",
+        ],
+    );
+}
@@ -0,0 +1,752 @@
+use crate::ast::ClusteringOrder;
+use crate::schema::*;
+use crate::test_cql::*;
+use std::collections::BTreeMap;
+
+fn catalog_using_keyspace(cql: &[&str]) -> Result<SchemaCatalog, SchemaError> {
+    let mut catalog = SchemaCatalog::new();
+    catalog.apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION)?;
+    catalog.apply_cql(USE_KEYSPACE)?;
+    for cql in cql {
+        catalog.apply_cql(cql)?;
+    }
+    Ok(catalog)
+}
+
+fn signature(name: &str, argument_types: &[&str]) -> FunctionSignature {
+    FunctionSignature {
+        name: name.to_string(),
+        argument_types: argument_types
+            .iter()
+            .map(|data_type| data_type.to_string())
+            .collect(),
+    }
+}
+
+fn column(name: &str, data_type: &str) -> ColumnSchema {
+    ColumnSchema {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+        is_static: false,
+    }
+}
+
+#[test]
+fn test_create_keyspace() {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_KEYSPACE_WITH_DURABLE_WRITES_FALSE)
+        .unwrap();
+    let keyspace = catalog.keyspace("big_data_keyspace").unwrap();
+    assert!(!keyspace.durable_writes);
+    assert!(keyspace.tables.is_empty());
+}
+
+#[test]
+fn test_create_keyspace_already_exists() {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION)
+        .unwrap();
+    catalog.apply_cql(CREATE_KEYSPACE_IF_NOT_EXISTS).unwrap();
+    match catalog.apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION) {
+        Err(SchemaError::AlreadyExists { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Keyspace);
+            assert_eq!(name, "big_data_keyspace");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_alter_and_drop_keyspace() {
+    let mut catalog = catalog_using_keyspace(&[ALTER_KEYSPACE_WITH_DURABLE_WRITES]).unwrap();
+    assert!(
+        !catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .durable_writes
+    );
+    catalog.apply_cql(DROP_KEYSPACE).unwrap();
+    assert!(catalog.keyspaces.is_empty());
+    catalog.apply_cql(DROP_KEYSPACE_IF_EXISTS).unwrap();
+    assert!(matches!(
+        catalog.apply_cql(DROP_KEYSPACE),
+        Err(SchemaError::DoesNotExist { .. })
+    ));
+}
+
+#[test]
+fn test_use_keyspace_that_does_not_exist() {
+    let mut catalog = SchemaCatalog::new();
+    assert!(matches!(
+        catalog.apply_cql(USE_KEYSPACE),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Keyspace,
+            ..
+        })
+    ));
+    assert!(catalog.current_keyspace().is_none());
+}
+
+#[test]
+fn test_create_table_without_keyspace() {
+    let mut catalog = SchemaCatalog::new();
+    assert!(matches!(
+        catalog.apply_cql(CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY),
+        Err(SchemaError::NoKeyspace { .. })
+    ));
+}
+
+#[test]
+fn test_create_table_with_explicit_keyspace() {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_KEYSPACE_WITH_SIMPLE_REPLICATION)
+        .unwrap();
+    catalog
+        .apply_cql(CREATE_TABLE_WITH_EXPLICIT_KEYSPACE)
+        .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(table.columns, vec![column("uuid_column", "uuid")]);
+    assert_eq!(table.partition_key, vec!["uuid_column".to_string()]);
+    assert!(table.clustering_key.is_empty());
+}
+
+#[test]
+fn test_create_table_with_composite_partition_primary_key() {
+    let catalog =
+        catalog_using_keyspace(&[CREATE_TABLE_WITH_COMPOSITE_PARTITION_PRIMARY_KEY]).unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.partition_key,
+        vec!["text_column".to_string(), "uuid_column".to_string()]
+    );
+    assert_eq!(
+        table.clustering_key,
+        vec![ClusteringColumnSchema {
+            name: "timestamp_column".to_string(),
+            order: ClusteringOrder::Asc,
+        }]
+    );
+}
+
+#[test]
+fn test_create_table_with_clustering_orders() {
+    let catalog = catalog_using_keyspace(&[CREATE_TABLE_WITH_MULTIPLE_CLUSTERING_ORDERS]).unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.clustering_key,
+        vec![
+            ClusteringColumnSchema {
+                name: "time_column".to_string(),
+                order: ClusteringOrder::Desc,
+            },
+            ClusteringColumnSchema {
+                name: "uuid_column".to_string(),
+                order: ClusteringOrder::Asc,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_create_table_with_options() {
+    let catalog = catalog_using_keyspace(&[CREATE_TABLE_WITH_TABLE_OPTIONS]).unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.options,
+        BTreeMap::from([
+            ("caching".to_string(), "{'keys': 'ALL'}".to_string()),
+            ("default_time_to_live".to_string(), "86400".to_string()),
+        ])
+    );
+}
+
+#[test]
+fn test_create_table_with_compact_storage() {
+    let catalog = catalog_using_keyspace(&[CREATE_TABLE_WITH_COMMENT_AND_COMPACT_STORAGE]).unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert!(table.compact_storage);
+    assert_eq!(table.options["comment"], "'big data!'");
+}
+
+#[test]
+fn test_create_table_already_exists() {
+    let result = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_TABLE_IF_NOT_EXISTS,
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+    ]);
+    match result {
+        Err(SchemaError::AlreadyExists { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Table);
+            assert_eq!(name, "big_data_keyspace.big_data_table");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_create_table_with_duplicate_column() {
+    match catalog_using_keyspace(&[
+        "create table big_data_table (text_column text primary key, text_column int);",
+    ]) {
+        Err(SchemaError::AlreadyExists { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Column);
+            assert_eq!(name, "big_data_keyspace.big_data_table.text_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_create_table_with_undeclared_primary_key_column() {
+    match catalog_using_keyspace(&[BAD_CREATE_TABLE_WITH_UNDECLARED_PRIMARY_KEY_COLUMN]) {
+        Err(SchemaError::UndeclaredPrimaryKeyColumn { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_table.time_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_create_table_with_clustering_order_on_non_clustering_column() {
+    match catalog_using_keyspace(&[BAD_CREATE_TABLE_WITH_CLUSTERING_ORDER_ON_NON_CLUSTERING_KEY]) {
+        Err(SchemaError::ClusteringOrderOnNonClusteringColumn { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_table.uuid_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_create_table_with_clustering_order_on_inline_primary_key() {
+    match catalog_using_keyspace(&[BAD_CREATE_TABLE_WITH_CLUSTERING_ORDER_ON_INLINE_PRIMARY_KEY]) {
+        Err(SchemaError::ClusteringOrderOnNonClusteringColumn { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_table.uuid_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_create_table_with_counter_columns() {
+    catalog_using_keyspace(&[CREATE_TABLE_WITH_COUNTER_DATA_TYPE]).unwrap();
+    match catalog_using_keyspace(&[BAD_CREATE_TABLE_WITH_COUNTER_AND_NON_COUNTER_COLUMNS]) {
+        Err(SchemaError::CounterColumnMix { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_table");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_alter_table_add_non_counter_column_to_counter_table() {
+    assert!(matches!(
+        catalog_using_keyspace(&[
+            CREATE_TABLE_WITH_COUNTER_DATA_TYPE,
+            "alter table big_data_table add uuid_column uuid;",
+        ]),
+        Err(SchemaError::CounterColumnMix { .. })
+    ));
+}
+
+#[test]
+fn test_create_table_with_unknown_udt() {
+    match catalog_using_keyspace(&[CREATE_TABLE_WITH_NESTED_DATA_TYPES]) {
+        Err(SchemaError::DoesNotExist { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Type);
+            assert_eq!(name, "big_data_keyspace.big_data_udt");
+        }
+        result => panic!("{result:?}"),
+    }
+    let catalog = catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES,
+        CREATE_TABLE_WITH_NESTED_DATA_TYPES,
+    ])
+    .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.column("list_column").unwrap().data_type,
+        "list<frozen<big_data_udt>>"
+    );
+}
+
+#[test]
+fn test_create_table_with_explicit_keyspace_udt() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES,
+        CREATE_TABLE_WITH_EXPLICIT_KEYSPACE_UDT_COLLECTION,
+    ])
+    .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.column("list_column").unwrap().data_type,
+        "list<frozen<big_data_udt>>"
+    );
+    match catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES,
+        "create table big_data_table (uuid_column uuid primary key, udt_column frozen<other_keyspace.big_data_udt>);",
+    ]) {
+        Err(SchemaError::DoesNotExist { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Type);
+            assert_eq!(name, "other_keyspace.big_data_udt");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_alter_table_add_column_with_unknown_udt() {
+    assert!(matches!(
+        catalog_using_keyspace(&[
+            CREATE_TABLE_IF_NOT_EXISTS,
+            "alter table big_data_table add udt_column frozen<big_data_udt>;",
+        ]),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Type,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_create_udt_with_unknown_udt_field() {
+    assert!(matches!(
+        catalog_using_keyspace(&["create type big_data_udt (udt_attr frozen<other_udt>);"]),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Type,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_alter_table_add_columns() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_IF_NOT_EXISTS,
+        ALTER_TABLE_ADD_COLUMN,
+        ALTER_TABLE_ADD_COLUMN_IF_NOT_EXISTS,
+    ])
+    .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.columns,
+        vec![column("uuid_column", "uuid"), column("text_column", "text")]
+    );
+}
+
+#[test]
+fn test_alter_table_add_column_already_exists() {
+    let result = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        ALTER_TABLE_ADD_COLUMN,
+    ]);
+    assert!(matches!(
+        result,
+        Err(SchemaError::AlreadyExists {
+            object_type: SchemaObjectType::Column,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_alter_table_that_does_not_exist() {
+    catalog_using_keyspace(&[ALTER_TABLE_IF_EXISTS]).unwrap();
+    match catalog_using_keyspace(&[ALTER_TABLE_ADD_COLUMN]) {
+        Err(SchemaError::DoesNotExist { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Table);
+            assert_eq!(name, "big_data_keyspace.big_data_table");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_alter_table_drop_columns() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_IF_NOT_EXISTS,
+        ALTER_TABLE_ADD_COLUMN,
+        ALTER_TABLE_DROP_COLUMN,
+        ALTER_TABLE_DROP_COLUMN_IF_EXISTS,
+    ])
+    .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(table.columns, vec![column("uuid_column", "uuid")]);
+}
+
+#[test]
+fn test_alter_table_drop_primary_key_column() {
+    let result = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        ALTER_TABLE_DROP_COLUMN,
+    ]);
+    match result {
+        Err(SchemaError::DropPrimaryKeyColumn { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_table.text_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_alter_table_rename_columns() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        ALTER_TABLE_RENAME_MULTIPLE_COLUMNS,
+    ])
+    .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(
+        table.columns,
+        vec![column("text_col", "text"), column("uuid_col", "uuid")]
+    );
+    assert_eq!(table.partition_key, vec!["text_col".to_string()]);
+    assert_eq!(table.clustering_key[0].name, "uuid_col");
+}
+
+#[test]
+fn test_alter_table_with_comment() {
+    let catalog =
+        catalog_using_keyspace(&[CREATE_TABLE_WITH_TABLE_OPTIONS, ALTER_TABLE_WITH_COMMENT])
+            .unwrap();
+    let table = catalog
+        .table("big_data_keyspace", "big_data_table")
+        .unwrap();
+    assert_eq!(table.options["comment"], "'big data!'");
+    assert_eq!(table.options["default_time_to_live"], "86400");
+}
+
+#[test]
+fn test_drop_table_drops_indexes() {
+    let mut catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_INDEX,
+        CREATE_INDEX_WITHOUT_NAME,
+    ])
+    .unwrap();
+    let keyspace = catalog.keyspace("big_data_keyspace").unwrap();
+    assert_eq!(
+        keyspace.indexes.keys().collect::<Vec<_>>(),
+        vec!["big_data_index", "big_data_table_text_column_idx"]
+    );
+    catalog.apply_cql(DROP_TABLE_DEFAULT_KEYSPACE).unwrap();
+    let keyspace = catalog.keyspace("big_data_keyspace").unwrap();
+    assert!(keyspace.tables.is_empty());
+    assert!(keyspace.indexes.is_empty());
+}
+
+#[test]
+fn test_create_index_on_table_that_does_not_exist() {
+    assert!(matches!(
+        catalog_using_keyspace(&[CREATE_INDEX]),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Table,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_drop_index() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_INDEX,
+        DROP_INDEX_EXPLICIT_KEYSPACE,
+        DROP_INDEX_DEFAULT_KEYSPACE_IF_EXISTS,
+    ])
+    .unwrap();
+    assert!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .indexes
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_create_materialized_view() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_MATERIALIZED_VIEW_WITH_COMPOUND_PRIMARY_KEY,
+        ALTER_MATERIALIZED_VIEW,
+    ])
+    .unwrap();
+    let view = &catalog.keyspace("big_data_keyspace").unwrap().views["big_data_view"];
+    assert_eq!(view.base_table_name, "big_data_table");
+    assert_eq!(
+        view.columns,
+        vec![column("text_column", "text"), column("uuid_column", "uuid")]
+    );
+    assert_eq!(
+        view.where_clause,
+        "text_column IS NOT null AND uuid_column IS NOT null"
+    );
+    assert_eq!(view.partition_key, vec!["text_column".to_string()]);
+    assert_eq!(view.options["comment"], "'quoi de neuf'");
+}
+
+#[test]
+fn test_create_materialized_view_on_table_that_does_not_exist() {
+    assert!(matches!(
+        catalog_using_keyspace(&[CREATE_MATERIALIZED_VIEW]),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Table,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_drop_materialized_view() {
+    let catalog = catalog_using_keyspace(&[
+        "create table big_data_table (int_column int primary key);",
+        CREATE_MATERIALIZED_VIEW_IF_NOT_EXISTS,
+        CREATE_MATERIALIZED_VIEW_IF_NOT_EXISTS,
+        DROP_MATERIALIZED_VIEW_DEFAULT_KEYSPACE,
+    ])
+    .unwrap();
+    assert!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .views
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_create_and_alter_udt() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES,
+        ALTER_UDT_ADD_FIELD,
+        ALTER_UDT_ADD_FIELD_IF_NOT_EXISTS,
+        ALTER_UDT_RENAME_FIELD,
+    ])
+    .unwrap();
+    let udt = &catalog.keyspace("big_data_keyspace").unwrap().types["big_data_udt"];
+    assert_eq!(
+        udt.fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.data_type.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("int_attr", "int"),
+            ("text_attr", "text"),
+            ("modest_data_text_column", "text"),
+        ]
+    );
+}
+
+#[test]
+fn test_alter_udt_rename_field_that_does_not_exist() {
+    catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_SINGLE_ATTRIBUTE,
+        ALTER_UDT_RENAME_FIELD_IF_EXISTS,
+    ])
+    .unwrap();
+    match catalog_using_keyspace(&[
+        CREATE_DEFAULT_KEYSPACE_UDT_WITH_SINGLE_ATTRIBUTE,
+        ALTER_UDT_RENAME_FIELD,
+    ]) {
+        Err(SchemaError::DoesNotExist { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Field);
+            assert_eq!(name, "big_data_keyspace.big_data_udt.big_data_text_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
+
+#[test]
+fn test_drop_udt() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_EXPLICIT_KEYSPACE_UDT_WITH_SINGLE_ATTRIBUTE,
+        DROP_UDT_EXPLICIT_KEYSPACE,
+        DROP_UDT_DEFAULT_KEYSPACE_IF_EXISTS,
+    ])
+    .unwrap();
+    assert!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .types
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_create_function_and_aggregate() {
+    let catalog = catalog_using_keyspace(&[
+        CREATE_FUNCTION_CALLED_ON_NULL_AS_DOLLAR_DOLLAR,
+        CREATE_AGGREGATE_WITH_FINALFUNC_AND_INITCOND,
+    ])
+    .unwrap();
+    let keyspace = catalog.keyspace("big_data_keyspace").unwrap();
+    let function = &keyspace.functions[&signature("big_data_fn", &["int"])];
+    assert!(function.called_on_null_input);
+    assert_eq!(function.returns, "text");
+    assert_eq!(function.language, "java");
+    assert_eq!(function.arguments[0].data_type, "int");
+    let aggregate = &keyspace.aggregates[&signature("big_data_agg", &["int"])];
+    assert_eq!(aggregate.state_function, "fn_name");
+    assert_eq!(aggregate.state_type, "list<text>");
+    assert_eq!(aggregate.final_function.as_deref(), Some("ffn_name"));
+    assert_eq!(aggregate.init_condition.as_deref(), Some("'state value'"));
+}
+
+#[test]
+fn test_create_and_drop_function_overloads() {
+    let mut catalog = catalog_using_keyspace(&[
+        CREATE_FUNCTION_CALLED_ON_NULL_AS_DOLLAR_DOLLAR,
+        "create function big_data_fn(fn_arg text) called on null input returns text language java as 'return fn_arg;';",
+    ])
+    .unwrap();
+    assert_eq!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .functions
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![
+            &signature("big_data_fn", &["int"]),
+            &signature("big_data_fn", &["text"])
+        ]
+    );
+    match catalog.apply_cql(DROP_FUNCTION_WITHOUT_ARGS) {
+        Err(SchemaError::AmbiguousOverload { object_type, name }) => {
+            assert_eq!(object_type, SchemaObjectType::Function);
+            assert_eq!(name, "big_data_keyspace.big_data_fn");
+        }
+        result => panic!("{result:?}"),
+    }
+    assert!(matches!(
+        catalog.apply_cql(DROP_FUNCTION_WITH_MULTIPLE_ARGS),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Function,
+            ..
+        })
+    ));
+    catalog.apply_cql(DROP_FUNCTION_WITH_SINGLE_ARG).unwrap();
+    assert_eq!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .functions
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![&signature("big_data_fn", &["text"])]
+    );
+    catalog.apply_cql(DROP_FUNCTION_WITHOUT_ARGS).unwrap();
+    assert!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .functions
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_quoted_names_are_case_sensitive() {
+    let mut catalog = catalog_using_keyspace(&[
+        "create table \"BigData\" (\"Id\" uuid primary key, text_column text);",
+        "create function \"BigDataFn\"(fn_arg int) called on null input returns int language java as 'return fn_arg;';",
+    ])
+    .unwrap();
+    assert!(catalog.table("big_data_keyspace", "bigdata").is_none());
+    let table = catalog.table("big_data_keyspace", "\"BigData\"").unwrap();
+    assert_eq!(table.partition_key, vec!["Id"]);
+    assert!(
+        catalog
+            .keyspace("big_data_keyspace")
+            .unwrap()
+            .functions
+            .contains_key(&signature("BigDataFn", &["int"]))
+    );
+    assert!(matches!(
+        catalog.apply_cql("drop table big_data_keyspace.BigData;"),
+        Err(SchemaError::DoesNotExist {
+            object_type: SchemaObjectType::Table,
+            ..
+        })
+    ));
+    catalog
+        .apply_cql("drop table big_data_keyspace.\"BigData\";")
+        .unwrap();
+}
+
+#[test]
+fn test_create_alter_and_drop_roles() {
+    let mut catalog = SchemaCatalog::new();
+    catalog
+        .apply_cql(CREATE_USER_WITH_HASHED_PASSWORD_SUPERUSER)
+        .unwrap();
+    catalog.apply_cql(CREATE_ROLE).unwrap();
+    assert_eq!(
+        catalog.roles.keys().collect::<Vec<_>>(),
+        vec!["big_data_role", "big_data_user"]
+    );
+    assert_eq!(
+        catalog.roles["big_data_user"],
+        RoleSchema {
+            login: true,
+            superuser: true,
+        }
+    );
+    catalog
+        .apply_cql(ALTER_ROLE_WITH_MULTIPLE_ROLE_OPTIONS)
+        .unwrap();
+    catalog.apply_cql(DROP_ROLE).unwrap();
+    assert!(!catalog.roles.contains_key("big_data_role"));
+}
+
+#[test]
+fn test_dml_does_not_change_schema() {
+    let before = catalog_using_keyspace(&[CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY]).unwrap();
+    let after = catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        SELECT_WHERE_EQ,
+        INSERT_COLLECTION_LITERALS,
+        TRUNCATE_TABLE,
+        DESCRIBE_TABLE,
+    ])
+    .unwrap();
+    assert_eq!(before, after);
+}
+
+#[test]
+fn test_create_materialized_view_with_undeclared_primary_key_column() {
+    match catalog_using_keyspace(&[
+        CREATE_TABLE_WITH_COMPOUND_PRIMARY_KEY,
+        CREATE_MATERIALIZED_VIEW,
+    ]) {
+        Err(SchemaError::UndeclaredPrimaryKeyColumn { name }) => {
+            assert_eq!(name, "big_data_keyspace.big_data_view.int_column");
+        }
+        result => panic!("{result:?}"),
+    }
+}
@@ -26,6 +26,13 @@ pub const USE_KEYSPACE: &str = "\
 use big_data_keyspace;
 ";
 
+pub const USE_KEYSPACE_THEN_CREATE_TABLE: &str = "\
+create table big_data_table (uuid_column uuid primary key);
+use big_data_keyspace;
+create table big_data_table (uuid_column uuid primary key);
+create table other_keyspace.big_data_table (uuid_column uuid primary key);
+";
+
 // https://cassandra.apache.org/doc/stable/cassandra/cql/ddl.html#alter-keyspace-statement
 
 pub const ALTER_KEYSPACE_WITH_DURABLE_WRITES: &str = "\
@@ -86,6 +93,28 @@ create table big_data_table
 );
 ";
 
+pub const BAD_CREATE_TABLE_WITH_COUNTER_AND_NON_COUNTER_COLUMNS: &str = "\
+create table big_data_table
+(
+    text_column text primary key,
+    counter_column counter,
+    int_column int
+);
+";
+
+pub const CREATE_TABLE_WITH_NESTED_DATA_TYPES: &str = "\
+create table big_data_table
+(
+    text_column   text primary key,
+    list_column   list<frozen<big_data_udt>>,
+    set_column    set<int>,
+    map_column    map<text, frozen<list<tuple<int, text, uuid>>>>,
+    tuple_column  tuple<int, text, uuid>,
+    vector_column vector<float, 768>,
+    custom_column 'org.example.BigDataType'
+);
+";
+
 pub const CREATE_TABLE_IF_NOT_EXISTS: &str = "\
 create table if not exists big_data_table (uuid_column uuid primary key);
 ";
@@ -111,6 +140,14 @@ create table big_data_table (
 );
 ";
 
+pub const BAD_CREATE_TABLE_WITH_UNDECLARED_PRIMARY_KEY_COLUMN: &str = "\
+create table big_data_table (
+    text_column text,
+    uuid_column uuid,
+    primary key (text_column, time_column)
+);
+";
+
 pub const CREATE_TABLE_WITH_COMPOSITE_PARTITION_PRIMARY_KEY_MISSING_CLUSTERING_KEYS: &str = "\
 create table big_data_table (
     text_column text,
@@ -126,6 +163,12 @@ create table big_data_table (
 ) with comment = 'big data!';
 ";
 
+pub const CREATE_TABLE_WITH_COMMENT_AND_COMPACT_STORAGE: &str = "\
+create table big_data_table (
+    uuid_column uuid primary key
+) with comment = 'big data!' and compact storage;
+";
+
 pub const CREATE_TABLE_WITH_COMPACT_STORAGE: &str = "\
 create table big_data_table (
     uuid_column uuid primary key
@@ -138,6 +181,12 @@ create table big_data_table (
 ) with compaction = {'class': 'LeveledCompactionStrategy'};
 ";
 
+pub const CREATE_TABLE_WITH_TABLE_OPTIONS: &str = "\
+create table big_data_table (
+    uuid_column uuid primary key
+) with default_time_to_live = 86400 and caching = {'keys': 'ALL'};
+";
+
 pub const CREATE_TABLE_WITH_ASC_CLUSTERING_ORDER: &str = "\
 create table big_data_table (
     text_column text,
@@ -165,7 +214,13 @@ create table big_data_table (
 )  with clustering order by (time_column desc, uuid_column asc);
 ";
 
-// todo parser test clustering order by uuid_column errors because it is not a clustering key
+pub const BAD_CREATE_TABLE_WITH_CLUSTERING_ORDER_ON_INLINE_PRIMARY_KEY: &str = "\
+create table big_data_table (
+    uuid_column uuid primary key,
+    text_column text
+) with clustering order by (uuid_column asc);
+";
+
 pub const BAD_CREATE_TABLE_WITH_CLUSTERING_ORDER_ON_NON_CLUSTERING_KEY: &str = "\
 create table big_data_table (
     text_column text,
@@ -189,6 +244,10 @@ pub const ALTER_TABLE_ADD_MULTIPLE_COLUMNS: &str = "\
 alter table big_data_table add text_column text, uuid_column uuid;
 ";
 
+pub const ALTER_TABLE_ADD_PARENTHESIZED_COLUMNS: &str = "\
+alter table big_data_table add (text_column text, uuid_column uuid);
+";
+
 pub const ALTER_TABLE_ADD_COLUMN_IF_NOT_EXISTS: &str = "\
 alter table big_data_table add if not exists text_column text;
 ";
@@ -209,6 +268,10 @@ pub const ALTER_TABLE_DROP_MULTIPLE_COLUMNS: &str = "\
 alter table big_data_table drop text_column uuid_column;
 ";
 
+pub const ALTER_TABLE_DROP_PARENTHESIZED_COLUMNS: &str = "\
+alter table big_data_table drop (text_column, uuid_column);
+";
+
 pub const ALTER_TABLE_RENAME_COLUMN: &str = "\
 alter table big_data_table rename text_column to text_col;
 ";
@@ -249,6 +312,96 @@ pub const TRUNCATE_TABLE: &str = "\
 truncate table big_data_table;
 ";
 
+pub const TRUNCATE_EXPLICIT_KEYSPACE: &str = "\
+truncate table big_data_keyspace.big_data_table;
+";
+
+// https://cassandra.apache.org/doc/stable/cassandra/managing/tools/cqlsh.html#describe
+
+pub const DESCRIBE_CLUSTER: &str = "\
+describe cluster;
+";
+
+pub const DESCRIBE_SCHEMA: &str = "\
+describe schema;
+";
+
+pub const DESCRIBE_FULL_SCHEMA: &str = "\
+describe full schema;
+";
+
+pub const DESCRIBE_KEYSPACES: &str = "\
+describe keyspaces;
+";
+
+pub const DESCRIBE_KEYSPACE: &str = "\
+describe keyspace big_data_keyspace;
+";
+
+pub const DESCRIBE_KEYSPACE_IN_USE: &str = "\
+describe keyspace;
+";
+
+pub const DESCRIBE_ONLY_KEYSPACE: &str = "\
+describe only keyspace big_data_keyspace;
+";
+
+pub const DESCRIBE_KEYSPACE_WITH_INTERNALS: &str = "\
+describe keyspace big_data_keyspace with internals;
+";
+
+pub const DESCRIBE_TABLES: &str = "\
+describe tables;
+";
+
+pub const DESCRIBE_TABLE: &str = "\
+describe table big_data_table;
+";
+
+pub const DESCRIBE_TABLE_EXPLICIT_KEYSPACE: &str = "\
+describe table big_data_keyspace.big_data_table;
+";
+
+pub const DESCRIBE_INDEX: &str = "\
+describe index big_data_index;
+";
+
+pub const DESCRIBE_MATERIALIZED_VIEW: &str = "\
+describe materialized view big_data_view;
+";
+
+pub const DESCRIBE_TYPES: &str = "\
+describe types;
+";
+
+pub const DESCRIBE_TYPE: &str = "\
+describe type big_data_type;
+";
+
+pub const DESCRIBE_FUNCTIONS: &str = "\
+describe functions;
+";
+
+pub const DESCRIBE_FUNCTION: &str = "\
+describe function big_data_keyspace.big_data_function;
+";
+
+pub const DESCRIBE_AGGREGATES: &str = "\
+describe aggregates;
+";
+
+pub const DESCRIBE_AGGREGATE: &str = "\
+describe aggregate big_data_agg;
+";
+
+pub const DESCRIBE_OBJECT: &str = "\
+describe big_data_keyspace.big_data_table;
+";
+
+pub const DESC_TABLE: &str = "\
+desc table big_data_table;
+";
+
 // https://cassandra.apache.org/doc/stable/cassandra/cql/dml.html#select-statement
 
 pub const SELECT_EXPLICIT_COLUMNS: &str = "\
@@ -464,6 +617,58 @@ insert into big_data_table (text_col2) values ('more data!');
 apply batch;
 ";
 
+pub const UNLOGGED_BATCH: &str = "\
+begin unlogged batch
+insert into big_data_table (text_col1) values ('big data!');
+update big_data_table set text_col2 = 'more data!' where text_col1 = 'big data!';
+apply batch;
+";
+
+pub const COUNTER_BATCH: &str = "\
+begin counter batch
+update big_data_table set count_col = count_col + 1 where text_col = 'big data!';
+delete from big_data_table where text_col = 'more data!';
+apply batch;
+";
+
+// https://cassandra.apache.org/doc/stable/cassandra/cql/definitions.html#terms
+
+pub const UPDATE_ARITHMETIC: &str = "\
+update big_data_table set int_column = int_column + 2 * 3 where text_column = 'big data!';
+";
+
+pub const UPDATE_COLLECTION_ELEMENT: &str = "\
+update big_data_table set map_column['key'] = 'big data!' where text_column = 'more data!';
+";
+
+pub const UPDATE_EXPLICIT_KEYSPACE: &str = "\
+update big_data_keyspace.big_data_table using ttl 86400 and timestamp 1698796800 set int_column = 1 where text_column = 'big data!';
+";
+
+pub const INSERT_COLLECTION_LITERALS: &str = "\
+insert into big_data_table (list_column, set_column, map_column, tuple_column) values ([1, 2], {'a', 'b'}, {'k': -1}, (true, null));
+";
+
+pub const INSERT_BLOB_AND_EXPONENT_LITERALS: &str = "\
+insert into big_data_table (blob_column, float_column, double_column) values (0xcafe, 1.5e10, -2E-3);
+";
+
+pub const UPDATE_BLOB_LITERALS: &str = "\
+update big_data_table set blob_column = 0xcafe where blob_key = 0xBEEF;
+";
+
+pub const INSERT_FUNCTION_CALL_AND_DURATION: &str = "\
+insert into big_data_table (uuid_column, duration_column, iso_duration_column) values (uuid(), 1h30m, P1DT2H);
+";
+
+pub const SELECT_BIND_MARKERS: &str = "\
+select * from big_data_table where text_column = ? and int_column in :int_values;
+";
+
+pub const SELECT_WHERE_TOKEN: &str = "\
+select * from big_data_table where token(text_column) > token('big data!');
+";
+
 // https://cassandra.apache.org/doc/stable/cassandra/cql/indexes.html#create-index-statement
 
 pub const CREATE_INDEX: &str = "\
@@ -546,6 +751,13 @@ create materialized view big_data_view as
     with comment = 'comment ca va';
 ";
 
+pub const CREATE_MATERIALIZED_VIEW_WITH_COMPOUND_PRIMARY_KEY: &str = "\
+create materialized view big_data_keyspace.big_data_view as
+    select text_column, uuid_column from big_data_keyspace.big_data_table
+    where text_column is not null and uuid_column is not null
+    primary key (text_column, uuid_column);
+";
+
 // https://cassandra.apache.org/doc/stable/cassandra/cql/mvs.html#alter-materialized-view-statement
 
 pub const ALTER_MATERIALIZED_VIEW: &str = "\
@@ -593,6 +805,14 @@ create function big_data_fn(fn_arg int)
     $$;
 ";
 
+pub const CREATE_FUNCTION_WITH_EXPLICIT_KEYSPACE: &str = "\
+CREATE FUNCTION big_data_keyspace.big_data_fn (fn_arg int)
+    RETURNS NULL ON NULL INPUT
+    RETURNS int
+    LANGUAGE java
+    AS 'return fn_arg;';
+";
+
 pub const CREATE_FUNCTION_RETURNS_NULL_ON_NULL_INPUT: &str = "\
 create function big_data_fn (fn_arg int)
     returns null on null input
@@ -709,6 +929,10 @@ pub const CREATE_AGGREGATE_WITH_FINALFUNC_AND_INITCOND: &str = "\
 create aggregate big_data_agg (int) sfunc fn_name stype list<text> finalfunc ffn_name initcond 'state value';
 ";
 
+pub const CREATE_AGGREGATE_WITH_EXPLICIT_KEYSPACE: &str = "\
+create aggregate big_data_keyspace.big_data_agg (int) sfunc fn_name stype int;
+";
+
 // https://cassandra.apache.org/doc/stable/cassandra/cql/functions.html#drop-aggregate-statement
 
 pub const DROP_AGGREGATE_WITHOUT_ARGS: &str = "\
@@ -757,6 +981,18 @@ pub const CREATE_EXPLICIT_KEYSPACE_UDT_WITH_MULTIPLE_ATTRIBUTES: &str = "\
 create type big_data_keyspace.big_data_udt (int_attr int, text_attr text);
 ";
 
+pub const CREATE_EXPLICIT_KEYSPACE_UDT_WITH_EXPLICIT_KEYSPACE_UDT_FIELD: &str = "\
+create type big_data_keyspace.big_data_udt (udt_attr frozen<big_data_keyspace.other_udt>);
+";
+
+pub const CREATE_TABLE_WITH_EXPLICIT_KEYSPACE_UDT_COLLECTION: &str = "\
+create table big_data_keyspace.big_data_table
+(
+    uuid_column uuid primary key,
+    list_column list<frozen<big_data_keyspace.big_data_udt>>
+);
+";
+
 pub const CREATE_EXPLICIT_KEYSPACE_UDT_IF_NOT_EXISTS: &str = "\
 create type if not exists big_data_keyspace.big_data_udt (int_attr int);
 ";
@@ -0,0 +1,787 @@
+//! Traversal of a parsed AST by reference.
+//!
+//! Each method of [Visit] defaults to calling the `walk_*` function of the same node type, which
+//! visits the node's children. An implementation overrides the methods for the nodes it is
+//! interested in and calls the matching `walk_*` function to continue into the node's children.
+
+use crate::ast::*;
+
+pub trait Visit {
+    fn visit_statement(&mut self, node: &CqlStatement) {
+        walk_statement(self, node)
+    }
+
+    fn visit_alter(&mut self, node: &AlterStatement) {
+        walk_alter(self, node)
+    }
+
+    fn visit_alter_keyspace(&mut self, node: &AlterKeyspaceStatement) {
+        walk_alter_keyspace(self, node)
+    }
+
+    fn visit_alter_materialized_view(&mut self, node: &AlterMaterializedViewStatement) {
+        walk_alter_materialized_view(self, node)
+    }
+
+    fn visit_alter_role(&mut self, node: &AlterRoleStatement) {
+        walk_alter_role(self, node)
+    }
+
+    fn visit_alter_table(&mut self, node: &AlterTableStatement) {
+        walk_alter_table(self, node)
+    }
+
+    fn visit_alter_type(&mut self, node: &AlterTypeStatement) {
+        walk_alter_type(self, node)
+    }
+
+    fn visit_alter_user(&mut self, node: &AlterUserStatement) {
+        walk_alter_user(self, node)
+    }
+
+    fn visit_batch(&mut self, node: &BatchStatement) {
+        walk_batch(self, node)
+    }
+
+    fn visit_create(&mut self, node: &CreateStatement) {
+        walk_create(self, node)
+    }
+
+    fn visit_create_aggregate(&mut self, node: &CreateAggregateStatement) {
+        walk_create_aggregate(self, node)
+    }
+
+    fn visit_create_function(&mut self, node: &CreateFunctionStatement) {
+        walk_create_function(self, node)
+    }
+
+    fn visit_create_index(&mut self, node: &CreateIndexStatement) {
+        walk_create_index(self, node)
+    }
+
+    fn visit_create_keyspace(&mut self, node: &CreateKeyspaceStatement) {
+        walk_create_keyspace(self, node)
+    }
+
+    fn visit_create_materialized_view(&mut self, node: &CreateMaterializedViewStatement) {
+        walk_create_materialized_view(self, node)
+    }
+
+    fn visit_create_role(&mut self, node: &CreateRoleStatement) {
+        walk_create_role(self, node)
+    }
+
+    fn visit_create_table(&mut self, node: &CreateTableStatement) {
+        walk_create_table(self, node)
+    }
+
+    fn visit_create_trigger(&mut self, node: &CreateTriggerStatement) {
+        walk_create_trigger(self, node)
+    }
+
+    fn visit_create_type(&mut self, node: &CreateTypeStatement) {
+        walk_create_type(self, node)
+    }
+
+    fn visit_create_user(&mut self, node: &CreateUserStatement) {
+        walk_create_user(self, node)
+    }
+
+    fn visit_delete(&mut self, node: &DeleteStatement) {
+        walk_delete(self, node)
+    }
+
+    fn visit_describe(&mut self, node: &DescribeStatement) {
+        walk_describe(self, node)
+    }
+
+    fn visit_drop(&mut self, node: &DropStatement) {
+        walk_drop(self, node)
+    }
+
+    fn visit_drop_aggregate(&mut self, node: &DropAggregateStatement) {
+        walk_drop_aggregate(self, node)
+    }
+
+    fn visit_drop_function(&mut self, node: &DropFunctionStatement) {
+        walk_drop_function(self, node)
+    }
+
+    fn visit_drop_index(&mut self, node: &DropIndexStatement) {
+        walk_drop_index(self, node)
+    }
+
+    fn visit_drop_keyspace(&mut self, node: &DropKeyspaceStatement) {
+        walk_drop_keyspace(self, node)
+    }
+
+    fn visit_drop_materialized_view(&mut self, node: &DropMaterializedViewStatement) {
+        walk_drop_materialized_view(self, node)
+    }
+
+    fn visit_drop_role(&mut self, node: &DropRoleStatement) {
+        walk_drop_role(self, node)
+    }
+
+    fn visit_drop_table(&mut self, node: &DropTableStatement) {
+        walk_drop_table(self, node)
+    }
+
+    fn visit_drop_trigger(&mut self, node: &DropTriggerStatement) {
+        walk_drop_trigger(self, node)
+    }
+
+    fn visit_drop_type(&mut self, node: &DropTypeStatement) {
+        walk_drop_type(self, node)
+    }
+
+    fn visit_drop_user(&mut self, node: &DropUserStatement) {
+        walk_drop_user(self, node)
+    }
+
+    fn visit_insert(&mut self, node: &InsertStatement) {
+        walk_insert(self, node)
+    }
+
+    fn visit_select(&mut self, node: &SelectStatement) {
+        walk_select(self, node)
+    }
+
+    fn visit_truncate(&mut self, node: &TruncateStatement) {
+        walk_truncate(self, node)
+    }
+
+    fn visit_update(&mut self, node: &UpdateStatement) {
+        walk_update(self, node)
+    }
+
+    fn visit_use(&mut self, node: &UseStatement) {
+        walk_use(self, node)
+    }
+
+    fn visit_assignment_selection(&mut self, node: &AssignmentSelection) {
+        walk_assignment_selection(self, node)
+    }
+
+    fn visit_column_definition(&mut self, node: &ColumnDefinition) {
+        walk_column_definition(self, node)
+    }
+
+    fn visit_data_type(&mut self, node: &CqlDataType) {
+        walk_data_type(self, node)
+    }
+
+    fn visit_expression(&mut self, node: &ExpressionTerm) {
+        walk_expression(self, node)
+    }
+
+    fn visit_function_call(&mut self, node: &FunctionCall) {
+        walk_function_call(self, node)
+    }
+
+    fn visit_selector(&mut self, node: &Selector) {
+        walk_selector(self, node)
+    }
+
+    fn visit_where_clause(&mut self, node: &WhereClause) {
+        walk_where_clause(self, node)
+    }
+
+    /// Visits a name referring to a keyspace, which defaults to [Visit::visit_identifier].
+    fn visit_keyspace_name(&mut self, node: &TokenView) {
+        self.visit_identifier(node)
+    }
+
+    /// Visits the name of a schema object, column, field or role.
+    fn visit_identifier(&mut self, _node: &TokenView) {}
+}
+
+pub fn walk_statement<V: Visit + ?Sized>(v: &mut V, node: &CqlStatement) {
+    match node {
+        CqlStatement::Alter(statement) => v.visit_alter(statement),
+        CqlStatement::Batch(statement) => v.visit_batch(statement),
+        CqlStatement::Create(statement) => v.visit_create(statement),
+        CqlStatement::Delete(statement) => v.visit_delete(statement),
+        CqlStatement::Describe(statement) => v.visit_describe(statement),
+        CqlStatement::Drop(statement) => v.visit_drop(statement),
+        CqlStatement::Insert(statement) => v.visit_insert(statement),
+        CqlStatement::Select(statement) => v.visit_select(statement),
+        CqlStatement::Truncate(statement) => v.visit_truncate(statement),
+        CqlStatement::Update(statement) => v.visit_update(statement),
+        CqlStatement::Use(statement) => v.visit_use(statement),
+    }
+}
+
+pub fn walk_alter<V: Visit + ?Sized>(v: &mut V, node: &AlterStatement) {
+    match node {
+        AlterStatement::Keyspace(statement) => v.visit_alter_keyspace(statement),
+        AlterStatement::MaterializedView(statement) => v.visit_alter_materialized_view(statement),
+        AlterStatement::Role(statement) => v.visit_alter_role(statement),
+        AlterStatement::Table(statement) => v.visit_alter_table(statement),
+        AlterStatement::Type(statement) => v.visit_alter_type(statement),
+        AlterStatement::User(statement) => v.visit_alter_user(statement),
+    }
+}
+
+pub fn walk_alter_keyspace<V: Visit + ?Sized>(v: &mut V, node: &AlterKeyspaceStatement) {
+    v.visit_keyspace_name(&node.keyspace_name);
+}
+
+pub fn walk_alter_materialized_view<V: Visit + ?Sized>(
+    v: &mut V,
+    node: &AlterMaterializedViewStatement,
+) {
+    walk_qualified_name(v, &node.keyspace_name, &node.view_name);
+    walk_table_attributes(v, &node.attributes);
+}
+
+pub fn walk_alter_role<V: Visit + ?Sized>(v: &mut V, node: &AlterRoleStatement) {
+    v.visit_identifier(&node.role_name);
+}
+
+pub fn walk_alter_table<V: Visit + ?Sized>(v: &mut V, node: &AlterTableStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    match &node.instruction {
+        AlterTableInstruction::AddColumns { columns, .. } => {
+            for column in columns {
+                v.visit_identifier(&column.column_name);
+                v.visit_data_type(&column.data_type);
+            }
+        }
+        AlterTableInstruction::DropColumns { column_names, .. } => {
+            for column_name in column_names {
+                v.visit_identifier(column_name);
+            }
+        }
+        AlterTableInstruction::RenameColumns { renames, .. } => {
+            for (from, to) in renames {
+                v.visit_identifier(from);
+                v.visit_identifier(to);
+            }
+        }
+        AlterTableInstruction::With(attributes) => walk_table_attributes(v, attributes),
+    }
+}
+
+pub fn walk_alter_type<V: Visit + ?Sized>(v: &mut V, node: &AlterTypeStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.type_name);
+    match &node.instruction {
+        AlterTypeInstruction::AddField {
+            field_name,
+            data_type,
+            ..
+        } => {
+            v.visit_identifier(field_name);
+            v.visit_data_type(data_type);
+        }
+        AlterTypeInstruction::RenameFields { renames, .. } => {
+            for (from, to) in renames {
+                v.visit_identifier(from);
+                v.visit_identifier(to);
+            }
+        }
+    }
+}
+
+pub fn walk_alter_user<V: Visit + ?Sized>(v: &mut V, node: &AlterUserStatement) {
+    v.visit_identifier(&node.user_name);
+}
+
+pub fn walk_batch<V: Visit + ?Sized>(v: &mut V, node: &BatchStatement) {
+    walk_update_parameters(v, &node.using);
+    for statement in &node.statements {
+        v.visit_statement(statement);
+    }
+}
+
+pub fn walk_create<V: Visit + ?Sized>(v: &mut V, node: &CreateStatement) {
+    match node {
+        CreateStatement::Aggregate(statement) => v.visit_create_aggregate(statement),
+        CreateStatement::Function(statement) => v.visit_create_function(statement),
+        CreateStatement::Index(statement) => v.visit_create_index(statement),
+        CreateStatement::Keyspace(statement) => v.visit_create_keyspace(statement),
+        CreateStatement::MaterializedView(statement) => v.visit_create_materialized_view(statement),
+        CreateStatement::Role(statement) => v.visit_create_role(statement),
+        CreateStatement::Table(statement) => v.visit_create_table(statement),
+        CreateStatement::Trigger(statement) => v.visit_create_trigger(statement),
+        CreateStatement::Type(statement) => v.visit_create_type(statement),
+        CreateStatement::User(statement) => v.visit_create_user(statement),
+    }
+}
+
+pub fn walk_create_aggregate<V: Visit + ?Sized>(v: &mut V, node: &CreateAggregateStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.function_name);
+    v.visit_data_type(&node.function_arg);
+    v.visit_identifier(&node.state_function);
+    v.visit_data_type(&node.state_type);
+    if let Some(final_function) = &node.final_function {
+        v.visit_identifier(final_function);
+    }
+    if let Some(init_condition) = &node.init_condition {
+        v.visit_expression(init_condition);
+    }
+}
+
+pub fn walk_create_function<V: Visit + ?Sized>(v: &mut V, node: &CreateFunctionStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.function_name);
+    for (arg_name, data_type) in &node.function_args {
+        v.visit_identifier(arg_name);
+        v.visit_data_type(data_type);
+    }
+    v.visit_data_type(&node.returns);
+}
+
+pub fn walk_create_index<V: Visit + ?Sized>(v: &mut V, node: &CreateIndexStatement) {
+    if let Some(index_name) = &node.index_name {
+        v.visit_identifier(index_name);
+    }
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    match &node.on_column {
+        CreateIndexColumn::Column(column_name)
+        | CreateIndexColumn::FullCollection(column_name)
+        | CreateIndexColumn::MapEntries(column_name)
+        | CreateIndexColumn::MapValues(column_name)
+        | CreateIndexColumn::MapKeys(column_name) => v.visit_identifier(column_name),
+    }
+}
+
+pub fn walk_create_keyspace<V: Visit + ?Sized>(v: &mut V, node: &CreateKeyspaceStatement) {
+    v.visit_keyspace_name(&node.keyspace_name);
+}
+
+pub fn walk_create_materialized_view<V: Visit + ?Sized>(
+    v: &mut V,
+    node: &CreateMaterializedViewStatement,
+) {
+    walk_qualified_name(v, &node.keyspace_name, &node.view_name);
+    walk_select_clause(v, &node.select_clause);
+    walk_qualified_name(v, &node.base_keyspace_name, &node.base_table_name);
+    v.visit_where_clause(&node.where_clause);
+    walk_primary_key(v, &node.primary_key);
+    if let Some(attributes) = &node.attributes {
+        walk_table_attributes(v, attributes);
+    }
+}
+
+pub fn walk_create_role<V: Visit + ?Sized>(v: &mut V, node: &CreateRoleStatement) {
+    v.visit_identifier(&node.role_name);
+}
+
+pub fn walk_create_table<V: Visit + ?Sized>(v: &mut V, node: &CreateTableStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    for column_definition in &node.column_definitions {
+        v.visit_column_definition(column_definition);
+    }
+    if let Some(attributes) = &node.attributes {
+        walk_table_attributes(v, attributes);
+    }
+}
+
+pub fn walk_create_trigger<V: Visit + ?Sized>(v: &mut V, node: &CreateTriggerStatement) {
+    v.visit_identifier(&node.trigger_name);
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+}
+
+pub fn walk_create_type<V: Visit + ?Sized>(v: &mut V, node: &CreateTypeStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.type_name);
+    for (field_name, data_type) in &node.fields {
+        v.visit_identifier(field_name);
+        v.visit_data_type(data_type);
+    }
+}
+
+pub fn walk_create_user<V: Visit + ?Sized>(v: &mut V, node: &CreateUserStatement) {
+    v.visit_identifier(&node.user_name);
+}
+
+pub fn walk_delete<V: Visit + ?Sized>(v: &mut V, node: &DeleteStatement) {
+    for selection in &node.selections {
+        v.visit_assignment_selection(selection);
+    }
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    walk_update_parameters(v, &node.using);
+    v.visit_where_clause(&node.where_clause);
+    if let Some(if_behavior) = &node.if_behavior {
+        walk_if_behavior(v, if_behavior);
+    }
+}
+
+pub fn walk_describe<V: Visit + ?Sized>(v: &mut V, node: &DescribeStatement) {
+    match &node.target {
+        DescribeTarget::Aggregate {
+            keyspace_name,
+            aggregate_name: name,
+        }
+        | DescribeTarget::Function {
+            keyspace_name,
+            function_name: name,
+        }
+        | DescribeTarget::Index {
+            keyspace_name,
+            index_name: name,
+        }
+        | DescribeTarget::MaterializedView {
+            keyspace_name,
+            view_name: name,
+        }
+        | DescribeTarget::Object {
+            keyspace_name,
+            object_name: name,
+        }
+        | DescribeTarget::Table {
+            keyspace_name,
+            table_name: name,
+        }
+        | DescribeTarget::Type {
+            keyspace_name,
+            type_name: name,
+        } => walk_qualified_name(v, keyspace_name, name),
+        DescribeTarget::Keyspace {
+            keyspace_name: Some(keyspace_name),
+            ..
+        } => v.visit_keyspace_name(keyspace_name),
+        DescribeTarget::Aggregates
+        | DescribeTarget::Cluster
+        | DescribeTarget::Functions
+        | DescribeTarget::Keyspace { .. }
+        | DescribeTarget::Keyspaces
+        | DescribeTarget::Schema { .. }
+        | DescribeTarget::Tables
+        | DescribeTarget::Types => {}
+    }
+}
+
+pub fn walk_drop<V: Visit + ?Sized>(v: &mut V, node: &DropStatement) {
+    match node {
+        DropStatement::Aggregate(statement) => v.visit_drop_aggregate(statement),
+        DropStatement::Function(statement) => v.visit_drop_function(statement),
+        DropStatement::Index(statement) => v.visit_drop_index(statement),
+        DropStatement::Keyspace(statement) => v.visit_drop_keyspace(statement),
+        DropStatement::MaterializedView(statement) => v.visit_drop_materialized_view(statement),
+        DropStatement::Role(statement) => v.visit_drop_role(statement),
+        DropStatement::Table(statement) => v.visit_drop_table(statement),
+        DropStatement::Trigger(statement) => v.visit_drop_trigger(statement),
+        DropStatement::Type(statement) => v.visit_drop_type(statement),
+        DropStatement::User(statement) => v.visit_drop_user(statement),
+    }
+}
+
+pub fn walk_drop_aggregate<V: Visit + ?Sized>(v: &mut V, node: &DropAggregateStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.aggregate_name);
+    if let Some(signature) = &node.signature {
+        for data_type in signature {
+            v.visit_data_type(data_type);
+        }
+    }
+}
+
+pub fn walk_drop_function<V: Visit + ?Sized>(v: &mut V, node: &DropFunctionStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.function_name);
+    if let Some(signature) = &node.signature {
+        for data_type in signature {
+            v.visit_data_type(data_type);
+        }
+    }
+}
+
+pub fn walk_drop_index<V: Visit + ?Sized>(v: &mut V, node: &DropIndexStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.index_name);
+}
+
+pub fn walk_drop_keyspace<V: Visit + ?Sized>(v: &mut V, node: &DropKeyspaceStatement) {
+    v.visit_keyspace_name(&node.keyspace_name);
+}
+
+pub fn walk_drop_materialized_view<V: Visit + ?Sized>(
+    v: &mut V,
+    node: &DropMaterializedViewStatement,
+) {
+    walk_qualified_name(v, &node.keyspace_name, &node.view_name);
+}
+
+pub fn walk_drop_role<V: Visit + ?Sized>(v: &mut V, node: &DropRoleStatement) {
+    v.visit_identifier(&node.role_name);
+}
+
+pub fn walk_drop_table<V: Visit + ?Sized>(v: &mut V, node: &DropTableStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+}
+
+pub fn walk_drop_trigger<V: Visit + ?Sized>(v: &mut V, node: &DropTriggerStatement) {
+    v.visit_identifier(&node.trigger_name);
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+}
+
+pub fn walk_drop_type<V: Visit + ?Sized>(v: &mut V, node: &DropTypeStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.type_name);
+}
+
+pub fn walk_drop_user<V: Visit + ?Sized>(v: &mut V, node: &DropUserStatement) {
+    v.visit_identifier(&node.user_name);
+}
+
+pub fn walk_insert<V: Visit + ?Sized>(v: &mut V, node: &InsertStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    match &node.values {
+        InsertValues::Json { json, .. } => v.visit_expression(json),
+        InsertValues::Values {
+            column_names,
+            values,
+        } => {
+            for column_name in column_names {
+                v.visit_identifier(column_name);
+            }
+            for value in values {
+                v.visit_expression(value);
+            }
+        }
+    }
+    walk_update_parameters(v, &node.using);
+}
+
+pub fn walk_select<V: Visit + ?Sized>(v: &mut V, node: &SelectStatement) {
+    walk_select_clause(v, &node.select_clause);
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    if let Some(where_clause) = &node.where_clause {
+        v.visit_where_clause(where_clause);
+    }
+    if let Some(group_by) = &node.group_by {
+        for column_name in group_by {
+            v.visit_identifier(column_name);
+        }
+    }
+    if let Some(order_by) = &node.order_by {
+        for clustering_order in order_by {
+            v.visit_identifier(&clustering_order.column_name);
+        }
+    }
+    if let Some(per_partition_limit) = &node.per_partition_limit {
+        v.visit_expression(per_partition_limit);
+    }
+    if let Some(limit) = &node.limit {
+        v.visit_expression(limit);
+    }
+}
+
+pub fn walk_truncate<V: Visit + ?Sized>(v: &mut V, node: &TruncateStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+}
+
+pub fn walk_update<V: Visit + ?Sized>(v: &mut V, node: &UpdateStatement) {
+    walk_qualified_name(v, &node.keyspace_name, &node.table_name);
+    walk_update_parameters(v, &node.using);
+    for assignment in &node.assignments {
+        v.visit_assignment_selection(&assignment.selection);
+        v.visit_expression(&assignment.expr_term);
+    }
+    v.visit_where_clause(&node.where_clause);
+    if let Some(if_behavior) = &node.if_behavior {
+        walk_if_behavior(v, if_behavior);
+    }
+}
+
+pub fn walk_use<V: Visit + ?Sized>(v: &mut V, node: &UseStatement) {
+    v.visit_keyspace_name(&node.keyspace_name);
+}
+
+pub fn walk_assignment_selection<V: Visit + ?Sized>(v: &mut V, node: &AssignmentSelection) {
+    match node {
+        AssignmentSelection::Column { column_name } => v.visit_identifier(column_name),
+        AssignmentSelection::ColumnAccess {
+            column_name,
+            expr_term,
+        } => {
+            v.visit_identifier(column_name);
+            v.visit_expression(expr_term);
+        }
+        AssignmentSelection::ColumnField {
+            column_name,
+            field_name,
+        } => {
+            v.visit_identifier(column_name);
+            v.visit_identifier(field_name);
+        }
+    }
+}
+
+pub fn walk_column_definition<V: Visit + ?Sized>(v: &mut V, node: &ColumnDefinition) {
+    match node {
+        ColumnDefinition::Column {
+            column_name,
+            data_type,
+            ..
+        } => {
+            v.visit_identifier(column_name);
+            v.visit_data_type(data_type);
+        }
+        ColumnDefinition::PrimaryKey(primary_key) => walk_primary_key(v, primary_key),
+    }
+}
+
+pub fn walk_data_type<V: Visit + ?Sized>(v: &mut V, node: &CqlDataType) {
+    match node {
+        CqlDataType::CollectionType(CqlCollectionType::List(data_type))
+        | CqlDataType::CollectionType(CqlCollectionType::Set(data_type))
+        | CqlDataType::Frozen(data_type)
+        | CqlDataType::Vector(data_type, _) => v.visit_data_type(data_type),
+        CqlDataType::CollectionType(CqlCollectionType::Map(key_type, value_type)) => {
+            v.visit_data_type(key_type);
+            v.visit_data_type(value_type);
+        }
+        CqlDataType::Tuple(data_types) => {
+            for data_type in data_types {
+                v.visit_data_type(data_type);
+            }
+        }
+        CqlDataType::ValueType(CqlValueType::UserDefinedType(keyspace_name, type_name)) => {
+            walk_qualified_name(v, keyspace_name, type_name);
+        }
+        CqlDataType::CustomType(_) | CqlDataType::ValueType(CqlValueType::NativeType(_)) => {}
+    }
+}
+
+pub fn walk_expression<V: Visit + ?Sized>(v: &mut V, node: &ExpressionTerm) {
+    match node {
+        ExpressionTerm::Arithmetic { left, right, .. } => {
+            v.visit_expression(left);
+            v.visit_expression(right);
+        }
+        ExpressionTerm::Column(column_name) => v.visit_identifier(column_name),
+        ExpressionTerm::FunctionCall(function_call) => v.visit_function_call(function_call),
+        ExpressionTerm::List(terms) | ExpressionTerm::Set(terms) | ExpressionTerm::Tuple(terms) => {
+            for term in terms {
+                v.visit_expression(term);
+            }
+        }
+        ExpressionTerm::Map(entries) => {
+            for (key, value) in entries {
+                v.visit_expression(key);
+                v.visit_expression(value);
+            }
+        }
+        ExpressionTerm::Negated(term) => v.visit_expression(term),
+        ExpressionTerm::UserDefinedType(fields) => {
+            for (field_name, value) in fields {
+                v.visit_identifier(field_name);
+                v.visit_expression(value);
+            }
+        }
+        ExpressionTerm::BindMarker(_)
+        | ExpressionTerm::Blob(_)
+        | ExpressionTerm::Boolean(_)
+        | ExpressionTerm::Duration(_)
+        | ExpressionTerm::Null
+        | ExpressionTerm::Number(_)
+        | ExpressionTerm::String(_)
+        | ExpressionTerm::Uuid(_) => {}
+    }
+}
+
+pub fn walk_function_call<V: Visit + ?Sized>(v: &mut V, node: &FunctionCall) {
+    walk_qualified_name(v, &node.keyspace_name, &node.function_name);
+    for arg in &node.args {
+        v.visit_expression(arg);
+    }
+}
+
+pub fn walk_selector<V: Visit + ?Sized>(v: &mut V, node: &Selector) {
+    match node {
+        Selector::Cast {
+            selector,
+            data_type,
+        } => {
+            v.visit_selector(selector);
+            v.visit_data_type(data_type);
+        }
+        Selector::CountAll => {}
+        Selector::Term(term) => v.visit_expression(term),
+    }
+}
+
+pub fn walk_where_clause<V: Visit + ?Sized>(v: &mut V, node: &WhereClause) {
+    for relation in &node.relations {
+        match &relation.columns {
+            RelationColumns::Single(column_name) => v.visit_identifier(column_name),
+            RelationColumns::Tuple(column_names) | RelationColumns::Token(column_names) => {
+                for column_name in column_names {
+                    v.visit_identifier(column_name);
+                }
+            }
+        }
+        v.visit_expression(&relation.expr_term);
+    }
+}
+
+fn walk_qualified_name<V: Visit + ?Sized>(
+    v: &mut V,
+    keyspace_name: &Option<TokenView>,
+    name: &TokenView,
+) {
+    if let Some(keyspace_name) = keyspace_name {
+        v.visit_keyspace_name(keyspace_name);
+    }
+    v.visit_identifier(name);
+}
+
+fn walk_primary_key<V: Visit + ?Sized>(v: &mut V, node: &PrimaryKeyDefinition) {
+    match node {
+        PrimaryKeyDefinition::Single(partition) => v.visit_identifier(partition),
+        PrimaryKeyDefinition::Compound {
+            partition,
+            clustering,
+        } => {
+            v.visit_identifier(partition);
+            for column_name in clustering {
+                v.visit_identifier(column_name);
+            }
+        }
+        PrimaryKeyDefinition::CompositePartition {
+            partition,
+            clustering,
+        } => {
+            for column_name in partition.iter().chain(clustering) {
+                v.visit_identifier(column_name);
+            }
+        }
+    }
+}
+
+fn walk_select_clause<V: Visit + ?Sized>(v: &mut V, node: &SelectClause) {
+    if let SelectClause::Selectors(selectors) = node {
+        for selector_definition in selectors {
+            v.visit_selector(&selector_definition.selector);
+            if let Some(alias) = &selector_definition.alias {
+                v.visit_identifier(alias);
+            }
+        }
+    }
+}
+
+fn walk_table_attributes<V: Visit + ?Sized>(v: &mut V, attributes: &[TableDefinitionAttribute]) {
+    for attribute in attributes {
+        if let TableDefinitionAttribute::ClusteringOrderBy(clustering_orders) = attribute {
+            for clustering_order in clustering_orders {
+                v.visit_identifier(&clustering_order.column_name);
+            }
+        }
+    }
+}
+
+fn walk_update_parameters<V: Visit + ?Sized>(v: &mut V, using: &[UpdateParameter]) {
+    for update_parameter in using {
+        match update_parameter {
+            UpdateParameter::Timestamp(term) | UpdateParameter::Ttl(term) => {
+                v.visit_expression(term)
+            }
+        }
+    }
+}
+
+fn walk_if_behavior<V: Visit + ?Sized>(v: &mut V, node: &UpdateIfBehavior) {
+    if let UpdateIfBehavior::Conditional(conditions) = node {
+        for condition in conditions {
+            v.visit_assignment_selection(&condition.selection);
+            v.visit_expression(&condition.expr_term);
+        }
+    }
+}
@@ -0,0 +1,807 @@
+//! Traversal of a parsed AST by mutable reference.
+//!
+//! Each method of [VisitMut] defaults to calling the `walk_*_mut` function of the same node type,
+//! which visits the node's children. An implementation overrides the methods for the nodes it is
+//! interested in and calls the matching `walk_*_mut` function to continue into the node's children.
+//! Identifiers are rewritten by replacing them with a [TokenView::from_value].
+
+use crate::ast::*;
+
+pub trait VisitMut {
+    fn visit_statement_mut(&mut self, node: &mut CqlStatement) {
+        walk_statement_mut(self, node)
+    }
+
+    fn visit_alter_mut(&mut self, node: &mut AlterStatement) {
+        walk_alter_mut(self, node)
+    }
+
+    fn visit_alter_keyspace_mut(&mut self, node: &mut AlterKeyspaceStatement) {
+        walk_alter_keyspace_mut(self, node)
+    }
+
+    fn visit_alter_materialized_view_mut(&mut self, node: &mut AlterMaterializedViewStatement) {
+        walk_alter_materialized_view_mut(self, node)
+    }
+
+    fn visit_alter_role_mut(&mut self, node: &mut AlterRoleStatement) {
+        walk_alter_role_mut(self, node)
+    }
+
+    fn visit_alter_table_mut(&mut self, node: &mut AlterTableStatement) {
+        walk_alter_table_mut(self, node)
+    }
+
+    fn visit_alter_type_mut(&mut self, node: &mut AlterTypeStatement) {
+        walk_alter_type_mut(self, node)
+    }
+
+    fn visit_alter_user_mut(&mut self, node: &mut AlterUserStatement) {
+        walk_alter_user_mut(self, node)
+    }
+
+    fn visit_batch_mut(&mut self, node: &mut BatchStatement) {
+        walk_batch_mut(self, node)
+    }
+
+    fn visit_create_mut(&mut self, node: &mut CreateStatement) {
+        walk_create_mut(self, node)
+    }
+
+    fn visit_create_aggregate_mut(&mut self, node: &mut CreateAggregateStatement) {
+        walk_create_aggregate_mut(self, node)
+    }
+
+    fn visit_create_function_mut(&mut self, node: &mut CreateFunctionStatement) {
+        walk_create_function_mut(self, node)
+    }
+
+    fn visit_create_index_mut(&mut self, node: &mut CreateIndexStatement) {
+        walk_create_index_mut(self, node)
+    }
+
+    fn visit_create_keyspace_mut(&mut self, node: &mut CreateKeyspaceStatement) {
+        walk_create_keyspace_mut(self, node)
+    }
+
+    fn visit_create_materialized_view_mut(&mut self, node: &mut CreateMaterializedViewStatement) {
+        walk_create_materialized_view_mut(self, node)
+    }
+
+    fn visit_create_role_mut(&mut self, node: &mut CreateRoleStatement) {
+        walk_create_role_mut(self, node)
+    }
+
+    fn visit_create_table_mut(&mut self, node: &mut CreateTableStatement) {
+        walk_create_table_mut(self, node)
+    }
+
+    fn visit_create_trigger_mut(&mut self, node: &mut CreateTriggerStatement) {
+        walk_create_trigger_mut(self, node)
+    }
+
+    fn visit_create_type_mut(&mut self, node: &mut CreateTypeStatement) {
+        walk_create_type_mut(self, node)
+    }
+
+    fn visit_create_user_mut(&mut self, node: &mut CreateUserStatement) {
+        walk_create_user_mut(self, node)
+    }
+
+    fn visit_delete_mut(&mut self, node: &mut DeleteStatement) {
+        walk_delete_mut(self, node)
+    }
+
+    fn visit_describe_mut(&mut self, node: &mut DescribeStatement) {
+        walk_describe_mut(self, node)
+    }
+
+    fn visit_drop_mut(&mut self, node: &mut DropStatement) {
+        walk_drop_mut(self, node)
+    }
+
+    fn visit_drop_aggregate_mut(&mut self, node: &mut DropAggregateStatement) {
+        walk_drop_aggregate_mut(self, node)
+    }
+
+    fn visit_drop_function_mut(&mut self, node: &mut DropFunctionStatement) {
+        walk_drop_function_mut(self, node)
+    }
+
+    fn visit_drop_index_mut(&mut self, node: &mut DropIndexStatement) {
+        walk_drop_index_mut(self, node)
+    }
+
+    fn visit_drop_keyspace_mut(&mut self, node: &mut DropKeyspaceStatement) {
+        walk_drop_keyspace_mut(self, node)
+    }
+
+    fn visit_drop_materialized_view_mut(&mut self, node: &mut DropMaterializedViewStatement) {
+        walk_drop_materialized_view_mut(self, node)
+    }
+
+    fn visit_drop_role_mut(&mut self, node: &mut DropRoleStatement) {
+        walk_drop_role_mut(self, node)
+    }
+
+    fn visit_drop_table_mut(&mut self, node: &mut DropTableStatement) {
+        walk_drop_table_mut(self, node)
+    }
+
+    fn visit_drop_trigger_mut(&mut self, node: &mut DropTriggerStatement) {
+        walk_drop_trigger_mut(self, node)
+    }
+
+    fn visit_drop_type_mut(&mut self, node: &mut DropTypeStatement) {
+        walk_drop_type_mut(self, node)
+    }
+
+    fn visit_drop_user_mut(&mut self, node: &mut DropUserStatement) {
+        walk_drop_user_mut(self, node)
+    }
+
+    fn visit_insert_mut(&mut self, node: &mut InsertStatement) {
+        walk_insert_mut(self, node)
+    }
+
+    fn visit_select_mut(&mut self, node: &mut SelectStatement) {
+        walk_select_mut(self, node)
+    }
+
+    fn visit_truncate_mut(&mut self, node: &mut TruncateStatement) {
+        walk_truncate_mut(self, node)
+    }
+
+    fn visit_update_mut(&mut self, node: &mut UpdateStatement) {
+        walk_update_mut(self, node)
+    }
+
+    fn visit_use_mut(&mut self, node: &mut UseStatement) {
+        walk_use_mut(self, node)
+    }
+
+    fn visit_assignment_selection_mut(&mut self, node: &mut AssignmentSelection) {
+        walk_assignment_selection_mut(self, node)
+    }
+
+    fn visit_column_definition_mut(&mut self, node: &mut ColumnDefinition) {
+        walk_column_definition_mut(self, node)
+    }
+
+    fn visit_data_type_mut(&mut self, node: &mut CqlDataType) {
+        walk_data_type_mut(self, node)
+    }
+
+    fn visit_expression_mut(&mut self, node: &mut ExpressionTerm) {
+        walk_expression_mut(self, node)
+    }
+
+    fn visit_function_call_mut(&mut self, node: &mut FunctionCall) {
+        walk_function_call_mut(self, node)
+    }
+
+    fn visit_selector_mut(&mut self, node: &mut Selector) {
+        walk_selector_mut(self, node)
+    }
+
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) {
+        walk_where_clause_mut(self, node)
+    }
+
+    /// Visits a name referring to a keyspace, which defaults to [VisitMut::visit_identifier_mut].
+    fn visit_keyspace_name_mut(&mut self, node: &mut TokenView) {
+        self.visit_identifier_mut(node)
+    }
+
+    /// Visits the name of a schema object, column, field or role.
+    fn visit_identifier_mut(&mut self, _node: &mut TokenView) {}
+}
+
+pub fn walk_statement_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CqlStatement) {
+    match node {
+        CqlStatement::Alter(statement) => v.visit_alter_mut(statement),
+        CqlStatement::Batch(statement) => v.visit_batch_mut(statement),
+        CqlStatement::Create(statement) => v.visit_create_mut(statement),
+        CqlStatement::Delete(statement) => v.visit_delete_mut(statement),
+        CqlStatement::Describe(statement) => v.visit_describe_mut(statement),
+        CqlStatement::Drop(statement) => v.visit_drop_mut(statement),
+        CqlStatement::Insert(statement) => v.visit_insert_mut(statement),
+        CqlStatement::Select(statement) => v.visit_select_mut(statement),
+        CqlStatement::Truncate(statement) => v.visit_truncate_mut(statement),
+        CqlStatement::Update(statement) => v.visit_update_mut(statement),
+        CqlStatement::Use(statement) => v.visit_use_mut(statement),
+    }
+}
+
+pub fn walk_alter_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterStatement) {
+    match node {
+        AlterStatement::Keyspace(statement) => v.visit_alter_keyspace_mut(statement),
+        AlterStatement::MaterializedView(statement) => {
+            v.visit_alter_materialized_view_mut(statement)
+        }
+        AlterStatement::Role(statement) => v.visit_alter_role_mut(statement),
+        AlterStatement::Table(statement) => v.visit_alter_table_mut(statement),
+        AlterStatement::Type(statement) => v.visit_alter_type_mut(statement),
+        AlterStatement::User(statement) => v.visit_alter_user_mut(statement),
+    }
+}
+
+pub fn walk_alter_keyspace_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterKeyspaceStatement) {
+    v.visit_keyspace_name_mut(&mut node.keyspace_name);
+}
+
+pub fn walk_alter_materialized_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut AlterMaterializedViewStatement,
+) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.view_name);
+    walk_table_attributes_mut(v, &mut node.attributes);
+}
+
+pub fn walk_alter_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterRoleStatement) {
+    v.visit_identifier_mut(&mut node.role_name);
+}
+
+pub fn walk_alter_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterTableStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    match &mut node.instruction {
+        AlterTableInstruction::AddColumns { columns, .. } => {
+            for column in columns {
+                v.visit_identifier_mut(&mut column.column_name);
+                v.visit_data_type_mut(&mut column.data_type);
+            }
+        }
+        AlterTableInstruction::DropColumns { column_names, .. } => {
+            for column_name in column_names {
+                v.visit_identifier_mut(column_name);
+            }
+        }
+        AlterTableInstruction::RenameColumns { renames, .. } => {
+            for (from, to) in renames {
+                v.visit_identifier_mut(from);
+                v.visit_identifier_mut(to);
+            }
+        }
+        AlterTableInstruction::With(attributes) => walk_table_attributes_mut(v, attributes),
+    }
+}
+
+pub fn walk_alter_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterTypeStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.type_name);
+    match &mut node.instruction {
+        AlterTypeInstruction::AddField {
+            field_name,
+            data_type,
+            ..
+        } => {
+            v.visit_identifier_mut(field_name);
+            v.visit_data_type_mut(data_type);
+        }
+        AlterTypeInstruction::RenameFields { renames, .. } => {
+            for (from, to) in renames {
+                v.visit_identifier_mut(from);
+                v.visit_identifier_mut(to);
+            }
+        }
+    }
+}
+
+pub fn walk_alter_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut AlterUserStatement) {
+    v.visit_identifier_mut(&mut node.user_name);
+}
+
+pub fn walk_batch_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut BatchStatement) {
+    walk_update_parameters_mut(v, &mut node.using);
+    for statement in &mut node.statements {
+        v.visit_statement_mut(statement);
+    }
+}
+
+pub fn walk_create_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateStatement) {
+    match node {
+        CreateStatement::Aggregate(statement) => v.visit_create_aggregate_mut(statement),
+        CreateStatement::Function(statement) => v.visit_create_function_mut(statement),
+        CreateStatement::Index(statement) => v.visit_create_index_mut(statement),
+        CreateStatement::Keyspace(statement) => v.visit_create_keyspace_mut(statement),
+        CreateStatement::MaterializedView(statement) => {
+            v.visit_create_materialized_view_mut(statement)
+        }
+        CreateStatement::Role(statement) => v.visit_create_role_mut(statement),
+        CreateStatement::Table(statement) => v.visit_create_table_mut(statement),
+        CreateStatement::Trigger(statement) => v.visit_create_trigger_mut(statement),
+        CreateStatement::Type(statement) => v.visit_create_type_mut(statement),
+        CreateStatement::User(statement) => v.visit_create_user_mut(statement),
+    }
+}
+
+pub fn walk_create_aggregate_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CreateAggregateStatement,
+) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.function_name);
+    v.visit_data_type_mut(&mut node.function_arg);
+    v.visit_identifier_mut(&mut node.state_function);
+    v.visit_data_type_mut(&mut node.state_type);
+    if let Some(final_function) = &mut node.final_function {
+        v.visit_identifier_mut(final_function);
+    }
+    if let Some(init_condition) = &mut node.init_condition {
+        v.visit_expression_mut(init_condition);
+    }
+}
+
+pub fn walk_create_function_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CreateFunctionStatement,
+) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.function_name);
+    for (arg_name, data_type) in &mut node.function_args {
+        v.visit_identifier_mut(arg_name);
+        v.visit_data_type_mut(data_type);
+    }
+    v.visit_data_type_mut(&mut node.returns);
+}
+
+pub fn walk_create_index_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateIndexStatement) {
+    if let Some(index_name) = &mut node.index_name {
+        v.visit_identifier_mut(index_name);
+    }
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    match &mut node.on_column {
+        CreateIndexColumn::Column(column_name)
+        | CreateIndexColumn::FullCollection(column_name)
+        | CreateIndexColumn::MapEntries(column_name)
+        | CreateIndexColumn::MapValues(column_name)
+        | CreateIndexColumn::MapKeys(column_name) => v.visit_identifier_mut(column_name),
+    }
+}
+
+pub fn walk_create_keyspace_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CreateKeyspaceStatement,
+) {
+    v.visit_keyspace_name_mut(&mut node.keyspace_name);
+}
+
+pub fn walk_create_materialized_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut CreateMaterializedViewStatement,
+) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.view_name);
+    walk_select_clause_mut(v, &mut node.select_clause);
+    walk_qualified_name_mut(v, &mut node.base_keyspace_name, &mut node.base_table_name);
+    v.visit_where_clause_mut(&mut node.where_clause);
+    walk_primary_key_mut(v, &mut node.primary_key);
+    if let Some(attributes) = &mut node.attributes {
+        walk_table_attributes_mut(v, attributes);
+    }
+}
+
+pub fn walk_create_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateRoleStatement) {
+    v.visit_identifier_mut(&mut node.role_name);
+}
+
+pub fn walk_create_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateTableStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    for column_definition in &mut node.column_definitions {
+        v.visit_column_definition_mut(column_definition);
+    }
+    if let Some(attributes) = &mut node.attributes {
+        walk_table_attributes_mut(v, attributes);
+    }
+}
+
+pub fn walk_create_trigger_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateTriggerStatement) {
+    v.visit_identifier_mut(&mut node.trigger_name);
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+}
+
+pub fn walk_create_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateTypeStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.type_name);
+    for (field_name, data_type) in &mut node.fields {
+        v.visit_identifier_mut(field_name);
+        v.visit_data_type_mut(data_type);
+    }
+}
+
+pub fn walk_create_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CreateUserStatement) {
+    v.visit_identifier_mut(&mut node.user_name);
+}
+
+pub fn walk_delete_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DeleteStatement) {
+    for selection in &mut node.selections {
+        v.visit_assignment_selection_mut(selection);
+    }
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    walk_update_parameters_mut(v, &mut node.using);
+    v.visit_where_clause_mut(&mut node.where_clause);
+    if let Some(if_behavior) = &mut node.if_behavior {
+        walk_if_behavior_mut(v, if_behavior);
+    }
+}
+
+pub fn walk_describe_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DescribeStatement) {
+    match &mut node.target {
+        DescribeTarget::Aggregate {
+            keyspace_name,
+            aggregate_name: name,
+        }
+        | DescribeTarget::Function {
+            keyspace_name,
+            function_name: name,
+        }
+        | DescribeTarget::Index {
+            keyspace_name,
+            index_name: name,
+        }
+        | DescribeTarget::MaterializedView {
+            keyspace_name,
+            view_name: name,
+        }
+        | DescribeTarget::Object {
+            keyspace_name,
+            object_name: name,
+        }
+        | DescribeTarget::Table {
+            keyspace_name,
+            table_name: name,
+        }
+        | DescribeTarget::Type {
+            keyspace_name,
+            type_name: name,
+        } => walk_qualified_name_mut(v, keyspace_name, name),
+        DescribeTarget::Keyspace {
+            keyspace_name: Some(keyspace_name),
+            ..
+        } => v.visit_keyspace_name_mut(keyspace_name),
+        DescribeTarget::Aggregates
+        | DescribeTarget::Cluster
+        | DescribeTarget::Functions
+        | DescribeTarget::Keyspace { .. }
+        | DescribeTarget::Keyspaces
+        | DescribeTarget::Schema { .. }
+        | DescribeTarget::Tables
+        | DescribeTarget::Types => {}
+    }
+}
+
+pub fn walk_drop_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropStatement) {
+    match node {
+        DropStatement::Aggregate(statement) => v.visit_drop_aggregate_mut(statement),
+        DropStatement::Function(statement) => v.visit_drop_function_mut(statement),
+        DropStatement::Index(statement) => v.visit_drop_index_mut(statement),
+        DropStatement::Keyspace(statement) => v.visit_drop_keyspace_mut(statement),
+        DropStatement::MaterializedView(statement) => v.visit_drop_materialized_view_mut(statement),
+        DropStatement::Role(statement) => v.visit_drop_role_mut(statement),
+        DropStatement::Table(statement) => v.visit_drop_table_mut(statement),
+        DropStatement::Trigger(statement) => v.visit_drop_trigger_mut(statement),
+        DropStatement::Type(statement) => v.visit_drop_type_mut(statement),
+        DropStatement::User(statement) => v.visit_drop_user_mut(statement),
+    }
+}
+
+pub fn walk_drop_aggregate_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropAggregateStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.aggregate_name);
+    if let Some(signature) = &mut node.signature {
+        for data_type in signature {
+            v.visit_data_type_mut(data_type);
+        }
+    }
+}
+
+pub fn walk_drop_function_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropFunctionStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.function_name);
+    if let Some(signature) = &mut node.signature {
+        for data_type in signature {
+            v.visit_data_type_mut(data_type);
+        }
+    }
+}
+
+pub fn walk_drop_index_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropIndexStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.index_name);
+}
+
+pub fn walk_drop_keyspace_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropKeyspaceStatement) {
+    v.visit_keyspace_name_mut(&mut node.keyspace_name);
+}
+
+pub fn walk_drop_materialized_view_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut DropMaterializedViewStatement,
+) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.view_name);
+}
+
+pub fn walk_drop_role_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropRoleStatement) {
+    v.visit_identifier_mut(&mut node.role_name);
+}
+
+pub fn walk_drop_table_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropTableStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+}
+
+pub fn walk_drop_trigger_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropTriggerStatement) {
+    v.visit_identifier_mut(&mut node.trigger_name);
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+}
+
+pub fn walk_drop_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropTypeStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.type_name);
+}
+
+pub fn walk_drop_user_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut DropUserStatement) {
+    v.visit_identifier_mut(&mut node.user_name);
+}
+
+pub fn walk_insert_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut InsertStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    match &mut node.values {
+        InsertValues::Json { json, .. } => v.visit_expression_mut(json),
+        InsertValues::Values {
+            column_names,
+            values,
+        } => {
+            for column_name in column_names {
+                v.visit_identifier_mut(column_name);
+            }
+            for value in values {
+                v.visit_expression_mut(value);
+            }
+        }
+    }
+    walk_update_parameters_mut(v, &mut node.using);
+}
+
+pub fn walk_select_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SelectStatement) {
+    walk_select_clause_mut(v, &mut node.select_clause);
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    if let Some(where_clause) = &mut node.where_clause {
+        v.visit_where_clause_mut(where_clause);
+    }
+    if let Some(group_by) = &mut node.group_by {
+        for column_name in group_by {
+            v.visit_identifier_mut(column_name);
+        }
+    }
+    if let Some(order_by) = &mut node.order_by {
+        for clustering_order in order_by {
+            v.visit_identifier_mut(&mut clustering_order.column_name);
+        }
+    }
+    if let Some(per_partition_limit) = &mut node.per_partition_limit {
+        v.visit_expression_mut(per_partition_limit);
+    }
+    if let Some(limit) = &mut node.limit {
+        v.visit_expression_mut(limit);
+    }
+}
+
+pub fn walk_truncate_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut TruncateStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+}
+
+pub fn walk_update_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UpdateStatement) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.table_name);
+    walk_update_parameters_mut(v, &mut node.using);
+    for assignment in &mut node.assignments {
+        v.visit_assignment_selection_mut(&mut assignment.selection);
+        v.visit_expression_mut(&mut assignment.expr_term);
+    }
+    v.visit_where_clause_mut(&mut node.where_clause);
+    if let Some(if_behavior) = &mut node.if_behavior {
+        walk_if_behavior_mut(v, if_behavior);
+    }
+}
+
+pub fn walk_use_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UseStatement) {
+    v.visit_keyspace_name_mut(&mut node.keyspace_name);
+}
+
+pub fn walk_assignment_selection_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    node: &mut AssignmentSelection,
+) {
+    match node {
+        AssignmentSelection::Column { column_name } => v.visit_identifier_mut(column_name),
+        AssignmentSelection::ColumnAccess {
+            column_name,
+            expr_term,
+        } => {
+            v.visit_identifier_mut(column_name);
+            v.visit_expression_mut(expr_term);
+        }
+        AssignmentSelection::ColumnField {
+            column_name,
+            field_name,
+        } => {
+            v.visit_identifier_mut(column_name);
+            v.visit_identifier_mut(field_name);
+        }
+    }
+}
+
+pub fn walk_column_definition_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ColumnDefinition) {
+    match node {
+        ColumnDefinition::Column {
+            column_name,
+            data_type,
+            ..
+        } => {
+            v.visit_identifier_mut(column_name);
+            v.visit_data_type_mut(data_type);
+        }
+        ColumnDefinition::PrimaryKey(primary_key) => walk_primary_key_mut(v, primary_key),
+    }
+}
+
+pub fn walk_data_type_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut CqlDataType) {
+    match node {
+        CqlDataType::CollectionType(CqlCollectionType::List(data_type))
+        | CqlDataType::CollectionType(CqlCollectionType::Set(data_type))
+        | CqlDataType::Frozen(data_type)
+        | CqlDataType::Vector(data_type, _) => v.visit_data_type_mut(data_type),
+        CqlDataType::CollectionType(CqlCollectionType::Map(key_type, value_type)) => {
+            v.visit_data_type_mut(key_type);
+            v.visit_data_type_mut(value_type);
+        }
+        CqlDataType::Tuple(data_types) => {
+            for data_type in data_types {
+                v.visit_data_type_mut(data_type);
+            }
+        }
+        CqlDataType::ValueType(CqlValueType::UserDefinedType(keyspace_name, type_name)) => {
+            walk_qualified_name_mut(v, keyspace_name, type_name);
+        }
+        CqlDataType::CustomType(_) | CqlDataType::ValueType(CqlValueType::NativeType(_)) => {}
+    }
+}
+
+pub fn walk_expression_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut ExpressionTerm) {
+    match node {
+        ExpressionTerm::Arithmetic { left, right, .. } => {
+            v.visit_expression_mut(left);
+            v.visit_expression_mut(right);
+        }
+        ExpressionTerm::Column(column_name) => v.visit_identifier_mut(column_name),
+        ExpressionTerm::FunctionCall(function_call) => v.visit_function_call_mut(function_call),
+        ExpressionTerm::List(terms) | ExpressionTerm::Set(terms) | ExpressionTerm::Tuple(terms) => {
+            for term in terms {
+                v.visit_expression_mut(term);
+            }
+        }
+        ExpressionTerm::Map(entries) => {
+            for (key, value) in entries {
+                v.visit_expression_mut(key);
+                v.visit_expression_mut(value);
+            }
+        }
+        ExpressionTerm::Negated(term) => v.visit_expression_mut(term),
+        ExpressionTerm::UserDefinedType(fields) => {
+            for (field_name, value) in fields {
+                v.visit_identifier_mut(field_name);
+                v.visit_expression_mut(value);
+            }
+        }
+        ExpressionTerm::BindMarker(_)
+        | ExpressionTerm::Blob(_)
+        | ExpressionTerm::Boolean(_)
+        | ExpressionTerm::Duration(_)
+        | ExpressionTerm::Null
+        | ExpressionTerm::Number(_)
+        | ExpressionTerm::String(_)
+        | ExpressionTerm::Uuid(_) => {}
+    }
+}
+
+pub fn walk_function_call_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut FunctionCall) {
+    walk_qualified_name_mut(v, &mut node.keyspace_name, &mut node.function_name);
+    for arg in &mut node.args {
+        v.visit_expression_mut(arg);
+    }
+}
+
+pub fn walk_selector_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut Selector) {
+    match node {
+        Selector::Cast {
+            selector,
+            data_type,
+        } => {
+            v.visit_selector_mut(selector);
+            v.visit_data_type_mut(data_type);
+        }
+        Selector::CountAll => {}
+        Selector::Term(term) => v.visit_expression_mut(term),
+    }
+}
+
+pub fn walk_where_clause_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut WhereClause) {
+    for relation in &mut node.relations {
+        match &mut relation.columns {
+            RelationColumns::Single(column_name) => v.visit_identifier_mut(column_name),
+            RelationColumns::Tuple(column_names) | RelationColumns::Token(column_names) => {
+                for column_name in column_names {
+                    v.visit_identifier_mut(column_name);
+                }
+            }
+        }
+        v.visit_expression_mut(&mut relation.expr_term);
+    }
+}
+
+fn walk_qualified_name_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    keyspace_name: &mut Option<TokenView>,
+    name: &mut TokenView,
+) {
+    if let Some(keyspace_name) = keyspace_name {
+        v.visit_keyspace_name_mut(keyspace_name);
+    }
+    v.visit_identifier_mut(name);
+}
+
+fn walk_primary_key_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut PrimaryKeyDefinition) {
+    match node {
+        PrimaryKeyDefinition::Single(partition) => v.visit_identifier_mut(partition),
+        PrimaryKeyDefinition::Compound {
+            partition,
+            clustering,
+        } => {
+            v.visit_identifier_mut(partition);
+            for column_name in clustering {
+                v.visit_identifier_mut(column_name);
+            }
+        }
+        PrimaryKeyDefinition::CompositePartition {
+            partition,
+            clustering,
+        } => {
+            for column_name in partition.iter_mut().chain(clustering) {
+                v.visit_identifier_mut(column_name);
+            }
+        }
+    }
+}
+
+fn walk_select_clause_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut SelectClause) {
+    if let SelectClause::Selectors(selectors) = node {
+        for selector_definition in selectors {
+            v.visit_selector_mut(&mut selector_definition.selector);
+            if let Some(alias) = &mut selector_definition.alias {
+                v.visit_identifier_mut(alias);
+            }
+        }
+    }
+}
+
+fn walk_table_attributes_mut<V: VisitMut + ?Sized>(
+    v: &mut V,
+    attributes: &mut [TableDefinitionAttribute],
+) {
+    for attribute in attributes {
+        if let TableDefinitionAttribute::ClusteringOrderBy(clustering_orders) = attribute {
+            for clustering_order in clustering_orders {
+                v.visit_identifier_mut(&mut clustering_order.column_name);
+            }
+        }
+    }
+}
+
+fn walk_update_parameters_mut<V: VisitMut + ?Sized>(v: &mut V, using: &mut [UpdateParameter]) {
+    for update_parameter in using {
+        match update_parameter {
+            UpdateParameter::Timestamp(term) | UpdateParameter::Ttl(term) => {
+                v.visit_expression_mut(term)
+            }
+        }
+    }
+}
+
+fn walk_if_behavior_mut<V: VisitMut + ?Sized>(v: &mut V, node: &mut UpdateIfBehavior) {
+    if let UpdateIfBehavior::Conditional(conditions) = node {
+        for condition in conditions {
+            v.visit_assignment_selection_mut(&mut condition.selection);
+            v.visit_expression_mut(&mut condition.expr_term);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::ast::*;
+use crate::parse_cql;
+use crate::test_cql::*;
+use crate::visit::{self, Visit};
+use crate::visit_mut::VisitMut;
+
+#[derive(Default)]
+struct NameCollector {
+    keyspace_names: Vec<String>,
+    identifiers: Vec<String>,
+    user_defined_types: Vec<String>,
+}
+
+impl Visit for NameCollector {
+    fn visit_data_type(&mut self, node: &CqlDataType) {
+        if let CqlDataType::ValueType(CqlValueType::UserDefinedType(_, type_name)) = node {
+            self.user_defined_types.push(type_name.value());
+        }
+        visit::walk_data_type(self, node);
+    }
+
+    fn visit_keyspace_name(&mut self, node: &TokenView) {
+        self.keyspace_names.push(node.value());
+    }
+
+    fn visit_identifier(&mut self, node: &TokenView) {
+        self.identifiers.push(node.value());
+    }
+}
+
+fn collect_names(cql: &str) -> NameCollector {
+    let mut collector = NameCollector::default();
+    for statement in parse_cql(cql.to_string()).unwrap() {
+        collector.visit_statement(&statement);
+    }
+    collector
+}
+
+#[test]
+fn test_visit_keyspace_names() {
+    let collector = collect_names(USE_KEYSPACE_THEN_CREATE_TABLE);
+    assert_eq!(
+        collector.keyspace_names,
+        vec!("big_data_keyspace", "other_keyspace")
+    );
+    assert_eq!(
+        collector.identifiers,
+        vec!(
+            "big_data_table",
+            "uuid_column",
+            "big_data_table",
+            "uuid_column",
+            "big_data_table",
+            "uuid_column",
+        )
+    );
+}
+
+#[test]
+fn test_visit_nested_data_types() {
+    let collector = collect_names(CREATE_TABLE_WITH_NESTED_DATA_TYPES);
+    assert_eq!(collector.user_defined_types, vec!("big_data_udt"));
+}
+
+#[test]
+fn test_visit_expressions() {
+    let collector = collect_names(COUNTER_BATCH);
+    assert!(collector.keyspace_names.is_empty());
+    assert_eq!(
+        collector.identifiers,
+        vec!(
+            "big_data_table",
+            "count_col",
+            "count_col",
+            "text_col",
+            "big_data_table",
+            "text_col",
+        )
+    );
+}
+
+struct KeyspaceRenamer;
+
+impl VisitMut for KeyspaceRenamer {
+    fn visit_keyspace_name_mut(&mut self, node: &mut TokenView) {
+        *node = TokenView::from_value(format!("{}_renamed", node.value()).as_str());
+    }
+}
+
+#[test]
+fn test_visit_mut_renames_keyspaces() {
+    let mut statements = parse_cql(
+        "use big_data_keyspace; drop table other_keyspace.big_data_table; select * from big_data_table;"
+            .to_string(),
+    )
+    .unwrap();
+    for statement in statements.iter_mut() {
+        KeyspaceRenamer.visit_statement_mut(statement);
+    }
+    assert_eq!(
+        statements
+            .iter()
+            .map(|statement| statement.to_string())
+            .collect::<Vec<String>>(),
+        vec!(
+            "USE big_data_keyspace_renamed",
+            "DROP TABLE other_keyspace_renamed.big_data_table",
+            "SELECT * FROM big_data_table",
+        )
+    );
+}
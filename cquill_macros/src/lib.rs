@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro::{TokenStream, TokenTree};
+
+/// Embeds the cql files of a directory relative to the crate's `Cargo.toml` into the executable
+/// as a `cquill::EmbeddedMigrations` for `cquill::migrate_embedded`, read from subdirectories
+/// and excluding cql files that are not migrated like `cquill migrate`.
+///
+/// Cargo rebuilds the executable when an embedded cql file changes but not when a cql file is
+/// added, which a build script printing `cargo:rerun-if-changed=cql` will.
+#[proc_macro]
+pub fn embed_migrations(input: TokenStream) -> TokenStream {
+    let expanded = match embed(input) {
+        Err(err) => format!("::core::compile_error!({err:?})"),
+        Ok(expanded) => expanded,
+    };
+    expanded.parse().expect("embed_migrations expansion")
+}
+
+fn embed(input: TokenStream) -> Result<String, String> {
+    let mut tokens = input.into_iter();
+    let cql_dir = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => parse_str_literal(&literal.to_string())?,
+        _ => {
+            return Err(String::from(
+                "embed_migrations! expects the path of a cql dir such as \"cql\"",
+            ));
+        }
+    };
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| String::from("embed_migrations! requires CARGO_MANIFEST_DIR"))?;
+    let dir = Path::new(&manifest_dir).join(&cql_dir);
+    let mut cql_file_paths = Vec::new();
+    read_cql_file_paths(&dir, &mut cql_file_paths)
+        .map_err(|err| format!("failed reading cql dir '{cql_dir}': {err}"))?;
+    cql_file_paths.sort();
+    let mut embedded_files = Vec::with_capacity(cql_file_paths.len());
+    for path in cql_file_paths {
+        let content =
+            fs::read(&path).map_err(|err| format!("failed reading {}: {err}", path.display()))?;
+        let relative_path = path
+            .strip_prefix(&dir)
+            .unwrap_or(&path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        embedded_files.push(format!(
+            "::cquill::EmbeddedCql {{ path: {relative_path:?}, cql: ::core::include_str!({:?}), hash: {:?} }}",
+            path.to_string_lossy(),
+            format!("{:x}", md5::compute(content)),
+        ));
+    }
+    Ok(format!(
+        "::cquill::EmbeddedMigrations::new({cql_dir:?}, &[{}])",
+        embedded_files.join(", ")
+    ))
+}
+
+/// Unquotes a string literal without escape sequences.
+fn parse_str_literal(literal: &str) -> Result<String, String> {
+    match literal
+        .strip_prefix('"')
+        .and_then(|literal| literal.strip_suffix('"'))
+    {
+        Some(value) if !value.contains('\\') => Ok(value.to_string()),
+        _ => Err(format!(
+            "embed_migrations! expects a string literal without escapes instead of {literal}"
+        )),
+    }
+}
+
+/// Reads the paths of migrated cql files like `cquill migrate`, skipping subdirectories prefixed
+/// with `_` or `.`, cql files prefixed with `_` and `.down.cql` files.
+fn read_cql_file_paths(dir: &Path, cql_file_paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for dir_entry in fs::read_dir(dir)? {
+        let path = dir_entry?.path();
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        if path.is_dir() {
+            if !name.starts_with('_') && !name.starts_with('.') {
+                read_cql_file_paths(&path, cql_file_paths)?;
+            }
+        } else if name.ends_with(".cql") && !name.starts_with('_') && !name.ends_with(".down.cql") {
+            cql_file_paths.push(path);
+        }
+    }
+    Ok(())
+}